@@ -1,17 +1,11 @@
 use std::{
     borrow::Cow,
     fmt::Display,
-    ops::{
-        Mul,
-        Range,
-    },
+    ops::Range,
     time::Duration,
 };
 
-use freya_core::{
-    elements::paragraph::ParagraphHolderInner,
-    prelude::*,
-};
+use freya_core::prelude::*;
 use freya_edit::*;
 use ropey::Rope;
 use tree_sitter::InputEdit;
@@ -103,12 +97,6 @@ impl CodeEditorData {
                 editor_line,
                 holder,
             } => {
-                let holder = holder.0.borrow();
-                let ParagraphHolderInner {
-                    paragraph,
-                    scale_factor,
-                } = holder.as_ref().unwrap();
-
                 let current_selection = self.selection().clone();
 
                 if self.dragging.shift || self.dragging.clicked {
@@ -123,11 +111,8 @@ impl CodeEditorData {
 
                 self.dragging.clicked = true;
 
-                let char_position = paragraph.get_glyph_position_at_coordinate(
-                    location.mul(*scale_factor).to_i32().to_tuple(),
-                );
                 let press_selection =
-                    self.measure_selection(char_position.position as usize, editor_line);
+                    self.measure_selection(holder.index_at(location).unwrap(), editor_line);
 
                 let new_selection = match EventsCombos::pressed(location) {
                     PressEventType::Quadruple => {
@@ -157,18 +142,8 @@ impl CodeEditorData {
                 holder,
             } => {
                 if self.dragging.clicked {
-                    let paragraph = holder.0.borrow();
-                    let ParagraphHolderInner {
-                        paragraph,
-                        scale_factor,
-                    } = paragraph.as_ref().unwrap();
-
-                    let dist_position = location.mul(*scale_factor);
-
                     // Calculate the end of the highlighting
-                    let dist_char = paragraph
-                        .get_glyph_position_at_coordinate(dist_position.to_i32().to_tuple());
-                    let to = dist_char.position as usize;
+                    let to = holder.index_at(location).unwrap();
 
                     if self.get_selection().is_none() {
                         self.selection_mut().set_as_range();
@@ -1,3 +1,10 @@
+use std::{
+    cell::RefCell,
+    path::PathBuf,
+    rc::Rc,
+};
+
+use bytes::Bytes;
 use freya_core::{
     prelude::*,
     scope_id::ScopeId,
@@ -14,9 +21,179 @@ enum DragPhase {
     Dragging {
         position: CursorPoint,
         offset: CursorPoint,
+        outside: bool,
     },
 }
 
+/// Data carried by a drag that can leave its window, to be dropped into another Freya window or,
+/// for its files, into other applications on platforms with an OS drag source
+/// (see `LaunchConfig::with_os_drag`).
+///
+/// Attach it to a [`DragZone`] with [`DragZone::payload`]. Drags coming from other windows are
+/// received by a [`DropZone`] of [`DragPayload`].
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     DragZone::new(0)
+///         .payload(
+///             DragPayload::new()
+///                 .with_file("report.pdf")
+///                 .with_text("report.pdf"),
+///         )
+///         .child("Drag me out!")
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DragPayload {
+    files: Vec<PathBuf>,
+    text: Option<String>,
+    data: Vec<(String, Bytes)>,
+}
+
+impl DragPayload {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file, which file managers and other applications receive as a file drop.
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.files.push(file.into());
+        self
+    }
+
+    /// Plain text representation of the payload.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Add raw data under a MIME type, replacing any data previously set for it.
+    pub fn with_data(mut self, mime: impl Into<String>, data: impl Into<Bytes>) -> Self {
+        let mime = mime.into();
+        self.data.retain(|(other, _)| *other != mime);
+        self.data.push((mime, data.into()));
+        self
+    }
+
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Raw data stored under `mime`, if any.
+    pub fn data(&self, mime: &str) -> Option<&Bytes> {
+        self.data
+            .iter()
+            .find_map(|(other, data)| (other == mime).then_some(data))
+    }
+
+    /// MIME types of the raw data, in insertion order.
+    pub fn mime_types(&self) -> impl Iterator<Item = &str> {
+        self.data.iter().map(|(mime, _)| mime.as_str())
+    }
+}
+
+/// Hands the [`DragPayload`] of drags leaving their window to the platform.
+///
+/// Renderers provide one as a root context through [`DragSourceHandle`], `freya-winit` starts OS
+/// drag sessions or delivers drags to other windows of the app and `freya-testing` uses
+/// [`InProcessDragSource`].
+pub trait DragSource {
+    /// The cursor left the window while dragging `payload`.
+    ///
+    /// This is where integrations able to start an OS drag session hand the drag over.
+    fn drag_exited(&self, _payload: &DragPayload) {}
+
+    /// The cursor moved outside of the window while dragging `payload`. `position` is relative
+    /// to the window, in logical pixels.
+    ///
+    /// This is where integrations show the drag over the window under the cursor.
+    fn drag_moved_outside(&self, _payload: &DragPayload, _position: CursorPoint) {}
+
+    /// The cursor came back into the window while dragging `payload`.
+    fn drag_returned(&self, _payload: &DragPayload) {}
+
+    /// The drag was released outside of its window. `position` is relative to the window,
+    /// in logical pixels, so it can fall outside of its bounds.
+    fn drop_outside(&self, payload: DragPayload, position: CursorPoint);
+}
+
+/// Shared [`DragSource`] of a window.
+#[derive(Clone)]
+pub struct DragSourceHandle(Rc<dyn DragSource>);
+
+impl DragSourceHandle {
+    pub fn new(drag_source: impl DragSource + 'static) -> Self {
+        Self(Rc::new(drag_source))
+    }
+
+    /// Get the [`DragSource`] of the current window, if the renderer provides one.
+    pub fn try_get() -> Option<Self> {
+        try_consume_root_context()
+    }
+
+    pub fn drag_exited(&self, payload: &DragPayload) {
+        self.0.drag_exited(payload);
+    }
+
+    pub fn drag_moved_outside(&self, payload: &DragPayload, position: CursorPoint) {
+        self.0.drag_moved_outside(payload, position);
+    }
+
+    pub fn drag_returned(&self, payload: &DragPayload) {
+        self.0.drag_returned(payload);
+    }
+
+    pub fn drop_outside(&self, payload: DragPayload, position: CursorPoint) {
+        self.0.drop_outside(payload, position);
+    }
+}
+
+/// A drag released outside of its window, as recorded by [`InProcessDragSource`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutgoingDrag {
+    pub payload: DragPayload,
+    pub position: CursorPoint,
+}
+
+/// In-process stand-in for an OS drag source, it records the drags that leave the window
+/// instead of handing them to the platform, so headless runners can inspect them or deliver
+/// them to another runner with [`receive_drag`].
+#[derive(Clone, Default)]
+pub struct InProcessDragSource {
+    outgoing: Rc<RefCell<Vec<OutgoingDrag>>>,
+}
+
+impl InProcessDragSource {
+    /// Take the drags released outside of the window so far.
+    pub fn take_outgoing(&self) -> Vec<OutgoingDrag> {
+        self.outgoing.borrow_mut().drain(..).collect()
+    }
+}
+
+impl DragSource for InProcessDragSource {
+    fn drop_outside(&self, payload: DragPayload, position: CursorPoint) {
+        self.outgoing
+            .borrow_mut()
+            .push(OutgoingDrag { payload, position });
+    }
+}
+
+/// Set the [`DragPayload`] of a drag that started outside of this window, or clear it with `None`.
+///
+/// Meant for renderers, which call it from the root scope of the target window right before
+/// emitting pointer moves or the pointer release over it, so that the [`DropZone`] of
+/// [`DragPayload`] under the cursor shows the drag over it and receives it.
+pub fn receive_drag(payload: Option<DragPayload>) {
+    *use_drag::<DragPayload>().write() = payload;
+}
+
 /// Access the global drag state for payloads of type `T`.
 ///
 /// Returns a [`State`] that holds `Some(payload)` while a [`DragZone`] of `T` is being dragged
@@ -48,6 +225,7 @@ pub struct DragZone<T: Clone + 'static + PartialEq> {
     children: Vec<Element>,
     drag_element: Option<Element>,
     data: T,
+    payload: Option<DragPayload>,
     show_while_dragging: bool,
     drag_threshold: f64,
     enabled: bool,
@@ -80,6 +258,7 @@ impl<T: Clone + PartialEq + 'static> DragZone<T> {
     pub fn new(data: T) -> Self {
         Self {
             data,
+            payload: None,
             children: Vec::new(),
             drag_element: None,
             show_while_dragging: true,
@@ -108,6 +287,13 @@ impl<T: Clone + PartialEq + 'static> DragZone<T> {
         self
     }
 
+    /// Payload handed to the platform when the drag leaves the window, so it can be dropped
+    /// into other windows of the app or, for its files, other applications.
+    pub fn payload(mut self, payload: DragPayload) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
     /// Whether dragging can start. Defaults to `true`.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.enabled = enabled;
@@ -121,13 +307,38 @@ impl<T: Clone + PartialEq> Component for DragZone<T> {
         let mut phase = use_state(|| DragPhase::Idle);
         let mut drag_element_size = use_state(|| None::<Size2D>);
         let data = self.data.clone();
+        let payload = self.payload.clone();
         let drag_threshold = self.drag_threshold;
 
         let on_global_pointer_move = move |e: Event<PointerEventData>| match phase() {
-            DragPhase::Dragging { offset, .. } => {
+            DragPhase::Dragging {
+                offset, outside, ..
+            } => {
+                let position = e.global_location();
+                let platform = Platform::get();
+                let root_size = *platform.root_size.peek();
+                let scale_factor = *platform.scale_factor.peek();
+                let is_outside = position.x < 0.
+                    || position.y < 0.
+                    || position.x > root_size.width as f64 / scale_factor
+                    || position.y > root_size.height as f64 / scale_factor;
+                if let Some((drag_source, payload)) =
+                    DragSourceHandle::try_get().zip(payload.as_ref())
+                {
+                    match (outside, is_outside) {
+                        (false, true) => {
+                            drag_source.drag_exited(payload);
+                            drag_source.drag_moved_outside(payload, position);
+                        }
+                        (true, true) => drag_source.drag_moved_outside(payload, position),
+                        (true, false) => drag_source.drag_returned(payload),
+                        (false, false) => {}
+                    }
+                }
                 phase.set(DragPhase::Dragging {
-                    position: e.global_location(),
+                    position,
                     offset,
+                    outside: is_outside,
                 });
             }
             DragPhase::Pressing {
@@ -142,6 +353,7 @@ impl<T: Clone + PartialEq> Component for DragZone<T> {
                     phase.set(DragPhase::Dragging {
                         position: current,
                         offset,
+                        outside: false,
                     });
                     *drags.write() = Some(data.clone());
                 }
@@ -159,15 +371,26 @@ impl<T: Clone + PartialEq> Component for DragZone<T> {
             });
         };
 
-        let on_global_pointer_press = move |_: Event<PointerEventData>| {
-            if !matches!(phase(), DragPhase::Idle) {
-                phase.set(DragPhase::Idle);
-                *drags.write() = None;
+        let on_global_pointer_press = {
+            let payload = self.payload.clone();
+            move |e: Event<PointerEventData>| {
+                if let DragPhase::Dragging { outside: true, .. } = phase()
+                    && let Some((drag_source, payload)) =
+                        DragSourceHandle::try_get().zip(payload.clone())
+                {
+                    drag_source.drop_outside(payload, e.global_location());
+                }
+                if !matches!(phase(), DragPhase::Idle) {
+                    phase.set(DragPhase::Idle);
+                    *drags.write() = None;
+                }
             }
         };
 
         let dragging = match phase() {
-            DragPhase::Dragging { position, offset } => Some((position, offset)),
+            DragPhase::Dragging {
+                position, offset, ..
+            } => Some((position, offset)),
             _ => None,
         };
 
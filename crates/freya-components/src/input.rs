@@ -7,13 +7,7 @@ use std::{
     rc::Rc,
 };
 
-use freya_core::{
-    elements::paragraph::{
-        ParagraphHolderInner,
-        cursor_character_rect,
-    },
-    prelude::*,
-};
+use freya_core::prelude::*;
 use freya_edit::*;
 use torin::{
     gaps::Gaps,
@@ -438,17 +432,6 @@ impl Component for Input {
                 return;
             }
 
-            let holder = holder.peek();
-            let holder = holder.0.borrow();
-            let Some(ParagraphHolderInner {
-                paragraph,
-                scale_factor,
-            }) = holder.as_ref()
-            else {
-                warn!("Paragraph should be build by now.");
-                return;
-            };
-
             let viewport = viewport_area();
             if viewport.width() == 0. {
                 return;
@@ -463,9 +446,14 @@ impl Component for Input {
                 InputMode::Shown => editor.rope().to_string(),
             };
 
-            let cursor_rect =
-                cursor_character_rect(paragraph, &text, editor.cursor_pos(), text_align);
-            let cursor_x = cursor_rect.left / (*scale_factor as f32);
+            let cursor_rect = holder
+                .peek()
+                .cursor_rect(&text, editor.cursor_pos(), text_align);
+            let Some(cursor_rect) = cursor_rect else {
+                warn!("Paragraph should be build by now.");
+                return;
+            };
+            let cursor_x = cursor_rect.min_x();
 
             // Visible window start
             let visible_start = viewport.min_x() - area.peek().min_x();
@@ -39,6 +39,7 @@ pub mod scrollviews;
 pub mod segmented_button;
 pub mod select;
 pub mod selectable_text;
+pub mod selection_scope;
 pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
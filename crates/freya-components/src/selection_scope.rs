@@ -0,0 +1,154 @@
+use freya_core::prelude::*;
+use freya_edit::Clipboard;
+
+/// A container whose texts can be selected as a whole by dragging, across all the
+/// [paragraphs](freya_core::prelude::paragraph) and [labels](freya_core::prelude::label) inside,
+/// and copied with `Ctrl+C` (`Cmd+C` on macOS).
+///
+/// Unlike [SelectableText](crate::selectable_text::SelectableText), the texts stay regular
+/// elements, so whole documents or chat transcripts can be selected at once.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     SelectionScope::new()
+///         .child(label().text("Hello, World!"))
+///         .child(paragraph().span("Select me ").span("too"))
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SelectionScope {
+    children: Vec<Element>,
+    layout: LayoutData,
+    highlight_color: Option<Color>,
+    key: DiffKey,
+}
+
+impl Default for SelectionScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChildrenExt for SelectionScope {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.children
+    }
+}
+
+impl KeyExt for SelectionScope {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl LayoutExt for SelectionScope {
+    fn get_layout(&mut self) -> &mut LayoutData {
+        &mut self.layout
+    }
+}
+
+impl ContainerExt for SelectionScope {}
+
+impl ContainerWithContentExt for SelectionScope {}
+
+impl SelectionScope {
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            layout: LayoutData::default(),
+            highlight_color: None,
+            key: DiffKey::None,
+        }
+    }
+
+    /// Set the color of the selected text background.
+    pub fn highlight_color(mut self, highlight_color: impl Into<Color>) -> Self {
+        self.highlight_color = Some(highlight_color.into());
+        self
+    }
+}
+
+impl Component for SelectionScope {
+    fn render(&self) -> impl IntoElement {
+        let mut selection = use_state(TextSelection::default);
+        let mut dragging = use_state(|| false);
+        let mut hovering = use_state(|| false);
+        let a11y_id = use_a11y();
+
+        let on_pointer_down = move |e: Event<PointerEventData>| {
+            if !e.data().is_primary() {
+                return;
+            }
+            let position = selection.read().position_at(e.global_location());
+            if let Some(position) = position {
+                selection.write().start(position);
+                dragging.set(true);
+                a11y_id.request_focus();
+            }
+        };
+
+        let on_global_pointer_move = move |e: Event<PointerEventData>| {
+            if !dragging() {
+                return;
+            }
+            let position = selection.read().position_at(e.global_location());
+            if let Some(position) = position
+                && selection.read().focus != Some(position)
+            {
+                selection.write().extend(position);
+            }
+        };
+
+        let on_global_pointer_press = move |_: Event<PointerEventData>| {
+            if dragging() {
+                dragging.set(false);
+            }
+        };
+
+        let on_global_pointer_down = move |_: Event<PointerEventData>| {
+            if !hovering() && selection.read().anchor.is_some() {
+                selection.write().clear();
+            }
+        };
+
+        let on_pointer_enter = move |_| hovering.set(true);
+
+        let on_pointer_leave = move |_| hovering.set(false);
+
+        let on_key_down = move |e: Event<KeyboardEventData>| {
+            if let Key::Character(character) = &e.key
+                && character == "c"
+                && e.modifiers.contains(Modifiers::ctrl_or_meta())
+                && !selection.read().is_empty()
+            {
+                Clipboard::set(selection.read().text()).ok();
+            }
+        };
+
+        let mut text_selection = selection();
+        if let Some(highlight_color) = self.highlight_color {
+            text_selection.highlight_color = highlight_color;
+        }
+
+        rect()
+            .layout(self.layout.clone())
+            .a11y_id(a11y_id)
+            .a11y_focusable(true)
+            .text_selection(text_selection)
+            .on_pointer_down(on_pointer_down)
+            .on_global_pointer_move(on_global_pointer_move)
+            .on_global_pointer_press(on_global_pointer_press)
+            .on_global_pointer_down(on_global_pointer_down)
+            .on_pointer_enter(on_pointer_enter)
+            .on_pointer_leave(on_pointer_leave)
+            .on_key_down(on_key_down)
+            .children(self.children.clone())
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
@@ -210,24 +210,22 @@ impl Component for Slider {
                 }
                 a11y_id.request_focus();
                 clicking.set(true);
+                e.capture_pointer(e.pointer_id());
                 e.stop_propagation();
                 let coordinates = e.element_location();
                 on_moved.call(calc_percentage(coordinates.x, coordinates.y));
             }
         };
 
-        let on_global_pointer_press = move |_: Event<PointerEventData>| {
+        let on_lost_pointer_capture = move |_: Event<PointerCaptureEventData>| {
             clicking.set(false);
         };
 
-        let on_global_pointer_move = move |e: Event<PointerEventData>| {
+        let on_pointer_move = move |e: Event<PointerEventData>| {
             e.stop_propagation();
             if *clicking.peek() {
-                let coordinates = e.global_location();
-                on_moved.call(calc_percentage(
-                    coordinates.x - size.read().min_x() as f64,
-                    coordinates.y - size.read().min_y() as f64,
-                ));
+                let coordinates = e.element_location();
+                on_moved.call(calc_percentage(coordinates.x, coordinates.y));
             }
         };
 
@@ -332,8 +330,8 @@ impl Component for Slider {
             .maybe(self.enabled, |rect| {
                 rect.on_key_down(on_key_down)
                     .on_pointer_down(on_pointer_down)
-                    .on_global_pointer_move(on_global_pointer_move)
-                    .on_global_pointer_press(on_global_pointer_press)
+                    .on_pointer_move(on_pointer_move)
+                    .on_lost_pointer_capture(on_lost_pointer_capture)
                     .maybe(self.scroll_enabled, |el| el.on_wheel(on_wheel))
             })
             .on_pointer_enter(on_pointer_enter)
@@ -0,0 +1,101 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+pub fn drag_payload_between_windows() {
+    fn source_app() -> impl IntoElement {
+        DragZone::new(0)
+            .payload(
+                DragPayload::new()
+                    .with_file("report.pdf")
+                    .with_text("report")
+                    .with_data("application/x-report", vec![1u8, 2, 3]),
+            )
+            .child(rect().width(Size::px(100.)).height(Size::px(100.)))
+    }
+
+    fn target_app() -> impl IntoElement {
+        let mut dropped = use_consume::<State<Vec<DragPayload>>>();
+        DropZone::new(move |payload: DragPayload| dropped.write().push(payload))
+            .child(rect().width(Size::px(100.)).height(Size::px(100.)))
+    }
+
+    let mut source = launch_test(source_app);
+    source.sync_and_update();
+
+    // Releasing inside of the window does not export anything
+    source.press_cursor((50., 50.));
+    source.move_cursor((80., 80.));
+    source.sync_and_update();
+    source.release_cursor((80., 80.));
+    assert!(source.take_outgoing_drags().is_empty());
+
+    source.press_cursor((50., 50.));
+    source.move_cursor((80., 80.));
+    source.sync_and_update();
+    source.move_cursor((700., 50.));
+    source.sync_and_update();
+    source.release_cursor((700., 50.));
+
+    let outgoing = source.take_outgoing_drags();
+    assert_eq!(outgoing.len(), 1);
+    assert_eq!(outgoing[0].position, CursorPoint::new(700., 50.));
+    let payload = outgoing[0].payload.clone();
+    assert_eq!(payload.text(), Some("report"));
+    assert_eq!(
+        payload.mime_types().collect::<Vec<_>>(),
+        vec!["application/x-report"]
+    );
+
+    let (mut target, dropped) = TestingRunner::new(
+        target_app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<DragPayload>::new())),
+        1.,
+    );
+    target.sync_and_update();
+
+    // Dropped outside of the drop zone
+    target.drop_drag(payload.clone(), (300., 300.));
+    assert!(dropped.peek().is_empty());
+
+    // The payload is not kept around after a missed drop
+    target.click_cursor((50., 50.));
+    assert!(dropped.peek().is_empty());
+
+    target.drop_drag(payload.clone(), (50., 50.));
+    assert_eq!(*dropped.peek(), vec![payload]);
+}
+
+#[test]
+pub fn drag_payload_over_window() {
+    fn target_app() -> impl IntoElement {
+        let mut hovered = use_consume::<State<Vec<bool>>>();
+        DropZone::new(|_: DragPayload| {})
+            .on_drag_over(move |is_over| hovered.write().push(is_over))
+            .child(rect().width(Size::px(100.)).height(Size::px(100.)))
+    }
+
+    let (mut target, hovered) = TestingRunner::new(
+        target_app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<bool>::new())),
+        1.,
+    );
+    target.sync_and_update();
+
+    let payload = DragPayload::new().with_text("report");
+    target.drag_over(payload.clone(), (300., 300.));
+    assert!(hovered.peek().is_empty());
+
+    target.drag_over(payload.clone(), (50., 50.));
+    assert_eq!(*hovered.peek(), vec![true]);
+
+    target.drag_leave();
+    assert_eq!(*hovered.peek(), vec![true, false]);
+
+    // Hovering without a drag does not count as a drag over
+    target.move_cursor((50., 50.));
+    target.sync_and_update();
+    assert_eq!(*hovered.peek(), vec![true, false]);
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+fn selection_scope_drag_selects_across_texts() {
+    let mut utils = launch_test(|| {
+        SelectionScope::new()
+            .child(label().text("Hello"))
+            .child(paragraph().span("World"))
+            .child(label().text("Bye"))
+    });
+
+    utils.set_fonts(HashMap::from_iter([(
+        "NotoSans",
+        include_bytes!("../../freya-edit/tests/NotoSans-Regular.ttf").as_slice(),
+    )]));
+    utils.set_default_fonts(&["NotoSans".into()]);
+    utils.sync_and_update();
+
+    // Press down at the start of the first text
+    utils.press_cursor((1.0, 5.0));
+
+    // Drag past the end of the second text
+    utils.move_cursor((400.0, 30.0));
+
+    utils.release_cursor((400.0, 30.0));
+
+    let selected = utils.find(|_, e| Some(Rect::try_downcast(e)?.text_selection?.text()));
+    assert_eq!(selected.as_deref(), Some("Hello\nWorld"));
+}
+
+#[test]
+fn selection_scope_selects_clipped_texts() {
+    let mut utils = launch_test(|| {
+        rect().height(Size::px(50.)).overflow(Overflow::Clip).child(
+            SelectionScope::new()
+                .child(label().text("Hello"))
+                .child(label().text("World"))
+                .child(label().text("Hidden")),
+        )
+    });
+
+    utils.set_fonts(HashMap::from_iter([(
+        "NotoSans",
+        include_bytes!("../../freya-edit/tests/NotoSans-Regular.ttf").as_slice(),
+    )]));
+    utils.set_default_fonts(&["NotoSans".into()]);
+    utils.sync_and_update();
+
+    utils.press_cursor((1.0, 5.0));
+
+    // Drag below the clipped area, where the last text is laid out
+    utils.move_cursor((400.0, 200.0));
+    utils.release_cursor((400.0, 200.0));
+
+    let selected = utils.find(|_, e| Some(Rect::try_downcast(e)?.text_selection?.text()));
+    assert_eq!(selected.as_deref(), Some("Hello\nWorld\nHidden"));
+}
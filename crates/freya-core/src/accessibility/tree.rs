@@ -40,6 +40,7 @@ use crate::{
         AccessibilityFocusMovement,
         Color,
         EventType,
+        FocusEventData,
         FontSlant,
         TextAlign,
         TextDecoration,
@@ -55,6 +56,8 @@ pub struct AccessibilityTree {
     pub map: FxHashMap<AccessibilityId, NodeId>,
     // Current focused Accessibility Node.
     pub focused_id: AccessibilityId,
+    // Focused Node followed by its ancestors, as of the last dispatched focus events.
+    focus_path: Vec<NodeId>,
 }
 
 impl Default for AccessibilityTree {
@@ -68,6 +71,7 @@ impl AccessibilityTree {
         Self {
             focused_id,
             map: FxHashMap::default(),
+            focus_path: Vec::new(),
         }
     }
 
@@ -185,6 +189,8 @@ impl AccessibilityTree {
             self.scroll_to(node_id, tree, events_sender);
         }
 
+        self.dispatch_focus_events(tree, events_sender);
+
         TreeUpdate {
             tree_id: TreeId::ROOT,
             nodes,
@@ -289,6 +295,68 @@ impl AccessibilityTree {
         tracing::info!("Focused {:?} node.", self.focused_id);
     }
 
+    /// Send the focus, blur and focus within change events caused by the focused Node changing since the last call.
+    fn dispatch_focus_events(
+        &mut self,
+        tree: &Tree,
+        events_sender: &futures_channel::mpsc::UnboundedSender<EventsChunk>,
+    ) {
+        let mut focus_path = Vec::new();
+        // The root being focused means that nothing is
+        let mut next_node = self
+            .focused_node_id()
+            .filter(|node_id| *node_id != NodeId::ROOT);
+        while let Some(node_id) = next_node {
+            focus_path.push(node_id);
+            next_node = tree.parents.get(&node_id).copied();
+        }
+
+        if focus_path == self.focus_path {
+            return;
+        }
+
+        let focus_event = |name: EventName, node_id: NodeId, focused: bool| EmmitableEvent {
+            name,
+            source_event: name,
+            node_id,
+            data: EventType::Focus(FocusEventData::new(focused)),
+            bubbles: false,
+        };
+
+        let previous_focused = self.focus_path.first().copied();
+        let focused = focus_path.first().copied();
+        let mut events = Vec::new();
+
+        if previous_focused != focused
+            && let Some(node_id) = previous_focused
+        {
+            events.push(focus_event(EventName::Blur, node_id, false));
+        }
+        for node_id in &self.focus_path {
+            if !focus_path.contains(node_id) {
+                events.push(focus_event(EventName::FocusWithinChange, *node_id, false));
+            }
+        }
+        for node_id in focus_path.iter().rev() {
+            if !self.focus_path.contains(node_id) {
+                events.push(focus_event(EventName::FocusWithinChange, *node_id, true));
+            }
+        }
+        if previous_focused != focused
+            && let Some(node_id) = focused
+        {
+            events.push(focus_event(EventName::Focus, node_id, true));
+        }
+
+        self.focus_path = focus_path;
+
+        if !events.is_empty() {
+            events_sender
+                .unbounded_send(EventsChunk::Batch(events))
+                .unwrap();
+        }
+    }
+
     /// Send the necessary wheel events to scroll views so that the given focused [NodeId] is visible on screen.
     fn scroll_to(
         &self,
@@ -433,7 +501,7 @@ impl AccessibilityTree {
                 builder.set_transform(Affine::new([cos, sin, -sin, cos, 0.0, 0.0]));
             }
 
-            if effect_state.overflow == Overflow::Clip {
+            if effect_state.overflow == Overflow::Clip || effect_state.masked {
                 builder.set_clips_children();
             }
         }
@@ -464,16 +532,24 @@ impl AccessibilityTree {
                 builder.set_italic();
             }
 
+            let is_rtl = text_style.layout_direction.is_rtl();
+
             builder.set_text_align(match text_style.text_align {
                 TextAlign::Center => accesskit::TextAlign::Center,
                 TextAlign::Justify => accesskit::TextAlign::Justify,
-                // TODO: change the representation of `Start` and `End` once writing modes are supported.
-                TextAlign::Left | TextAlign::Start => accesskit::TextAlign::Left,
-                TextAlign::Right | TextAlign::End => accesskit::TextAlign::Right,
+                TextAlign::Left => accesskit::TextAlign::Left,
+                TextAlign::Right => accesskit::TextAlign::Right,
+                TextAlign::Start if is_rtl => accesskit::TextAlign::Right,
+                TextAlign::End if is_rtl => accesskit::TextAlign::Left,
+                TextAlign::Start => accesskit::TextAlign::Left,
+                TextAlign::End => accesskit::TextAlign::Right,
             });
 
-            // TODO: adjust this once text directions other than left to right are supported.
-            builder.set_text_direction(accesskit::TextDirection::LeftToRight);
+            builder.set_text_direction(if is_rtl {
+                accesskit::TextDirection::RightToLeft
+            } else {
+                accesskit::TextDirection::LeftToRight
+            });
 
             let decoration = accesskit::TextDecoration {
                 style: accesskit::TextDecorationStyle::Solid,
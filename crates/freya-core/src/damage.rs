@@ -0,0 +1,128 @@
+use torin::prelude::Area;
+
+/// Max amount of separate areas tracked before merging them all into their bounding area.
+const MAX_AREAS: usize = 8;
+
+/// Areas of the window that changed since the last frame and must be redrawn.
+///
+/// A [Damage] is either empty, made of a few areas or [full](Damage::full), meaning that
+/// everything has to be redrawn.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Damage {
+    areas: Vec<Area>,
+    full: bool,
+}
+
+impl Damage {
+    /// Create a [Damage] that covers everything.
+    pub fn full() -> Self {
+        Self {
+            areas: Vec::new(),
+            full: true,
+        }
+    }
+
+    /// Mark everything as damaged.
+    pub fn invalidate_all(&mut self) {
+        self.areas.clear();
+        self.full = true;
+    }
+
+    /// Mark the given area as damaged. The area is expanded to whole pixels and merged with
+    /// the areas it overlaps.
+    pub fn invalidate(&mut self, area: Area) {
+        if self.full || area.is_empty() || !area.is_finite() {
+            return;
+        }
+
+        let mut area = area.round_out();
+
+        // Merge all the overlapping areas into a single one
+        while let Some(i) = self.areas.iter().position(|other| other.intersects(&area)) {
+            area = area.union(&self.areas.swap_remove(i));
+        }
+
+        self.areas.push(area);
+
+        if self.areas.len() > MAX_AREAS {
+            let bounds = self.bounds();
+            self.areas.clear();
+            self.areas.extend(bounds);
+        }
+    }
+
+    /// Merge another [Damage] into this one.
+    pub fn extend(&mut self, other: Damage) {
+        if other.full {
+            self.invalidate_all();
+        } else {
+            for area in other.areas {
+                self.invalidate(area);
+            }
+        }
+    }
+
+    /// Whether everything must be redrawn.
+    pub fn is_full(&self) -> bool {
+        self.full
+    }
+
+    /// Whether nothing must be redrawn.
+    pub fn is_empty(&self) -> bool {
+        !self.full && self.areas.is_empty()
+    }
+
+    /// The damaged areas. Always empty when the damage is [full](Damage::is_full).
+    pub fn areas(&self) -> &[Area] {
+        &self.areas
+    }
+
+    /// The area containing all the damaged areas.
+    pub fn bounds(&self) -> Option<Area> {
+        self.areas.iter().copied().reduce(|a, b| a.union(&b))
+    }
+
+    /// Whether the given area has to be redrawn.
+    pub fn intersects(&self, area: &Area) -> bool {
+        self.full || self.areas.iter().any(|damaged| damaged.intersects(area))
+    }
+
+    /// Take the damage out, leaving an empty one behind.
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use torin::prelude::{
+        Area,
+        Point2D,
+        Size2D,
+    };
+
+    use crate::damage::Damage;
+
+    #[test]
+    fn merge_overlapping_areas() {
+        let mut damage = Damage::default();
+        assert!(damage.is_empty());
+
+        damage.invalidate(Area::new(Point2D::new(0.5, 0.), Size2D::new(10., 10.)));
+        damage.invalidate(Area::new(Point2D::new(50., 50.), Size2D::new(10., 10.)));
+        damage.invalidate(Area::new(Point2D::new(5., 5.), Size2D::new(10., 10.)));
+
+        assert_eq!(
+            damage.areas(),
+            &[
+                Area::new(Point2D::new(50., 50.), Size2D::new(10., 10.)),
+                Area::new(Point2D::new(0., 0.), Size2D::new(15., 15.)),
+            ]
+        );
+        assert!(!damage.intersects(&Area::new(Point2D::new(20., 20.), Size2D::new(5., 5.))));
+
+        damage.extend(Damage::full());
+        assert!(damage.is_full());
+        assert!(damage.areas().is_empty());
+    }
+}
@@ -8,8 +8,12 @@ use std::{
     rc::Rc,
 };
 
+use freya_engine::prelude::TextDirection;
 use torin::{
-    prelude::Area,
+    prelude::{
+        Area,
+        LayoutDirection,
+    },
     torin::Torin,
 };
 
@@ -35,22 +39,37 @@ use crate::{
         CursorStyle,
     },
     style::{
+        blend_mode::BlendMode,
         border::Border,
+        clip_path::ClipPath,
         color::Color,
         corner_radius::CornerRadius,
         fill::Fill,
+        filter::Filter,
+        font_feature::FontFeature,
         font_size::FontSize,
         font_slant::FontSlant,
+        font_variation::FontVariation,
         font_weight::FontWeight,
         font_width::FontWidth,
+        hyphens::Hyphens,
+        mask::Mask,
         scale::Scale,
         shadow::Shadow,
         text_align::TextAlign,
-        text_decoration::TextDecoration,
+        text_decoration::{
+            TextDecoration,
+            TextDecorationStyle,
+            TextDecorationThickness,
+        },
         text_height::TextHeightBehavior,
         text_overflow::TextOverflow,
         text_shadow::TextShadow,
+        text_spacing::TextSpacing,
+        text_stroke::TextStroke,
+        transform::Transform,
         transform_origin::TransformOrigin,
+        word_break::WordBreak,
     },
 };
 
@@ -82,13 +101,20 @@ impl DerefMut for LayoutData {
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct EffectData {
     pub overflow: Overflow,
+    pub clip_path: Option<ClipPath>,
+    pub mask: Option<Mask>,
     pub rotation: Option<f32>,
     pub scale: Option<Scale>,
+    pub transforms: Vec<Transform>,
     pub transform_origin: TransformOrigin,
     pub opacity: Option<f32>,
+    pub blend_mode: Option<BlendMode>,
     pub blur: Option<f32>,
+    pub filters: Vec<Filter>,
+    pub backdrop_filters: Vec<Filter>,
     pub scrollable: bool,
     pub interactive: Interactive,
+    pub cache_layer: bool,
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -128,9 +154,21 @@ pub struct TextStyleState {
     pub text_overflow: TextOverflow,
     pub text_shadows: Vec<TextShadow>,
     pub text_decoration: TextDecoration,
+    pub text_decoration_style: TextDecorationStyle,
+    pub text_decoration_color: Option<Color>,
+    pub text_decoration_thickness: TextDecorationThickness,
+    pub text_stroke: Option<TextStroke>,
     pub font_slant: FontSlant,
     pub font_weight: FontWeight,
     pub font_width: FontWidth,
+    pub font_features: Vec<FontFeature>,
+    pub font_variations: Vec<FontVariation>,
+    pub letter_spacing: TextSpacing,
+    pub word_spacing: TextSpacing,
+    pub word_break: WordBreak,
+    pub hyphens: Hyphens,
+    pub locale: Option<Cow<'static, str>>,
+    pub layout_direction: LayoutDirection,
 }
 
 impl Default for TextStyleState {
@@ -144,9 +182,21 @@ impl Default for TextStyleState {
             text_overflow: TextOverflow::default(),
             text_shadows: Vec::new(),
             text_decoration: TextDecoration::default(),
+            text_decoration_style: TextDecorationStyle::default(),
+            text_decoration_color: None,
+            text_decoration_thickness: TextDecorationThickness::default(),
+            text_stroke: None,
             font_slant: FontSlant::default(),
             font_weight: FontWeight::default(),
             font_width: FontWidth::default(),
+            font_features: Vec::new(),
+            font_variations: Vec::new(),
+            letter_spacing: TextSpacing::default(),
+            word_spacing: TextSpacing::default(),
+            word_break: WordBreak::default(),
+            hyphens: Hyphens::default(),
+            locale: None,
+            layout_direction: LayoutDirection::default(),
         }
     }
 }
@@ -159,7 +209,17 @@ impl TextStyleState {
         let text_height = data.text_height.unwrap_or_default();
         let text_overflow = data.text_overflow.clone().unwrap_or_default();
         let text_shadows = data.text_shadows.clone();
-        let text_decoration = data.text_decoration.unwrap_or_default();
+
+        // Decorations and strokes are inherited so they also cover nested text
+        let text_decoration = data.text_decoration.unwrap_or(parent.text_decoration);
+        let text_decoration_style = data
+            .text_decoration_style
+            .unwrap_or(parent.text_decoration_style);
+        let text_decoration_color = data.text_decoration_color.or(parent.text_decoration_color);
+        let text_decoration_thickness = data
+            .text_decoration_thickness
+            .unwrap_or(parent.text_decoration_thickness);
+        let text_stroke = data.text_stroke.or(parent.text_stroke);
 
         // Font values can be inherited
         let font_size = data.font_size.unwrap_or(parent.font_size);
@@ -169,6 +229,30 @@ impl TextStyleState {
         let mut font_families = data.font_families.clone();
         font_families.extend_from_slice(&parent.font_families);
 
+        // Features and variations override those of the parent with the same tag
+        let mut font_features = parent.font_features.clone();
+        for feature in &data.font_features {
+            font_features.retain(|parent_feature| parent_feature.tag != feature.tag);
+            font_features.push(feature.clone());
+        }
+        let mut font_variations = parent.font_variations.clone();
+        for variation in &data.font_variations {
+            font_variations.retain(|parent_variation| parent_variation.axis != variation.axis);
+            font_variations.push(variation.clone());
+        }
+
+        // Spacing is inherited too
+        let letter_spacing = data.letter_spacing.unwrap_or(parent.letter_spacing);
+        let word_spacing = data.word_spacing.unwrap_or(parent.word_spacing);
+
+        // And so is how lines break
+        let word_break = data.word_break.unwrap_or(parent.word_break);
+        let hyphens = data.hyphens.unwrap_or(parent.hyphens);
+        let locale = data.locale.clone().or_else(|| parent.locale.clone());
+
+        // The layout direction is inherited too
+        let layout_direction = data.layout_direction.unwrap_or(parent.layout_direction);
+
         Self {
             color,
             text_align,
@@ -176,11 +260,31 @@ impl TextStyleState {
             text_overflow,
             text_shadows,
             text_decoration,
+            text_decoration_style,
+            text_decoration_color,
+            text_decoration_thickness,
+            text_stroke,
             font_size,
             font_slant,
             font_weight,
             font_width,
             font_families,
+            font_features,
+            font_variations,
+            letter_spacing,
+            word_spacing,
+            word_break,
+            hyphens,
+            locale,
+            layout_direction,
+        }
+    }
+
+    /// Base direction of the paragraphs, following the layout direction.
+    pub fn text_direction(&self) -> TextDirection {
+        match self.layout_direction {
+            LayoutDirection::LeftToRight => TextDirection::LTR,
+            LayoutDirection::RightToLeft => TextDirection::RTL,
         }
     }
 
@@ -217,9 +321,21 @@ pub struct TextStyleData {
     pub text_overflow: Option<TextOverflow>,
     pub text_shadows: Vec<TextShadow>,
     pub text_decoration: Option<TextDecoration>,
+    pub text_decoration_style: Option<TextDecorationStyle>,
+    pub text_decoration_color: Option<Color>,
+    pub text_decoration_thickness: Option<TextDecorationThickness>,
+    pub text_stroke: Option<TextStroke>,
     pub font_slant: Option<FontSlant>,
     pub font_weight: Option<FontWeight>,
     pub font_width: Option<FontWidth>,
+    pub font_features: Vec<FontFeature>,
+    pub font_variations: Vec<FontVariation>,
+    pub letter_spacing: Option<TextSpacing>,
+    pub word_spacing: Option<TextSpacing>,
+    pub word_break: Option<WordBreak>,
+    pub hyphens: Option<Hyphens>,
+    pub locale: Option<Cow<'static, str>>,
+    pub layout_direction: Option<LayoutDirection>,
 }
 
 #[derive(Debug, Default)]
@@ -301,23 +417,38 @@ impl From<bool> for Interactive {
 #[derive(PartialEq, Default, Debug, Clone)]
 pub struct EffectState {
     pub overflow: Overflow,
+    /// Whether the element has a clip path or a mask, which apply to its descendants too.
+    pub masked: bool,
     pub clips: Rc<[NodeId]>,
 
     pub rotations: Rc<[NodeId]>,
     pub rotation: Option<f32>,
 
-    pub scales: Rc<[NodeId]>,
+    /// Nodes with a scale effect or transforms, including this one.
+    pub transforms: Rc<[NodeId]>,
     pub scale: Option<Scale>,
+    pub transform: Rc<[Transform]>,
 
     pub transform_origin: TransformOrigin,
 
     pub opacities: Rc<[f32]>,
 
+    pub blend_modes: Rc<[BlendMode]>,
+
     pub blur: Option<f32>,
 
+    /// Nodes with filters, including this one.
+    pub filters: Rc<[NodeId]>,
+    pub filter: Rc<[Filter]>,
+
+    pub backdrop_filter: Rc<[Filter]>,
+
     pub scrollables: Rc<[NodeId]>,
 
     pub interactive: Interactive,
+
+    /// Outermost ancestor (or this node) whose subtree is rendered into a cached layer.
+    pub cached_layer: Option<NodeId>,
 }
 
 impl EffectState {
@@ -331,9 +462,13 @@ impl EffectState {
     ) {
         *self = Self {
             overflow: Overflow::default(),
+            masked: false,
             blur: None,
+            filter: Rc::default(),
+            backdrop_filter: Rc::default(),
             rotation: None,
             scale: None,
+            transform: Rc::default(),
             transform_origin: TransformOrigin::default(),
             ..parent_effect_state.clone()
         };
@@ -342,7 +477,9 @@ impl EffectState {
             Layer::Overlay | Layer::OverlayLevel(_) => {
                 self.clips = Rc::default();
             }
-            Layer::Relative(_) if parent_effect_state.overflow == Overflow::Clip => {
+            Layer::Relative(_)
+                if parent_effect_state.overflow == Overflow::Clip || parent_effect_state.masked =>
+            {
                 let mut clips = parent_effect_state.clips.to_vec();
                 clips.push(parent_node_id);
                 if self.clips.as_ref() != clips {
@@ -354,6 +491,7 @@ impl EffectState {
 
         if let Some(effect_data) = effect_data {
             self.overflow = effect_data.overflow;
+            self.masked = effect_data.clip_path.is_some() || effect_data.mask.is_some();
             self.blur = effect_data.blur;
             self.transform_origin = effect_data.transform_origin;
 
@@ -366,12 +504,13 @@ impl EffectState {
                 }
             }
 
-            if let Some(scale) = effect_data.scale {
-                let mut scales = parent_effect_state.scales.to_vec();
-                scales.push(node_id);
-                self.scale = Some(scale);
-                if self.scales.as_ref() != scales {
-                    self.scales = Rc::from(scales);
+            if effect_data.scale.is_some() || !effect_data.transforms.is_empty() {
+                let mut transforms = parent_effect_state.transforms.to_vec();
+                transforms.push(node_id);
+                self.scale = effect_data.scale;
+                self.transform = Rc::from(effect_data.transforms.as_slice());
+                if self.transforms.as_ref() != transforms {
+                    self.transforms = Rc::from(transforms);
                 }
             }
 
@@ -383,6 +522,27 @@ impl EffectState {
                 }
             }
 
+            if let Some(blend_mode) = effect_data.blend_mode {
+                let mut blend_modes = parent_effect_state.blend_modes.to_vec();
+                blend_modes.push(blend_mode);
+                if self.blend_modes.as_ref() != blend_modes {
+                    self.blend_modes = Rc::from(blend_modes);
+                }
+            }
+
+            if !effect_data.filters.is_empty() {
+                let mut filters = parent_effect_state.filters.to_vec();
+                filters.push(node_id);
+                self.filter = Rc::from(effect_data.filters.as_slice());
+                if self.filters.as_ref() != filters {
+                    self.filters = Rc::from(filters);
+                }
+            }
+
+            if !effect_data.backdrop_filters.is_empty() {
+                self.backdrop_filter = Rc::from(effect_data.backdrop_filters.as_slice());
+            }
+
             if effect_data.scrollable {
                 let mut scrolls = parent_effect_state.scrollables.to_vec();
                 scrolls.push(node_id);
@@ -394,9 +554,18 @@ impl EffectState {
             if effect_data.interactive == Interactive::No {
                 self.interactive = Interactive::No;
             }
+
+            if effect_data.cache_layer && self.cached_layer.is_none() {
+                self.cached_layer = Some(node_id);
+            }
         }
     }
 
+    /// Whether this node has a scale effect or transforms.
+    pub fn is_transformed(&self) -> bool {
+        self.scale.is_some() || !self.transform.is_empty()
+    }
+
     pub fn is_visible(&self, layout: &Torin<NodeId>, area: &Area) -> bool {
         // Skip elements that are completely out of any their parent's viewport
         for viewport_id in self.clips.iter() {
@@ -51,16 +51,21 @@ use crate::{
         },
         name::EventName,
     },
+    fonts::FallbackFonts,
     layers::Layer,
     node_id::NodeId,
     prelude::{
         Color,
         FileEventData,
+        FocusEventData,
+        GestureEventData,
         ImePreeditEventData,
         MaybeExt,
+        PointerCaptureEventData,
     },
     style::fill::Fill,
     text_cache::TextCache,
+    text_selection::TextSelection,
     tree::{
         DiffModifies,
         Tree,
@@ -83,6 +88,10 @@ pub trait ElementExt: Any {
         DiffModifies::empty()
     }
 
+    /// Runs when this element replaces a modified one in the tree, to carry over what the
+    /// previous element computed, as it might not get measured again.
+    fn inherit(&self, _previous: &Rc<dyn ElementExt>) {}
+
     fn layout(&'_ self) -> Cow<'_, LayoutData> {
         Cow::Owned(Default::default())
     }
@@ -117,6 +126,11 @@ pub trait ElementExt: Any {
         None
     }
 
+    /// The [TextSelection] shared by the texts inside of this element.
+    fn text_selection(&self) -> Option<&TextSelection> {
+        None
+    }
+
     fn measure(&self, _context: LayoutContext) -> Option<(Size2D, Rc<dyn Any>)> {
         None
     }
@@ -176,13 +190,14 @@ pub struct LayoutContext<'a> {
     pub font_collection: &'a mut FontCollection,
     pub font_manager: &'a FontMgr,
     pub text_style_state: &'a TextStyleState,
-    pub fallback_fonts: &'a [Cow<'static, str>],
+    pub fallback_fonts: &'a FallbackFonts,
     pub scale_factor: f64,
     pub text_cache: &'a mut TextCache,
 }
 
 #[allow(dead_code)]
 pub struct RenderContext<'a> {
+    pub node_id: NodeId,
     pub font_collection: &'a mut FontCollection,
     pub canvas: &'a Canvas,
     pub layout_node: &'a LayoutNode,
@@ -203,7 +218,7 @@ pub struct PostMeasureContext<'a> {
     pub layout: &'a Torin<NodeId>,
     pub font_collection: &'a mut FontCollection,
     pub text_style_state: &'a TextStyleState,
-    pub fallback_fonts: &'a [Cow<'static, str>],
+    pub fallback_fonts: &'a FallbackFonts,
     pub scale_factor: f64,
 }
 
@@ -460,4 +475,7 @@ pub enum EventHandlerType {
     Pointer(EventHandler<Event<PointerEventData>>),
     ImePreedit(EventHandler<Event<ImePreeditEventData>>),
     File(EventHandler<Event<FileEventData>>),
+    Gesture(EventHandler<Event<GestureEventData>>),
+    PointerCapture(EventHandler<Event<PointerCaptureEventData>>),
+    Focus(EventHandler<Event<FocusEventData>>),
 }
@@ -15,6 +15,9 @@ use torin::{
     prelude::{
         Alignment,
         Direction,
+        GridPlacement,
+        GridSize,
+        LayoutDirection,
         Length,
         Position,
         VisibleSize,
@@ -57,14 +60,22 @@ use crate::{
     layers::Layer,
     prelude::*,
     style::{
+        blend_mode::BlendMode,
+        clip_path::ClipPath,
+        filter::Filter,
+        font_feature::FontFeature,
         font_size::FontSize,
         font_slant::FontSlant,
+        font_variation::FontVariation,
         font_weight::FontWeight,
         font_width::FontWidth,
+        mask::Mask,
         scale::Scale,
         text_height::TextHeightBehavior,
         text_overflow::TextOverflow,
         text_shadow::TextShadow,
+        text_spacing::TextSpacing,
+        transform::Transform,
         transform_origin::TransformOrigin,
     },
 };
@@ -174,6 +185,36 @@ macro_rules! event_handlers {
     };
 }
 
+macro_rules! gesture_handlers {
+    (
+        $(
+            $(#[$attr:meta])*
+            $name:ident, $event_data:ty => $event_variant:expr ;
+        )*
+    ) => {
+        paste! {
+            $(
+                $(#[$attr])*
+                fn [<on_$name>](mut self, [<on_$name>]: impl Into<EventHandler<Event<$event_data>>>) -> Self {
+                    let [<on_$name>] = [<on_$name>].into();
+                    self.get_event_handlers().insert(
+                        $event_variant,
+                        EventHandlerType::Gesture(
+                            (move |e: Event<GestureEventData>| {
+                                if let Some(event) = e.try_map(GestureEventData::$name) {
+                                    [<on_$name>].call(event);
+                                }
+                            })
+                            .into(),
+                        ),
+                    );
+                    self
+                }
+            )*
+        }
+    };
+}
+
 /// Methods for attaching event handlers to an element.
 ///
 /// Many events come in three flavors: the plain one fires only while the pointer is over the
@@ -278,6 +319,26 @@ pub trait EventHandlersExt: Sized {
         pointer_out => EventName::PointerOut;
     }
 
+    event_handlers! {
+        PointerCapture,
+        PointerCaptureEventData;
+
+        /// Fires when the element stops capturing a pointer, see [Event::capture_pointer].
+        lost_pointer_capture => EventName::LostPointerCapture;
+    }
+
+    event_handlers! {
+        Focus,
+        FocusEventData;
+
+        /// Fires when the element gains focus, see [crate::prelude::use_focus].
+        focus => EventName::Focus;
+        /// Fires when the element loses focus.
+        blur => EventName::Blur;
+        /// Fires when focus moves into or out of the element and its descendants.
+        focus_within_change => EventName::FocusWithinChange;
+    }
+
     event_handlers! {
         File,
         FileEventData;
@@ -298,6 +359,19 @@ pub trait EventHandlersExt: Sized {
         ime_preedit => EventName::ImePreedit;
     }
 
+    gesture_handlers! {
+        /// Fires while one or more fingers, or the trackpad, drag over the element.
+        pan, PanEventData => EventName::Pan;
+        /// Fires while two fingers move closer or apart over the element, or the trackpad is pinched.
+        pinch, PinchEventData => EventName::Pinch;
+        /// Fires while two fingers turn around each other over the element, or the trackpad is rotated.
+        rotate, RotateEventData => EventName::Rotate;
+        /// Fires when a finger is held down over the element without moving.
+        long_press, LongPressEventData => EventName::LongPress;
+        /// Fires when a finger is lifted from the element while moving fast.
+        swipe, SwipeEventData => EventName::Swipe;
+    }
+
     /// Fires when the element's measured size or position changes.
     fn on_sized(mut self, on_sized: impl Into<EventHandler<Event<SizedEventData>>>) -> Self
     where
@@ -502,6 +576,15 @@ where
         self.get_layout().layout.content = content;
         self
     }
+
+    /// Place children in a grid with the given column and row tracks. Shorthand for [`content`](Self::content) set to [`Content::grid`].
+    ///
+    /// Children pick their cell with [`grid_placement`](ContainerPositionExt::grid_placement).
+    fn grid(mut self, columns: Vec<GridSize>, rows: Vec<GridSize>) -> Self {
+        self.get_layout().layout.content = Content::grid(columns, rows);
+        self
+    }
+
     /// Center children on both axes. Shorthand for [`main_align`](Self::main_align) and [`cross_align`](Self::cross_align) set to [`Alignment::Center`].
     fn center(mut self) -> Self {
         self.get_layout().layout.main_alignment = Alignment::Center;
@@ -558,6 +641,15 @@ where
         self.get_layout().layout.height = Size::fill();
         self
     }
+
+    /// Keep the given width to height ratio by deriving the dimension sized by its content
+    /// from the other one, e.g `16. / 9.`. Minimum and maximum sizes still apply.
+    ///
+    /// Has no effect when both the `width` and `height` are resolved on their own.
+    fn keep_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.get_layout().layout.aspect_ratio = Some(aspect_ratio);
+        self
+    }
 }
 
 impl<T: ContainerExt> ContainerSizeExt for T {}
@@ -578,6 +670,32 @@ where
         self.get_layout().layout.margin = margin.into();
         self
     }
+
+    /// Set the cell the element occupies when its parent uses a [`Content::Grid`]. See [`GridPlacement`].
+    fn grid_placement(mut self, grid_placement: impl Into<GridPlacement>) -> Self {
+        self.get_layout().layout.grid_placement = grid_placement.into();
+        self
+    }
+
+    /// Place the element in the given grid column, starting at `0`.
+    fn grid_column(mut self, column: usize) -> Self {
+        self.get_layout().layout.grid_placement.column = Some(column);
+        self
+    }
+
+    /// Place the element in the given grid row, starting at `0`.
+    fn grid_row(mut self, row: usize) -> Self {
+        self.get_layout().layout.grid_placement.row = Some(row);
+        self
+    }
+
+    /// Make the element span the given number of grid columns and rows.
+    fn grid_span(mut self, columns: usize, rows: usize) -> Self {
+        let grid_placement = &mut self.get_layout().layout.grid_placement;
+        grid_placement.column_span = columns.max(1);
+        grid_placement.row_span = rows.max(1);
+        self
+    }
 }
 
 impl<T: ContainerExt> ContainerPositionExt for T {}
@@ -827,6 +945,93 @@ where
         self.get_text_style_data().text_decoration = Some(text_decoration.into());
         self
     }
+
+    /// Set how the line of the text decoration is drawn, such as wavy. See
+    /// [`TextDecorationStyle`].
+    fn text_decoration_style(
+        mut self,
+        text_decoration_style: impl Into<TextDecorationStyle>,
+    ) -> Self {
+        self.get_text_style_data().text_decoration_style = Some(text_decoration_style.into());
+        self
+    }
+
+    /// Set the color of the text decoration. Defaults to the color of the text.
+    fn text_decoration_color(mut self, text_decoration_color: impl Into<Color>) -> Self {
+        self.get_text_style_data().text_decoration_color = Some(text_decoration_color.into());
+        self
+    }
+
+    /// Set the thickness of the text decoration, relative to the one of the font. See
+    /// [`TextDecorationThickness`].
+    fn text_decoration_thickness(
+        mut self,
+        text_decoration_thickness: impl Into<TextDecorationThickness>,
+    ) -> Self {
+        self.get_text_style_data().text_decoration_thickness =
+            Some(text_decoration_thickness.into());
+        self
+    }
+
+    /// Outline the glyphs instead of filling them. See [`TextStroke`].
+    fn text_stroke(mut self, text_stroke: impl Into<TextStroke>) -> Self {
+        self.get_text_style_data().text_stroke = Some(text_stroke.into());
+        self
+    }
+
+    /// Set whether the children and text are laid out from left to right or from right to left.
+    /// Inherited by the children. See [`LayoutDirection`].
+    fn layout_direction(mut self, layout_direction: impl Into<LayoutDirection>) -> Self {
+        self.get_text_style_data().layout_direction = Some(layout_direction.into());
+        self
+    }
+
+    /// Add extra space between letters. See [`TextSpacing`].
+    fn letter_spacing(mut self, letter_spacing: impl Into<TextSpacing>) -> Self {
+        self.get_text_style_data().letter_spacing = Some(letter_spacing.into());
+        self
+    }
+
+    /// Add extra space between words. See [`TextSpacing`].
+    fn word_spacing(mut self, word_spacing: impl Into<TextSpacing>) -> Self {
+        self.get_text_style_data().word_spacing = Some(word_spacing.into());
+        self
+    }
+
+    /// Toggle an OpenType feature of the font, such as tabular numbers. See [`FontFeature`].
+    fn font_feature(mut self, font_feature: impl Into<FontFeature>) -> Self {
+        self.get_text_style_data()
+            .font_features
+            .push(font_feature.into());
+        self
+    }
+
+    /// Set where lines of text can break, such as between any two letters. See [`WordBreak`].
+    fn word_break(mut self, word_break: impl Into<WordBreak>) -> Self {
+        self.get_text_style_data().word_break = Some(word_break.into());
+        self
+    }
+
+    /// Set how words are hyphenated when lines break inside of them. See [`Hyphens`].
+    fn hyphens(mut self, hyphens: impl Into<Hyphens>) -> Self {
+        self.get_text_style_data().hyphens = Some(hyphens.into());
+        self
+    }
+
+    /// Set the language of the text as a BCP 47 tag, such as `"de"` or `"ja-JP"`, used for the
+    /// line breaking rules and to pick the fallback fonts.
+    fn locale(mut self, locale: impl Into<Cow<'static, str>>) -> Self {
+        self.get_text_style_data().locale = Some(locale.into());
+        self
+    }
+
+    /// Set the value of an axis of a variable font. See [`FontVariation`].
+    fn font_variation(mut self, font_variation: impl Into<FontVariation>) -> Self {
+        self.get_text_style_data()
+            .font_variations
+            .push(font_variation.into());
+        self
+    }
 }
 
 /// Methods for styling an element's box: background, borders, shadows and corners.
@@ -998,7 +1203,8 @@ where
     }
 }
 
-/// Methods for visual effects applied to an element: clipping, blur, rotation, opacity and scale.
+/// Methods for visual effects applied to an element: clipping, masking, blur, filters, rotation,
+/// opacity, blend mode, scale and transforms.
 pub trait EffectExt: Sized {
     /// Returns a mutable reference to the element's effect data.
     fn get_effect(&mut self) -> &mut EffectData;
@@ -1015,12 +1221,54 @@ pub trait EffectExt: Sized {
         self
     }
 
+    /// Clip the element and its descendants to a shape. See [`ClipPath`].
+    ///
+    /// Only supported by [`rect()`](crate::elements::rect::rect).
+    fn clip_path(mut self, clip_path: impl Into<ClipPath>) -> Self {
+        self.get_effect().clip_path = Some(clip_path.into());
+        self
+    }
+
+    /// Mask the element and its descendants with the alpha of a fill or an image. See [`Mask`].
+    ///
+    /// Only supported by [`rect()`](crate::elements::rect::rect).
+    fn mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.get_effect().mask = Some(mask.into());
+        self
+    }
+
     /// Apply a gaussian blur of the given radius to the element.
     fn blur(mut self, blur: f32) -> Self {
         self.get_effect().blur = Some(blur);
         self
     }
 
+    /// Append a filter applied to the element and its descendants, like
+    /// [`Filter::grayscale`]. See [`Filter`].
+    fn filter(mut self, filter: Filter) -> Self {
+        self.get_effect().filters.push(filter);
+        self
+    }
+
+    /// Replace all of the element's filters, applied in order. See [`Filter`].
+    fn filters(mut self, filters: impl IntoIterator<Item = Filter>) -> Self {
+        self.get_effect().filters = filters.into_iter().collect();
+        self
+    }
+
+    /// Append a filter applied to the content behind the element, after the
+    /// [`blur`](Self::blur). See [`Filter`].
+    fn backdrop_filter(mut self, filter: Filter) -> Self {
+        self.get_effect().backdrop_filters.push(filter);
+        self
+    }
+
+    /// Replace all of the element's backdrop filters, applied in order. See [`Filter`].
+    fn backdrop_filters(mut self, filters: impl IntoIterator<Item = Filter>) -> Self {
+        self.get_effect().backdrop_filters = filters.into_iter().collect();
+        self
+    }
+
     /// Rotate the element by the given angle in degrees.
     fn rotation(mut self, rotation: f32) -> Self {
         self.get_effect().rotation = Some(rotation);
@@ -1033,17 +1281,47 @@ pub trait EffectExt: Sized {
         self
     }
 
+    /// Set how the element and its descendants are composited with the content behind them.
+    /// See [`BlendMode`].
+    fn blend_mode(mut self, blend_mode: impl Into<BlendMode>) -> Self {
+        self.get_effect().blend_mode = Some(blend_mode.into());
+        self
+    }
+
     /// Scale the element. See [`Scale`].
     fn scale(mut self, scale: impl Into<Scale>) -> Self {
         self.get_effect().scale = Some(scale.into());
         self
     }
 
-    /// Set the point that the scale and rotation effects pivot around.
+    /// Append a 2D transform, such as a translation or a skew, applied without affecting the
+    /// layout. See [`Transform`].
+    fn transform(mut self, transform: Transform) -> Self {
+        self.get_effect().transforms.push(transform);
+        self
+    }
+
+    /// Replace all of the element's transforms, composed in order. See [`Transform`].
+    fn transforms(mut self, transforms: impl IntoIterator<Item = Transform>) -> Self {
+        self.get_effect().transforms = transforms.into_iter().collect();
+        self
+    }
+
+    /// Set the point that the scale and rotation effects and the transforms pivot around.
     ///
     /// Defaults to the element's center.
     fn transform_origin(mut self, transform_origin: impl Into<TransformOrigin>) -> Self {
         self.get_effect().transform_origin = transform_origin.into();
         self
     }
+
+    /// Render the element and its descendants once into an offscreen layer and reuse it in
+    /// the next frames, until the layout, style or text of any of them changes.
+    ///
+    /// Useful for heavy but static content like long documents or charts.
+    /// Descendants are painted along with the element, on its layer.
+    fn cache_layer(mut self, cache_layer: bool) -> Self {
+        self.get_effect().cache_layer = cache_layer;
+        self
+    }
 }
@@ -0,0 +1,329 @@
+//! Placement of shaped glyphs outside of the horizontal lines of a Skia paragraph, used to lay
+//! text along a path and in vertical writing modes.
+
+use std::ops::Range;
+
+use freya_engine::prelude::*;
+use torin::prelude::{
+    Point2D,
+    Size2D,
+};
+
+use crate::{
+    fonts::FontScript,
+    style::{
+        text_align::TextAlign,
+        writing_mode::WritingMode,
+    },
+};
+
+struct ShapedGlyph {
+    id: GlyphId,
+    advance: f32,
+    character: char,
+}
+
+/// A run of glyphs sharing the same font and color.
+pub(crate) struct ShapedRun {
+    line: usize,
+    font: Font,
+    color: SkColor,
+    glyphs: Vec<ShapedGlyph>,
+}
+
+/// Extract the glyphs of a paragraph laid out in a single line per hard line break.
+/// `colors` maps ranges of the UTF-8 text to the color of their glyphs.
+pub(crate) fn shape(
+    paragraph: &mut SkParagraph,
+    text: &str,
+    colors: &[(Range<usize>, SkColor)],
+) -> Vec<ShapedRun> {
+    let mut runs = Vec::new();
+    paragraph.visit(|line, info| {
+        let Some(info) = info else {
+            return;
+        };
+        let positions = info.positions();
+        let utf8_starts = info.utf8_starts();
+        let run_end = positions.first().map(|p| p.x).unwrap_or_default() + info.advance_x();
+        let glyphs = info
+            .glyphs()
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let next_x = positions.get(i + 1).map(|p| p.x).unwrap_or(run_end);
+                ShapedGlyph {
+                    id: *id,
+                    advance: next_x - positions[i].x,
+                    character: text
+                        .get(utf8_starts[i] as usize..)
+                        .and_then(|text| text.chars().next())
+                        .unwrap_or_default(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let start = utf8_starts.first().copied().unwrap_or_default() as usize;
+        let color = colors
+            .iter()
+            .find(|(range, _)| range.contains(&start))
+            .map(|(_, color)| *color)
+            .unwrap_or(SkColor::WHITE);
+        runs.push(ShapedRun {
+            line,
+            font: info.font().clone(),
+            color,
+            glyphs,
+        });
+    });
+    runs
+}
+
+struct PlacedRun {
+    font: Font,
+    color: SkColor,
+    glyphs: Vec<GlyphId>,
+    xforms: Vec<RSXform>,
+}
+
+/// Glyphs with their final position and rotation, relative to the element origin.
+pub(crate) struct PlacedGlyphs {
+    runs: Vec<PlacedRun>,
+    size: Size2D,
+}
+
+impl PlacedGlyphs {
+    pub(crate) fn size(&self) -> Size2D {
+        self.size
+    }
+
+    pub(crate) fn render(&self, canvas: &Canvas, origin: Point2D) {
+        for run in &self.runs {
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_color(run.color);
+            canvas.draw_glyphs_at(
+                &run.glyphs,
+                run.xforms.as_slice(),
+                (origin.x, origin.y),
+                &run.font,
+                &paint,
+            );
+        }
+    }
+
+    /// Grow the size to fit the box of a glyph placed with the given transform.
+    fn include(&mut self, xform: &RSXform, advance: f32, metrics: &FontMetrics) {
+        for (x, y) in [
+            (0., metrics.ascent),
+            (advance, metrics.ascent),
+            (0., metrics.descent),
+            (advance, metrics.descent),
+        ] {
+            self.size.width = self
+                .size
+                .width
+                .max(xform.scos * x - xform.ssin * y + xform.tx);
+            self.size.height = self
+                .size
+                .height
+                .max(xform.ssin * x + xform.scos * y + xform.ty);
+        }
+    }
+}
+
+/// Place the glyphs one after the other with their baseline along `path`, starting at
+/// `start_offset` and aligned along the path length by `text_align`. Glyphs that don't fit
+/// in the path are left out.
+pub(crate) fn place_along_path(
+    runs: Vec<ShapedRun>,
+    path: &SkPath,
+    start_offset: f32,
+    text_align: TextAlign,
+) -> PlacedGlyphs {
+    let contours = ContourMeasureIter::new(path, false, None).collect::<Vec<_>>();
+    let path_length = contours.iter().map(|contour| contour.length()).sum::<f32>();
+    let text_length = runs
+        .iter()
+        .flat_map(|run| &run.glyphs)
+        .map(|glyph| glyph.advance)
+        .sum::<f32>();
+
+    let mut distance = start_offset
+        + match text_align {
+            TextAlign::Center => (path_length - text_length) / 2.,
+            TextAlign::Right | TextAlign::End => path_length - text_length,
+            _ => 0.,
+        };
+
+    let bounds = path.compute_tight_bounds();
+    let mut placed = PlacedGlyphs {
+        runs: Vec::new(),
+        size: Size2D::new(bounds.right.max(0.), bounds.bottom.max(0.)),
+    };
+
+    for run in runs {
+        let (_, metrics) = run.font.metrics();
+        let mut placed_run = PlacedRun {
+            font: run.font,
+            color: run.color,
+            glyphs: Vec::new(),
+            xforms: Vec::new(),
+        };
+        for glyph in run.glyphs {
+            let middle = distance + glyph.advance / 2.;
+            distance += glyph.advance;
+            if middle < 0. {
+                continue;
+            }
+
+            // Find the contour the middle of the glyph falls in
+            let mut middle = middle;
+            let Some(contour) = contours.iter().find(|contour| {
+                if middle <= contour.length() {
+                    true
+                } else {
+                    middle -= contour.length();
+                    false
+                }
+            }) else {
+                break;
+            };
+            let Some((point, tangent)) = contour.pos_tan(middle) else {
+                continue;
+            };
+
+            let xform = RSXform::new(
+                tangent.x,
+                tangent.y,
+                (
+                    point.x - tangent.x * glyph.advance / 2.,
+                    point.y - tangent.y * glyph.advance / 2.,
+                ),
+            );
+            placed.include(&xform, glyph.advance, &metrics);
+            placed_run.glyphs.push(glyph.id);
+            placed_run.xforms.push(xform);
+        }
+        placed.runs.push(placed_run);
+    }
+
+    placed
+}
+
+/// Place the glyphs in columns of at most `max_height`, starting a new column for every hard
+/// line break. CJK characters and emojis stay upright, the rest are rotated clockwise.
+pub(crate) fn place_vertically(
+    runs: Vec<ShapedRun>,
+    writing_mode: WritingMode,
+    max_height: f32,
+) -> PlacedGlyphs {
+    struct ColumnGlyph {
+        run: usize,
+        id: GlyphId,
+        advance: f32,
+        y: f32,
+        upright: bool,
+    }
+
+    struct Column {
+        width: f32,
+        glyphs: Vec<ColumnGlyph>,
+    }
+
+    let mut columns = vec![Column {
+        width: 0.,
+        glyphs: Vec::new(),
+    }];
+    let mut pen_y = 0f32;
+    let mut height = 0f32;
+    let mut current_line = runs.first().map(|run| run.line).unwrap_or_default();
+
+    let metrics = runs
+        .iter()
+        .map(|run| run.font.metrics().1)
+        .collect::<Vec<_>>();
+
+    for (run_index, run) in runs.iter().enumerate() {
+        let metrics = &metrics[run_index];
+        let line_height = metrics.descent - metrics.ascent;
+        for glyph in &run.glyphs {
+            let upright = matches!(
+                FontScript::of(glyph.character),
+                Some(FontScript::Cjk | FontScript::Emoji)
+            );
+            let advance = if upright {
+                run.font.size()
+            } else {
+                glyph.advance
+            };
+
+            let column = columns.last().unwrap();
+            if run.line != current_line
+                || (!column.glyphs.is_empty() && pen_y + advance > max_height)
+            {
+                current_line = run.line;
+                pen_y = 0.;
+                columns.push(Column {
+                    width: 0.,
+                    glyphs: Vec::new(),
+                });
+            }
+
+            let column = columns.last_mut().unwrap();
+            column.width = column.width.max(line_height);
+            column.glyphs.push(ColumnGlyph {
+                run: run_index,
+                id: glyph.id,
+                advance: glyph.advance,
+                y: pen_y,
+                upright,
+            });
+            pen_y += advance;
+            height = height.max(pen_y);
+        }
+    }
+
+    let width = columns.iter().map(|column| column.width).sum::<f32>();
+    let mut placed = PlacedGlyphs {
+        runs: runs
+            .iter()
+            .map(|run| PlacedRun {
+                font: run.font.clone(),
+                color: run.color,
+                glyphs: Vec::new(),
+                xforms: Vec::new(),
+            })
+            .collect(),
+        size: Size2D::new(width, height),
+    };
+
+    let mut column_start = 0.;
+    for column in columns {
+        let center = match writing_mode {
+            WritingMode::VerticalLr => column_start + column.width / 2.,
+            _ => width - column_start - column.width / 2.,
+        };
+        column_start += column.width;
+
+        for glyph in column.glyphs {
+            let metrics = &metrics[glyph.run];
+            let xform = if glyph.upright {
+                let size = runs[glyph.run].font.size();
+                let baseline = size * -metrics.ascent / (metrics.descent - metrics.ascent);
+                RSXform::new(1., 0., (center - glyph.advance / 2., glyph.y + baseline))
+            } else {
+                // Rotated clockwise, with the top of the glyphs facing the end of the columns
+                RSXform::new(
+                    0.,
+                    1.,
+                    (center + (metrics.ascent + metrics.descent) / 2., glyph.y),
+                )
+            };
+            let run = &mut placed.runs[glyph.run];
+            run.glyphs.push(glyph.id);
+            run.xforms.push(xform);
+        }
+    }
+
+    placed
+}
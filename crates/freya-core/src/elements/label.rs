@@ -8,6 +8,7 @@ use std::{
 
 use freya_engine::prelude::{
     ClipOp,
+    FontCollection,
     FontStyle,
     ParagraphBuilder,
     ParagraphStyle,
@@ -24,6 +25,7 @@ use crate::{
         LayoutData,
         StyleState,
         TextStyleData,
+        TextStyleState,
     },
     diff_key::DiffKey,
     element::{
@@ -34,13 +36,23 @@ use crate::{
         LayoutContext,
         RenderContext,
     },
-    elements::paragraph::paint_paragraph_with_fill,
+    elements::paragraph::{
+        TextOffsets,
+        apply_decorations,
+        apply_typography,
+        paint_paragraph_with_fill,
+        paint_with_overflow_fade,
+        truncate_text,
+    },
+    fonts::FallbackFonts,
     layers::Layer,
     prelude::{
         AccessibilityExt,
         Color,
         ContainerExt,
+        EffectExt,
         EventHandlersExt,
+        Hyphens,
         KeyExt,
         LayerExt,
         LayoutExt,
@@ -93,6 +105,7 @@ pub struct LabelElement {
     pub accessibility: AccessibilityData,
     pub text_style_data: TextStyleData,
     pub layout: LayoutData,
+    pub effect: Option<EffectData>,
     pub event_handlers: EventHandlers,
     pub max_lines: Option<usize>,
     pub line_height: Option<f32>,
@@ -108,6 +121,7 @@ impl Default for LabelElement {
             accessibility,
             text_style_data: Default::default(),
             layout: Default::default(),
+            effect: None,
             event_handlers: Default::default(),
             max_lines: None,
             line_height: None,
@@ -156,6 +170,10 @@ impl ElementExt for LabelElement {
             diff.insert(DiffModifies::LAYOUT);
         }
 
+        if self.effect != label.effect {
+            diff.insert(DiffModifies::EFFECT);
+        }
+
         if self.event_handlers != label.event_handlers {
             diff.insert(DiffModifies::EVENT_HANDLERS);
         }
@@ -168,7 +186,7 @@ impl ElementExt for LabelElement {
     }
 
     fn effect(&'_ self) -> Option<Cow<'_, EffectData>> {
-        None
+        self.effect.as_ref().map(Cow::Borrowed)
     }
 
     fn style(&'_ self) -> Cow<'_, StyleState> {
@@ -211,66 +229,46 @@ impl ElementExt for LabelElement {
             .text_cache
             .utilize(context.node_id, &cached_paragraph)
             .unwrap_or_else(|| {
-                let mut paragraph_style = ParagraphStyle::default();
-                let mut text_style = TextStyle::default();
-
-                let mut font_families = context.text_style_state.font_families.clone();
-                font_families.extend_from_slice(context.fallback_fonts);
-
-                text_style.set_color(
-                    context
-                        .text_style_state
-                        .color
-                        .as_color()
-                        .unwrap_or(Color::WHITE),
-                );
-                text_style.set_font_size(
-                    f32::from(context.text_style_state.font_size) * context.scale_factor as f32,
-                );
-                text_style.set_font_families(&font_families);
-                text_style.set_font_style(FontStyle::new(
-                    context.text_style_state.font_weight.into(),
-                    context.text_style_state.font_width.into(),
-                    context.text_style_state.font_slant.into(),
-                ));
-
-                if context.text_style_state.text_height.needs_custom_height() {
-                    text_style.set_height_override(true);
-                    text_style.set_half_leading(true);
-                }
-
-                if let Some(line_height) = self.line_height {
-                    text_style.set_height_override(true).set_height(line_height);
-                }
-
-                for text_shadow in context.text_style_state.text_shadows.iter() {
-                    text_style.add_shadow((*text_shadow).into());
-                }
-
-                if let Some(ellipsis) = context.text_style_state.text_overflow.get_ellipsis() {
-                    paragraph_style.set_ellipsis(ellipsis);
-                }
-
-                paragraph_style.set_text_style(&text_style);
-                paragraph_style.set_max_lines(self.max_lines);
-                paragraph_style.set_text_align(context.text_style_state.text_align.into());
-
-                let mut paragraph_builder =
-                    ParagraphBuilder::new(&paragraph_style, &*context.font_collection);
-
-                paragraph_builder.add_text(&self.text);
-
-                let mut paragraph = paragraph_builder.build();
-                paragraph.layout(
-                    if self.max_lines == Some(1)
+                let text_overflow = &context.text_style_state.text_overflow;
+                let width = if text_overflow.truncates_inside()
+                    || (self.max_lines == Some(1)
                         && context.text_style_state.text_align == TextAlign::default()
-                        && !paragraph_style.ellipsized()
-                    {
-                        f32::MAX
-                    } else {
-                        context.area_size.width + 1.0
-                    },
-                );
+                        && text_overflow.end_ellipsis().is_none())
+                {
+                    f32::MAX
+                } else {
+                    context.area_size.width + 1.0
+                };
+
+                let text_offsets = self.text_offsets(context.text_style_state);
+                let build_paragraph = |text: &str| {
+                    self.build_paragraph(
+                        text,
+                        &text_offsets,
+                        context.text_style_state,
+                        context.fallback_fonts,
+                        context.scale_factor,
+                        &*context.font_collection,
+                        width,
+                    )
+                };
+
+                let mut paragraph = build_paragraph(&self.text);
+                if text_overflow.truncates_inside()
+                    && paragraph.longest_line() > context.area_size.width
+                {
+                    let ellipsis = text_overflow.get_ellipsis().unwrap_or_default();
+                    let truncated = |head: usize, tail: usize| {
+                        format!("{}{ellipsis}{}", &self.text[..head], &self.text[tail..])
+                    };
+                    let (head, tail) = truncate_text(
+                        &self.text,
+                        text_overflow,
+                        context.area_size.width,
+                        |head, tail| build_paragraph(&truncated(head, tail)).longest_line(),
+                    );
+                    paragraph = build_paragraph(&truncated(head, tail));
+                }
 
                 context
                     .text_cache
@@ -303,15 +301,107 @@ impl ElementExt for LabelElement {
         let layout_data = context.layout_node.data.as_ref().unwrap();
         let paragraph = layout_data.downcast_ref::<SkParagraph>().unwrap();
 
-        paint_paragraph_with_fill(
-            paragraph,
+        // Draw the highlights of the text selection this label is part of
+        if let Some(text_selection) = context.tree.text_selection_of(&context.node_id) {
+            text_selection.render(
+                context.canvas,
+                context.node_id,
+                paragraph,
+                context.layout_node.visible_area().origin,
+            );
+        }
+
+        paint_with_overflow_fade(
             context.canvas,
-            context.layout_node.visible_area().origin,
-            &context.text_style_state.color,
+            context.layout_node.visible_area(),
+            paragraph.longest_line(),
+            context.text_style_state,
+            context.scale_factor,
+            || {
+                paint_paragraph_with_fill(
+                    paragraph,
+                    context.canvas,
+                    context.layout_node.visible_area().origin,
+                    &context.text_style_state.color,
+                )
+            },
         );
     }
 }
 
+impl LabelElement {
+    /// The break opportunities of the text, see [TextOffsets].
+    pub(crate) fn text_offsets(&self, text_style_state: &TextStyleState) -> TextOffsets {
+        // Truncated text is laid out in a single line, where breaks are of no use
+        if text_style_state.text_overflow.truncates_inside() {
+            TextOffsets::default()
+        } else {
+            TextOffsets::new(&self.text, text_style_state)
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_paragraph(
+        &self,
+        text: &str,
+        text_offsets: &TextOffsets,
+        text_style_state: &TextStyleState,
+        fallback_fonts: &FallbackFonts,
+        scale_factor: f64,
+        font_collection: &FontCollection,
+        width: f32,
+    ) -> SkParagraph {
+        let mut paragraph_style = ParagraphStyle::default();
+        let mut text_style = TextStyle::default();
+
+        let mut font_families = text_style_state.font_families.clone();
+        fallback_fonts.extend_families(&mut font_families, text);
+
+        text_style.set_color(text_style_state.color.as_color().unwrap_or(Color::WHITE));
+        text_style.set_font_size(f32::from(text_style_state.font_size) * scale_factor as f32);
+        text_style.set_font_families(&font_families);
+        text_style.set_font_style(FontStyle::new(
+            text_style_state.font_weight.into(),
+            text_style_state.font_width.into(),
+            text_style_state.font_slant.into(),
+        ));
+
+        if text_style_state.text_height.needs_custom_height() {
+            text_style.set_height_override(true);
+            text_style.set_half_leading(true);
+        }
+
+        if let Some(line_height) = self.line_height {
+            text_style.set_height_override(true).set_height(line_height);
+        }
+
+        for text_shadow in text_style_state.text_shadows.iter() {
+            text_style.add_shadow((*text_shadow).into());
+        }
+
+        apply_typography(&mut text_style, text_style_state, scale_factor);
+        apply_decorations(&mut text_style, text_style_state, scale_factor);
+
+        if let Some(ellipsis) = text_style_state.text_overflow.end_ellipsis() {
+            paragraph_style.set_ellipsis(ellipsis);
+        }
+
+        paragraph_style.set_text_style(&text_style);
+        paragraph_style.set_max_lines(self.max_lines);
+        paragraph_style.set_text_align(text_style_state.text_align.into());
+        paragraph_style.set_text_direction(text_style_state.text_direction());
+        paragraph_style.set_render_soft_hyphens(text_style_state.hyphens != Hyphens::None);
+
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+
+        paragraph_builder.add_text(text_offsets.layout_text(text, 0));
+
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(width);
+        paragraph
+    }
+}
+
 impl From<Label> for Element {
     fn from(value: Label) -> Self {
         Element::Element {
@@ -346,6 +436,12 @@ impl TextStyleExt for Label {
     }
 }
 
+impl EffectExt for Label {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
 impl LayerExt for Label {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
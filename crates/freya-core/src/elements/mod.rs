@@ -1,5 +1,7 @@
 pub mod extensions;
+pub(crate) mod glyphs;
 pub mod image;
 pub mod label;
 pub mod paragraph;
 pub mod rect;
+pub mod text_path;
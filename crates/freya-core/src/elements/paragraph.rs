@@ -3,35 +3,52 @@
 use std::{
     any::Any,
     borrow::Cow,
-    cell::RefCell,
+    cell::{
+        Cell,
+        RefCell,
+    },
     fmt::{
         Debug,
         Display,
     },
+    hash::Hash,
+    ops::Range,
     rc::Rc,
 };
 
 use freya_engine::prelude::{
     BlendMode,
     Canvas,
+    Colors,
+    FontArguments,
     FontCollection,
     FontStyle,
+    Gradient,
+    Interpolation,
     Paint,
     PaintStyle,
     ParagraphBuilder,
     ParagraphStyle,
-    PlaceholderAlignment,
     PlaceholderStyle,
     RectHeightStyle,
     RectWidthStyle,
     SaveLayerRec,
+    SkColor,
+    SkColor4f,
     SkParagraph,
+    SkPoint,
     SkRect,
     TextBaseline,
+    TextDirection,
     TextStyle,
+    TileMode,
+    VariationCoordinate,
+    VariationPosition,
+    shaders,
 };
 use torin::prelude::{
     Area,
+    CursorPoint,
     Length,
     Point2D,
     Position,
@@ -54,13 +71,30 @@ use crate::{
     element::{
         Element,
         ElementExt,
+        EventHandlerType,
         EventHandlers,
         IntoElement,
         LayoutContext,
         PostMeasureContext,
         RenderContext,
     },
-    elements::rect::rect,
+    elements::{
+        glyphs::{
+            self,
+            PlacedGlyphs,
+        },
+        rect::rect,
+    },
+    event_handler::EventHandler,
+    events::{
+        data::{
+            Event,
+            PointerEventData,
+            SpanEventData,
+        },
+        name::EventName,
+    },
+    fonts::FallbackFonts,
     layers::Layer,
     node_id::NodeId,
     prelude::{
@@ -69,15 +103,22 @@ use crate::{
         Color,
         ContainerExt,
         ContainerPositionExt,
+        EffectExt,
         EventHandlersExt,
         Fill,
+        Hyphens,
+        InlineAlignment,
         KeyExt,
         LayerExt,
         LayoutExt,
         MaybeExt,
         TextAlign,
+        TextDecoration,
+        TextOverflow,
         TextStyleExt,
         VerticalAlign,
+        WordBreak,
+        WritingMode,
     },
     style::cursor::{
         CursorMode,
@@ -103,9 +144,153 @@ pub fn paragraph() -> Paragraph {
     Paragraph::default()
 }
 
+#[derive(Clone)]
 pub struct ParagraphHolderInner {
     pub paragraph: Rc<SkParagraph>,
     pub scale_factor: f64,
+    /// Indexes of the laid-out text, see [TextOffsets].
+    pub(crate) text_offsets: TextOffsets,
+}
+
+/// Maps the indexes of a text, in UTF-16 code units, to the indexes of its laid-out text, which
+/// has the line break opportunities of [WordBreak] and [Hyphens] as invisible characters.
+///
+/// Cursors, highlights and the queries of a [ParagraphHolder] use the indexes of the text, so
+/// the Skia paragraph is only queried through them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct TextOffsets {
+    /// Index of the text with the character inserted before it, or `None` when the character at
+    /// that index is left out. Sorted by index.
+    edits: Vec<(usize, Option<char>)>,
+}
+
+impl TextOffsets {
+    /// The break opportunities of a text in the given text style.
+    pub(crate) fn new(text: &str, text_style_state: &TextStyleState) -> Self {
+        let mut text_offsets = Self::default();
+        text_offsets.push_text(
+            text,
+            0,
+            text_style_state.word_break,
+            text_style_state.hyphens,
+            text_style_state.locale.as_deref(),
+        );
+        text_offsets
+    }
+
+    /// Adds the break opportunities of a text that starts at the given index.
+    pub(crate) fn push_text(
+        &mut self,
+        text: &str,
+        start: usize,
+        word_break: WordBreak,
+        hyphens: Hyphens,
+        locale: Option<&str>,
+    ) {
+        // Like in CSS, automatic hyphenation depends on the language of the text
+        let auto_hyphens = hyphens == Hyphens::Auto && locale.is_some();
+        if word_break == WordBreak::Normal && hyphens != Hyphens::None && !auto_hyphens {
+            return;
+        }
+
+        let mut index = start;
+        let mut previous = None;
+        for word in text.split_word_bounds() {
+            let hyphenation_opportunities = match locale {
+                Some(locale) if auto_hyphens => Hyphens::opportunities(word, locale),
+                _ => Vec::new(),
+            };
+
+            for (byte_index, grapheme) in word.grapheme_indices(true) {
+                if hyphens == Hyphens::None && grapheme == "\u{AD}" {
+                    self.edits.push((index, None));
+                    index += 1;
+                    continue;
+                }
+                if let Some(previous) = previous {
+                    if let Some(separator) = word_break.separator(previous, grapheme) {
+                        self.edits.push((index, Some(separator)));
+                    } else if hyphenation_opportunities.contains(&byte_index) {
+                        // Soft hyphen
+                        self.edits.push((index, Some('\u{AD}')));
+                    }
+                }
+                index += grapheme.encode_utf16().count();
+                previous = Some(grapheme);
+            }
+        }
+    }
+
+    /// Index of the laid-out text for the given index of the text.
+    pub fn to_layout(&self, index: usize) -> usize {
+        self.shift(index, true)
+    }
+
+    /// Range of the laid-out text for the given range of the text, without the invisible
+    /// characters around it.
+    pub fn to_layout_range(&self, range: Range<usize>) -> Range<usize> {
+        let start = self.to_layout(range.start);
+        start..self.shift(range.end, false).max(start)
+    }
+
+    /// Shifts the index by the characters inserted and left out before it, and optionally by the
+    /// ones inserted right before it.
+    fn shift(&self, index: usize, inserted_before: bool) -> usize {
+        let mut layout_index = index;
+        for (edit_index, edit) in &self.edits {
+            if *edit_index > index || (*edit_index == index && !inserted_before) {
+                break;
+            }
+            match edit {
+                Some(_) => layout_index += 1,
+                None if *edit_index < index => layout_index -= 1,
+                None => {}
+            }
+        }
+        layout_index
+    }
+
+    /// Index of the text for the given index of the laid-out text. Indexes of the inserted
+    /// characters map to the character that follows them.
+    pub fn from_layout(&self, layout_index: usize) -> usize {
+        let mut shift = 0isize;
+        for (index, edit) in &self.edits {
+            if layout_index <= index.saturating_add_signed(shift) {
+                break;
+            }
+            shift += if edit.is_some() { 1 } else { -1 };
+        }
+        layout_index.saturating_add_signed(-shift)
+    }
+
+    /// The laid-out text of a text that starts at the given index.
+    pub fn layout_text<'a>(&self, text: &'a str, start: usize) -> Cow<'a, str> {
+        if self.edits.is_empty() {
+            return Cow::Borrowed(text);
+        }
+
+        let mut edits = self
+            .edits
+            .iter()
+            .skip_while(|(index, _)| *index < start)
+            .peekable();
+        let mut result = String::with_capacity(text.len());
+        let mut index = start;
+        for character in text.chars() {
+            let mut left_out = false;
+            while let Some((_, edit)) = edits.next_if(|(edit_index, _)| *edit_index == index) {
+                match edit {
+                    Some(inserted) => result.push(*inserted),
+                    None => left_out = true,
+                }
+            }
+            if !left_out {
+                result.push(character);
+            }
+            index += character.len_utf16();
+        }
+        Cow::Owned(result)
+    }
 }
 
 /// A shared slot that receives the laid-out paragraph, so callers can hit-test and measure
@@ -131,16 +316,190 @@ impl Default for ParagraphHolder {
     }
 }
 
+/// Metrics of a line of a laid-out [paragraph()], in logical pixels relative to the top-left
+/// corner of the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParagraphLine {
+    /// Zero-based line number.
+    pub index: usize,
+    /// Text of the line, in UTF-16 code units like the cursor indexes, including its line break.
+    pub range: Range<usize>,
+    /// Distance from the top of the text to the baseline of the line.
+    pub baseline: f32,
+    pub ascent: f32,
+    pub descent: f32,
+    pub left: f32,
+    pub width: f32,
+    /// Whether the line ends in a line break rather than wrapping.
+    pub hard_break: bool,
+}
+
+impl ParagraphLine {
+    pub fn top(&self) -> f32 {
+        self.baseline - self.ascent
+    }
+
+    pub fn height(&self) -> f32 {
+        self.ascent + self.descent
+    }
+}
+
+/// Query the laid-out text. Every method returns nothing until the paragraph has been measured,
+/// so call them from event handlers, `on_sized` or effects rather than while rendering. Text in
+/// a vertical [WritingMode] is not queryable, so they return nothing for it too.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let holder = use_state(ParagraphHolder::default);
+///     let mut lines = use_state(|| 0);
+///
+///     paragraph()
+///         .holder(holder.read().clone())
+///         .on_sized(move |_| lines.set(holder.read().line_count()))
+///         .span("Some text that might wrap")
+/// }
+/// ```
+impl ParagraphHolder {
+    fn with_paragraph<T>(
+        &self,
+        with: impl FnOnce(&SkParagraph, f32, &TextOffsets) -> T,
+    ) -> Option<T> {
+        let holder = self.0.borrow();
+        let ParagraphHolderInner {
+            paragraph,
+            scale_factor,
+            text_offsets,
+        } = holder.as_ref()?;
+        Some(with(paragraph, *scale_factor as f32, text_offsets))
+    }
+
+    /// Size of the text, in logical pixels.
+    pub fn size(&self) -> Option<Size2D> {
+        self.with_paragraph(|paragraph, scale_factor, _| {
+            Size2D::new(paragraph.longest_line(), paragraph.height()) / scale_factor
+        })
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.with_paragraph(|paragraph, _, _| paragraph.line_number())
+            .unwrap_or_default()
+    }
+
+    pub fn lines(&self) -> Vec<ParagraphLine> {
+        (0..self.line_count())
+            .filter_map(|index| self.line(index))
+            .collect()
+    }
+
+    pub fn line(&self, index: usize) -> Option<ParagraphLine> {
+        self.with_paragraph(|paragraph, scale_factor, text_offsets| {
+            let line = paragraph.get_line_metrics_at(index)?;
+            // Skia measures lines in UTF-8 bytes, which are at least as many as UTF-16 code units
+            let upper = line.end_including_newline;
+            Some(ParagraphLine {
+                index,
+                range: text_offsets.from_layout(utf16_line_start(paragraph, index, upper))
+                    ..text_offsets.from_layout(utf16_line_start(paragraph, index + 1, upper)),
+                baseline: line.baseline as f32 / scale_factor,
+                ascent: line.ascent as f32 / scale_factor,
+                descent: line.descent as f32 / scale_factor,
+                left: line.left as f32 / scale_factor,
+                width: line.width as f32 / scale_factor,
+                hard_break: line.hard_break,
+            })
+        })
+        .flatten()
+    }
+
+    /// Line number of the character at the given index, in UTF-16 code units.
+    pub fn line_at_index(&self, index: usize) -> Option<usize> {
+        self.with_paragraph(|paragraph, _, text_offsets| {
+            paragraph.get_line_number_at_utf16_offset(text_offsets.to_layout(index))
+        })
+        .flatten()
+    }
+
+    /// Index of the character closest to the given point, in UTF-16 code units. The point
+    /// is relative to the top-left corner of the text, like
+    /// [`element_location`](crate::prelude::PointerEventData::element_location).
+    pub fn index_at(&self, point: impl Into<CursorPoint>) -> Option<usize> {
+        let point = point.into();
+        self.with_paragraph(|paragraph, scale_factor, text_offsets| {
+            let point = (point * scale_factor as f64).cast::<f32>();
+            let position = paragraph
+                .get_glyph_position_at_coordinate((point.x, point.y))
+                .position;
+            text_offsets.from_layout(position.max(0) as usize)
+        })
+    }
+
+    /// Bounding boxes of the text in the given range, in UTF-16 code units. Wrapped ranges
+    /// return a box per line.
+    pub fn rects_for_range(&self, range: Range<usize>) -> Vec<Area> {
+        self.with_paragraph(|paragraph, scale_factor, text_offsets| {
+            paragraph
+                .get_rects_for_range(
+                    text_offsets.to_layout_range(range),
+                    RectHeightStyle::Max,
+                    RectWidthStyle::Tight,
+                )
+                .into_iter()
+                .map(|text_box| {
+                    let rect = text_box.rect;
+                    Area::new(
+                        (rect.left / scale_factor, rect.top / scale_factor).into(),
+                        (rect.width() / scale_factor, rect.height() / scale_factor).into(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+
+    /// Bounding box of the cursor at the given index, in UTF-16 code units, of `text`, the text
+    /// of the paragraph as displayed. See [cursor_character_rect].
+    pub fn cursor_rect(&self, text: &str, index: usize, text_align: TextAlign) -> Option<Area> {
+        self.with_paragraph(|paragraph, scale_factor, text_offsets| {
+            let rect = cursor_character_rect(
+                paragraph,
+                &text_offsets.layout_text(text, 0),
+                text_offsets.to_layout(index),
+                text_align,
+            );
+            Area::new(
+                (rect.left / scale_factor, rect.top / scale_factor).into(),
+                (rect.width() / scale_factor, rect.height() / scale_factor).into(),
+            )
+        })
+    }
+}
+
+/// First UTF-16 offset of the given line, searching up to `upper`.
+fn utf16_line_start(paragraph: &SkParagraph, line: usize, upper: usize) -> usize {
+    let (mut low, mut high) = (0, upper);
+    while low < high {
+        let middle = (low + high) / 2;
+        match paragraph.get_line_number_at_utf16_offset(middle) {
+            Some(middle_line) if middle_line < line => low = middle + 1,
+            _ => high = middle,
+        }
+    }
+    low
+}
+
 /// Marks the order of a [Paragraph]'s content.
 #[derive(PartialEq, Clone)]
 pub enum ParagraphContent {
     Span,
-    Element,
+    /// An inline child, aligned with its line as given.
+    Element(InlineAlignment),
 }
 
 #[derive(PartialEq, Clone)]
 pub struct ParagraphElement {
     pub layout: LayoutData,
+    pub effect: Option<EffectData>,
     pub spans: Vec<Span<'static>>,
     pub contents: Vec<ParagraphContent>,
     pub accessibility: AccessibilityData,
@@ -156,6 +515,13 @@ pub struct ParagraphElement {
     pub cursor_style: CursorStyle,
     pub cursor_mode: CursorMode,
     pub vertical_align: VerticalAlign,
+    pub writing_mode: WritingMode,
+    /// [Self::event_handlers] chained with the handlers of the interactive spans.
+    pub(crate) dispatch_handlers: EventHandlers,
+    /// Index of the interactive span under the pointer.
+    pub(crate) hovered_span: Rc<Cell<Option<usize>>>,
+    /// Distance from the top of the paragraph to the text, from [VerticalAlign], in physical pixels.
+    pub(crate) text_offset: Rc<Cell<f32>>,
 }
 
 impl Default for ParagraphElement {
@@ -164,6 +530,7 @@ impl Default for ParagraphElement {
         accessibility.builder.set_role(accesskit::Role::Paragraph);
         Self {
             layout: Default::default(),
+            effect: None,
             spans: Default::default(),
             contents: Default::default(),
             accessibility,
@@ -179,6 +546,10 @@ impl Default for ParagraphElement {
             cursor_style: CursorStyle::default(),
             cursor_mode: CursorMode::default(),
             vertical_align: VerticalAlign::default(),
+            writing_mode: WritingMode::default(),
+            dispatch_handlers: Default::default(),
+            hovered_span: Rc::default(),
+            text_offset: Rc::default(),
         }
     }
 }
@@ -231,23 +602,37 @@ impl ElementExt for ParagraphElement {
             diff.insert(DiffModifies::STYLE);
         }
 
-        if self.event_handlers != paragraph.event_handlers {
+        // Handlers never compare equal, and the ones of the spans aren't compared by them
+        if self.event_handlers != paragraph.event_handlers
+            || self.has_interactive_spans()
+            || paragraph.has_interactive_spans()
+        {
             diff.insert(DiffModifies::EVENT_HANDLERS);
         }
 
+        if self.effect != paragraph.effect {
+            diff.insert(DiffModifies::EFFECT);
+        }
+
         if self.cursor_index != paragraph.cursor_index
             || self.highlights != paragraph.highlights
             || self.cursor_mode != paragraph.cursor_mode
             || self.cursor_style != paragraph.cursor_style
             || self.cursor_style_data != paragraph.cursor_style_data
-            || self.vertical_align != paragraph.vertical_align
         {
             diff.insert(DiffModifies::STYLE);
         }
 
+        // The text offset is resolved after measuring
+        if self.vertical_align != paragraph.vertical_align {
+            diff.insert(DiffModifies::STYLE);
+            diff.insert(DiffModifies::LAYOUT);
+        }
+
         if self.text_style_data != paragraph.text_style_data
             || self.line_height != paragraph.line_height
             || self.max_lines != paragraph.max_lines
+            || self.writing_mode != paragraph.writing_mode
         {
             diff.insert(DiffModifies::TEXT_STYLE);
             diff.insert(DiffModifies::LAYOUT);
@@ -261,11 +646,26 @@ impl ElementExt for ParagraphElement {
         diff
     }
 
+    fn inherit(&self, previous: &Rc<dyn ElementExt>) {
+        let Some(paragraph) = (previous.as_ref() as &dyn Any).downcast_ref::<ParagraphElement>()
+        else {
+            return;
+        };
+
+        if self.sk_paragraph != paragraph.sk_paragraph
+            && let Some(holder) = paragraph.sk_paragraph.0.borrow().as_ref()
+        {
+            self.sk_paragraph.0.borrow_mut().replace(holder.clone());
+        }
+        self.hovered_span.set(paragraph.hovered_span.get());
+        self.text_offset.set(paragraph.text_offset.get());
+    }
+
     fn layout(&'_ self) -> Cow<'_, LayoutData> {
         Cow::Borrowed(&self.layout)
     }
     fn effect(&'_ self) -> Option<Cow<'_, EffectData>> {
-        None
+        self.effect.as_ref().map(Cow::Borrowed)
     }
 
     fn style(&'_ self) -> Cow<'_, StyleState> {
@@ -298,6 +698,19 @@ impl ElementExt for ParagraphElement {
     }
 
     fn measure(&self, context: LayoutContext) -> Option<(Size2D, Rc<dyn Any>)> {
+        if self.writing_mode.is_vertical() {
+            return Some(self.measure_vertical(context));
+        }
+
+        let text_overflow = &context.text_style_state.text_overflow;
+        let truncates_inside = text_overflow.truncates_inside() && !self.has_inline_content();
+        // Truncated text is laid out in a single line, where breaks are of no use
+        let text_offsets = if truncates_inside {
+            TextOffsets::default()
+        } else {
+            self.text_offsets(context.text_style_state)
+        };
+
         let cached_paragraph = CachedParagraph {
             text_style_state: context.text_style_state,
             spans: &self.spans,
@@ -309,27 +722,42 @@ impl ElementExt for ParagraphElement {
             .text_cache
             .utilize(context.node_id, &cached_paragraph)
             .unwrap_or_else(|| {
-                let width = if self.max_lines == Some(1)
-                    && context.text_style_state.text_align == TextAlign::default()
-                    && context
-                        .text_style_state
-                        .text_overflow
-                        .get_ellipsis()
-                        .is_none()
+                let width = if truncates_inside
+                    || (self.max_lines == Some(1)
+                        && context.text_style_state.text_align == TextAlign::default()
+                        && text_overflow.end_ellipsis().is_none())
                 {
                     f32::MAX
                 } else {
                     context.area_size.width + 1.0
                 };
 
-                let paragraph = self.build_paragraph(
-                    context.text_style_state,
-                    context.fallback_fonts,
-                    context.scale_factor,
-                    context.font_collection,
-                    width,
-                    &[],
-                );
+                let build_paragraph = |spans: &[Span]| {
+                    self.build_paragraph(
+                        spans,
+                        &text_offsets,
+                        context.text_style_state,
+                        context.fallback_fonts,
+                        context.scale_factor,
+                        &*context.font_collection,
+                        width,
+                        &[],
+                    )
+                };
+
+                let mut paragraph = build_paragraph(&self.spans);
+                if truncates_inside && paragraph.longest_line() > context.area_size.width {
+                    let (head, tail) = truncate_text(
+                        &self.text(),
+                        text_overflow,
+                        context.area_size.width,
+                        |head, tail| {
+                            build_paragraph(&self.truncated_spans(head, tail, text_overflow))
+                                .longest_line()
+                        },
+                    );
+                    paragraph = build_paragraph(&self.truncated_spans(head, tail, text_overflow));
+                }
                 context
                     .text_cache
                     .insert(context.node_id, &cached_paragraph, paragraph)
@@ -343,6 +771,7 @@ impl ElementExt for ParagraphElement {
             .replace(ParagraphHolderInner {
                 paragraph,
                 scale_factor: context.scale_factor,
+                text_offsets,
             });
 
         Some((size, Rc::new(())))
@@ -353,15 +782,22 @@ impl ElementExt for ParagraphElement {
     }
 
     fn should_measure_inner_children(&self) -> bool {
-        self.has_inline_content()
+        self.has_inline_content() && !self.writing_mode.is_vertical()
     }
 
     fn needs_post_measure(&self) -> bool {
-        self.has_inline_content()
+        (self.has_inline_content() || self.has_interactive_spans())
+            && !self.writing_mode.is_vertical()
     }
 
     fn post_measure(&self, context: PostMeasureContext) -> PostMeasure<NodeId> {
+        let visible_area = context.node_layout.visible_area();
+
         if context.children.is_empty() {
+            if let Some(holder) = self.sk_paragraph.0.borrow().as_ref() {
+                self.text_offset
+                    .set(self.vertical_offset(&visible_area, holder.paragraph.height()));
+            }
             return PostMeasure::default();
         }
 
@@ -377,15 +813,17 @@ impl ElementExt for ParagraphElement {
             })
             .collect();
 
-        let width = self
+        let (width, text_offsets) = self
             .sk_paragraph
             .0
             .borrow()
             .as_ref()
-            .map(|holder| holder.paragraph.max_width())
+            .map(|holder| (holder.paragraph.max_width(), holder.text_offsets.clone()))
             .unwrap();
 
         let paragraph = self.build_paragraph(
+            &self.spans,
+            &text_offsets,
             context.text_style_state,
             context.fallback_fonts,
             context.scale_factor,
@@ -404,13 +842,11 @@ impl ElementExt for ParagraphElement {
             .replace(ParagraphHolderInner {
                 paragraph: Rc::new(paragraph),
                 scale_factor: context.scale_factor,
+                text_offsets,
             });
 
-        let visible_area = context.node_layout.visible_area();
-        let vertical_offset = match self.vertical_align {
-            VerticalAlign::Start => 0.0,
-            VerticalAlign::Center => (visible_area.height() - paragraph_height).max(0.0) / 2.0,
-        };
+        let vertical_offset = self.vertical_offset(&visible_area, paragraph_height);
+        self.text_offset.set(vertical_offset);
         let origin = visible_area.origin;
 
         let mut offsets = Vec::new();
@@ -438,25 +874,35 @@ impl ElementExt for ParagraphElement {
     }
 
     fn events_handlers(&'_ self) -> Option<Cow<'_, EventHandlers>> {
-        Some(Cow::Borrowed(&self.event_handlers))
+        Some(Cow::Borrowed(&self.dispatch_handlers))
     }
 
     fn render(&self, context: RenderContext) {
+        if let Some(placed_glyphs) = context
+            .layout_node
+            .data
+            .as_ref()
+            .and_then(|data| data.downcast_ref::<PlacedGlyphs>())
+        {
+            placed_glyphs.render(context.canvas, context.layout_node.visible_area().origin);
+            return;
+        }
+
         let paragraph = self.sk_paragraph.0.borrow();
-        let ParagraphHolderInner { paragraph, .. } = paragraph.as_ref().unwrap();
+        let ParagraphHolderInner {
+            paragraph,
+            text_offsets,
+            ..
+        } = paragraph.as_ref().unwrap();
         let visible_area = context.layout_node.visible_area();
+        let layout_text = || text_offsets.layout_text(&self.text(), 0).into_owned();
 
         let cursor_area = match self.cursor_mode {
             CursorMode::Fit => visible_area,
             CursorMode::Expanded => context.layout_node.area,
         };
 
-        let paragraph_height = paragraph.height();
-        let area_height = visible_area.height();
-        let vertical_offset = match self.vertical_align {
-            VerticalAlign::Start => 0.0,
-            VerticalAlign::Center => (area_height - paragraph_height).max(0.0) / 2.0,
-        };
+        let vertical_offset = self.vertical_offset(&visible_area, paragraph.height());
 
         let cursor_vertical_offset = match self.cursor_mode {
             CursorMode::Fit => vertical_offset,
@@ -483,7 +929,7 @@ impl ElementExt for ParagraphElement {
             }
             let (from, to) = { if from < to { (from, to) } else { (to, from) } };
             let rects = paragraph.get_rects_for_range(
-                *from..*to,
+                text_offsets.to_layout_range(*from..*to),
                 RectHeightStyle::Tight,
                 RectWidthStyle::Tight,
             );
@@ -496,8 +942,8 @@ impl ElementExt for ParagraphElement {
             if rects.is_empty() && *from == 0 {
                 let caret_rect = cursor_character_rect(
                     paragraph,
-                    &self.text(),
-                    *from,
+                    &layout_text(),
+                    text_offsets.to_layout(*from),
                     context.text_style_state.text_align,
                 );
                 context
@@ -530,8 +976,8 @@ impl ElementExt for ParagraphElement {
         {
             let mut cursor_rect = cursor_character_rect(
                 paragraph,
-                &self.text(),
-                cursor_index,
+                &layout_text(),
+                text_offsets.to_layout(cursor_index),
                 context.text_style_state.text_align,
             );
             let width = (cursor_rect.right - cursor_rect.left).max(6.0);
@@ -541,12 +987,28 @@ impl ElementExt for ParagraphElement {
                 .draw_rect(to_cursor_area(cursor_rect), &cursor_paint);
         }
 
+        let origin = Point2D::new(visible_area.min_x(), visible_area.min_y() + vertical_offset);
+
+        // Draw the highlights of the text selection this paragraph is part of
+        if let Some(text_selection) = context.tree.text_selection_of(&context.node_id) {
+            text_selection.render(context.canvas, context.node_id, paragraph, origin);
+        }
+
         // Draw text
-        paint_paragraph_with_fill(
-            paragraph,
+        paint_with_overflow_fade(
             context.canvas,
-            Point2D::new(visible_area.min_x(), visible_area.min_y() + vertical_offset),
-            &context.text_style_state.color,
+            visible_area,
+            paragraph.longest_line(),
+            context.text_style_state,
+            context.scale_factor,
+            || {
+                paint_paragraph_with_fill(
+                    paragraph,
+                    context.canvas,
+                    origin,
+                    &context.text_style_state.color,
+                )
+            },
         );
 
         // Draw cursor
@@ -556,8 +1018,8 @@ impl ElementExt for ParagraphElement {
         {
             let mut cursor_rect = cursor_character_rect(
                 paragraph,
-                &self.text(),
-                cursor_index,
+                &layout_text(),
+                text_offsets.to_layout(cursor_index),
                 context.text_style_state.text_align,
             );
             match self.cursor_style {
@@ -572,14 +1034,164 @@ impl ElementExt for ParagraphElement {
 }
 
 impl ParagraphElement {
+    /// Forward the pointer events of the paragraph to its interactive spans. The chained handlers
+    /// are built once per element, and only whether there are any is compared when diffing.
+    fn attach_span_handlers(&mut self) {
+        self.dispatch_handlers = self.event_handlers.clone();
+
+        let mut targets = Vec::new();
+        let mut start = 0;
+        let mut spans = self.spans.iter().enumerate();
+        for content in &self.contents {
+            match content {
+                ParagraphContent::Span => {
+                    let Some((index, span)) = spans.next() else {
+                        continue;
+                    };
+                    let end = start + span.text.encode_utf16().count();
+                    if span.is_interactive() {
+                        targets.push(SpanTarget {
+                            index,
+                            range: start..end,
+                            id: span.id.clone(),
+                            event_handlers: span.event_handlers.clone(),
+                        });
+                    }
+                    start = end;
+                }
+                ParagraphContent::Element(_) => start += 1,
+            }
+        }
+
+        if targets.is_empty() {
+            return;
+        }
+
+        let targets = Rc::new(SpanTargets {
+            targets,
+            holder: self.sk_paragraph.clone(),
+            hovered: self.hovered_span.clone(),
+            text_offset: self.text_offset.clone(),
+        });
+
+        chain_pointer_handler(&mut self.dispatch_handlers, EventName::PointerPress, {
+            let targets = targets.clone();
+            move |e| {
+                if e.is_primary()
+                    && let Some(target) = targets.target_at(e.element_location())
+                    && let Some(on_press) = &target.event_handlers.on_press
+                {
+                    on_press.call(target.event(e));
+                }
+            }
+        });
+        chain_pointer_handler(&mut self.dispatch_handlers, EventName::PointerMove, {
+            let targets = targets.clone();
+            move |e| {
+                let target = targets.target_at(e.element_location());
+                targets.hover(target.map(|target| target.index), e);
+            }
+        });
+        chain_pointer_handler(
+            &mut self.dispatch_handlers,
+            EventName::PointerLeave,
+            move |e| {
+                targets.hover(None, e);
+            },
+        );
+    }
+
+    /// Lays the spans out in columns, see [WritingMode].
+    fn measure_vertical(&self, context: LayoutContext) -> (Size2D, Rc<dyn Any>) {
+        #[cfg(debug_assertions)]
+        self.warn_unsupported_vertical_features(context.text_style_state);
+
+        let mut paragraph_style = ParagraphStyle::default();
+        paragraph_style.set_text_style(&base_text_style(
+            context.text_style_state,
+            context.fallback_fonts,
+            context.scale_factor,
+            self.line_height,
+        ));
+
+        let mut paragraph_builder =
+            ParagraphBuilder::new(&paragraph_style, &*context.font_collection);
+        let mut text = String::new();
+        let mut colors = Vec::new();
+        for span in &self.spans {
+            let text_style = span_text_style(
+                context.text_style_state,
+                context.fallback_fonts,
+                context.scale_factor,
+                span,
+                self.line_height,
+            );
+            let start = text.len();
+            text.push_str(&span.text);
+            colors.push((start..text.len(), text_style.color()));
+            paragraph_builder.push_style(&text_style);
+            paragraph_builder.add_text(&span.text);
+        }
+
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(f32::MAX);
+
+        let runs = glyphs::shape(&mut paragraph, &text, &colors);
+        let placed_glyphs =
+            glyphs::place_vertically(runs, self.writing_mode, context.area_size.height);
+
+        // The paragraph is laid out in a single line, unlike the glyphs, so it's not queryable
+        self.sk_paragraph.0.borrow_mut().take();
+
+        (placed_glyphs.size(), Rc::new(placed_glyphs))
+    }
+
+    /// Warn about the features in use that the vertical modes ignore, see [Paragraph::writing_mode].
+    #[cfg(debug_assertions)]
+    fn warn_unsupported_vertical_features(&self, text_style_state: &TextStyleState) {
+        let has_decorations = text_style_state.text_decoration != TextDecoration::None
+            || self.spans.iter().any(|span| {
+                span.text_style_data
+                    .text_decoration
+                    .is_some_and(|text_decoration| text_decoration != TextDecoration::None)
+            });
+
+        for (in_use, feature) in [
+            (self.cursor_index.is_some(), "cursor"),
+            (!self.highlights.is_empty(), "highlights"),
+            (has_decorations, "text decorations"),
+            (self.has_inline_content(), "inline children"),
+            (self.has_interactive_spans(), "interactive spans"),
+        ] {
+            if in_use {
+                tracing::warn!(
+                    "The {feature} of a paragraph are ignored in the {:?} writing mode.",
+                    self.writing_mode
+                );
+            }
+        }
+    }
+
+    fn has_interactive_spans(&self) -> bool {
+        self.spans.iter().any(Span::is_interactive)
+    }
+
     fn has_inline_content(&self) -> bool {
         self.contents
             .iter()
-            .any(|content| matches!(content, ParagraphContent::Element))
+            .any(|content| matches!(content, ParagraphContent::Element(_)))
+    }
+
+    /// Distance from the top of the visible area to the text, from [VerticalAlign].
+    pub(crate) fn vertical_offset(&self, visible_area: &Area, paragraph_height: f32) -> f32 {
+        match self.vertical_align {
+            VerticalAlign::Start => 0.0,
+            VerticalAlign::Center => (visible_area.height() - paragraph_height).max(0.0) / 2.0,
+        }
     }
 
     /// The paragraph text as Skia indexes it, with inline children as placeholder characters.
-    fn text(&self) -> String {
+    pub(crate) fn text(&self) -> String {
         let mut text = String::new();
         let mut spans = self.spans.iter();
         for content in &self.contents {
@@ -589,18 +1201,88 @@ impl ParagraphElement {
                         text.push_str(&span.text);
                     }
                 }
-                ParagraphContent::Element => text.push('\u{FFFC}'),
+                ParagraphContent::Element(_) => text.push('\u{FFFC}'),
             }
         }
         text
     }
 
-    /// Builds the Skia paragraph from the content, reserving a placeholder (sized from
+    /// The break opportunities of [Self::text], see [TextOffsets].
+    fn text_offsets(&self, text_style_state: &TextStyleState) -> TextOffsets {
+        let mut text_offsets = TextOffsets::default();
+        let mut start = 0;
+        let mut spans = self.spans.iter();
+        for content in &self.contents {
+            match content {
+                ParagraphContent::Span => {
+                    let Some(span) = spans.next() else { continue };
+                    let text_style_data = &span.text_style_data;
+                    text_offsets.push_text(
+                        &span.text,
+                        start,
+                        text_style_data
+                            .word_break
+                            .unwrap_or(text_style_state.word_break),
+                        text_style_data.hyphens.unwrap_or(text_style_state.hyphens),
+                        text_style_data
+                            .locale
+                            .as_deref()
+                            .or(text_style_state.locale.as_deref()),
+                    );
+                    start += span.text.encode_utf16().count();
+                }
+                ParagraphContent::Element(_) => start += 1,
+            }
+        }
+        text_offsets
+    }
+
+    /// Keeps the text of the spans before the byte `head` and after the byte `tail` of
+    /// [Self::text], joined by the ellipsis of `text_overflow` in the style of the span it cuts.
+    fn truncated_spans(
+        &self,
+        head: usize,
+        tail: usize,
+        text_overflow: &TextOverflow,
+    ) -> Vec<Span<'static>> {
+        let mut ellipsis = text_overflow.get_ellipsis();
+        // The ellipsis follows the head, or leads the tail when there is no head
+        let ellipsis_at = if head > 0 { head } else { tail };
+        let mut spans = Vec::new();
+        let mut start = 0;
+        for span in &self.spans {
+            let end = start + span.text.len();
+            let mut text = String::new();
+            if start < head {
+                text.push_str(&span.text[..head.min(end) - start]);
+            }
+            if (start..=end).contains(&ellipsis_at)
+                && let Some(ellipsis) = ellipsis.take()
+            {
+                text.push_str(ellipsis);
+            }
+            if end > tail {
+                text.push_str(&span.text[tail.max(start) - start..]);
+            }
+            if !text.is_empty() {
+                let mut span = span.clone();
+                span.text = text.into();
+                spans.push(span);
+            }
+            start = end;
+        }
+        spans
+    }
+
+    /// Builds the Skia paragraph from `spans` and the inline children, reserving a placeholder (sized from
     /// `placeholders`, in order) for each inline child, laid out against `width`.
+    #[allow(clippy::too_many_arguments)]
     fn build_paragraph(
         &self,
+        spans: &[Span],
+        text_offsets: &TextOffsets,
         text_style_state: &TextStyleState,
-        fallback_fonts: &[Cow<'static, str>],
+        fallback_fonts: &FallbackFonts,
         scale_factor: f64,
         font_collection: &FontCollection,
         width: f32,
@@ -608,7 +1290,7 @@ impl ParagraphElement {
     ) -> SkParagraph {
         let mut paragraph_style = ParagraphStyle::default();
 
-        if let Some(ellipsis) = text_style_state.text_overflow.get_ellipsis() {
+        if let Some(ellipsis) = text_style_state.text_overflow.end_ellipsis() {
             paragraph_style.set_ellipsis(ellipsis);
         }
 
@@ -620,10 +1302,13 @@ impl ParagraphElement {
         ));
         paragraph_style.set_max_lines(self.max_lines);
         paragraph_style.set_text_align(text_style_state.text_align.into());
+        paragraph_style.set_text_direction(text_style_state.text_direction());
+        paragraph_style.set_render_soft_hyphens(text_style_state.hyphens != Hyphens::None);
 
         let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
-        let mut spans = self.spans.iter();
+        let mut start = 0;
+        let mut spans = spans.iter();
         let mut placeholders = placeholders.iter();
         for content in &self.contents {
             match content {
@@ -636,18 +1321,24 @@ impl ParagraphElement {
                         span,
                         self.line_height,
                     ));
-                    paragraph_builder.add_text(&span.text);
+                    paragraph_builder.add_text(text_offsets.layout_text(&span.text, start));
+                    start += span.text.encode_utf16().count();
                 }
-                ParagraphContent::Element => {
+                ParagraphContent::Element(alignment) => {
+                    start += 1;
                     let Some(size) = placeholders.next() else {
                         continue;
                     };
+                    let baseline_offset = match alignment {
+                        InlineAlignment::Baseline(offset) => *offset * scale_factor as f32,
+                        _ => 0.0,
+                    };
                     paragraph_builder.add_placeholder(&PlaceholderStyle::new(
                         size.width,
                         size.height,
-                        PlaceholderAlignment::Middle,
+                        (*alignment).into(),
                         TextBaseline::Alphabetic,
-                        0.0,
+                        baseline_offset,
                     ));
                 }
             }
@@ -717,8 +1408,107 @@ pub fn cursor_character_rect(
     SkRect::new(left, 0., left + 6., paragraph.height())
 }
 
+/// A [Span] with event handlers, and the range of the paragraph text it covers.
+struct SpanTarget {
+    index: usize,
+    /// In UTF-16 code units, like Skia indexes the text.
+    range: Range<usize>,
+    id: Option<Cow<'static, str>>,
+    event_handlers: SpanEventHandlers,
+}
+
+impl SpanTarget {
+    fn event(&self, e: Event<PointerEventData>) -> Event<SpanEventData> {
+        e.map(|pointer| SpanEventData {
+            id: self.id.clone(),
+            index: self.index,
+            pointer,
+        })
+    }
+}
+
+struct SpanTargets {
+    targets: Vec<SpanTarget>,
+    holder: ParagraphHolder,
+    hovered: Rc<Cell<Option<usize>>>,
+    text_offset: Rc<Cell<f32>>,
+}
+
+impl SpanTargets {
+    /// The span painted at the given location, relative to the visible area of the paragraph,
+    /// which already leaves out its margin.
+    fn target_at(&self, location: CursorPoint) -> Option<&SpanTarget> {
+        let holder = self.holder.0.borrow();
+        let ParagraphHolderInner {
+            paragraph,
+            scale_factor,
+            text_offsets,
+        } = holder.as_ref()?;
+        let x = (location.x * scale_factor) as f32;
+        let y = (location.y * scale_factor) as f32 - self.text_offset.get();
+
+        self.targets.iter().find(|target| {
+            paragraph
+                .get_rects_for_range(
+                    text_offsets.to_layout_range(target.range.clone()),
+                    RectHeightStyle::Max,
+                    RectWidthStyle::Tight,
+                )
+                .iter()
+                .any(|text_box| {
+                    let rect = text_box.rect;
+                    rect.left <= x && x < rect.right && rect.top <= y && y < rect.bottom
+                })
+        })
+    }
+
+    /// Move the hover to the span with the given index, notifying the spans that the pointer
+    /// left and entered.
+    fn hover(&self, index: Option<usize>, e: Event<PointerEventData>) {
+        let previous = self.hovered.replace(index);
+        if previous == index {
+            return;
+        }
+
+        let target = |index| self.targets.iter().find(|target| target.index == index);
+        if let Some(target) = previous.and_then(target)
+            && let Some(on_pointer_leave) = &target.event_handlers.on_pointer_leave
+        {
+            on_pointer_leave.call(target.event(e.clone()));
+        }
+        if let Some(target) = index.and_then(target)
+            && let Some(on_pointer_enter) = &target.event_handlers.on_pointer_enter
+        {
+            on_pointer_enter.call(target.event(e));
+        }
+    }
+}
+
+/// Run `handler` after the pointer handler already registered for `name`, if any.
+fn chain_pointer_handler(
+    event_handlers: &mut EventHandlers,
+    name: EventName,
+    mut handler: impl FnMut(Event<PointerEventData>) + 'static,
+) {
+    let previous = match event_handlers.remove(&name) {
+        Some(EventHandlerType::Pointer(previous)) => Some(previous),
+        _ => None,
+    };
+    event_handlers.insert(
+        name,
+        EventHandlerType::Pointer(EventHandler::new(move |e: Event<PointerEventData>| {
+            if let Some(previous) = &previous {
+                previous.call(e.clone());
+            }
+            handler(e);
+        })),
+    );
+}
+
 impl From<Paragraph> for Element {
-    fn from(value: Paragraph) -> Self {
+    fn from(mut value: Paragraph) -> Self {
+        value.element.attach_span_handlers();
+
         let elements = value
             .children
             .into_iter()
@@ -741,14 +1531,14 @@ impl From<Paragraph> for Element {
 /// Builds the paragraph-level base [TextStyle] from the inherited text style state.
 fn base_text_style(
     text_style_state: &TextStyleState,
-    fallback_fonts: &[Cow<'static, str>],
+    fallback_fonts: &FallbackFonts,
     scale_factor: f64,
     line_height: Option<f32>,
 ) -> TextStyle {
     let mut text_style = TextStyle::default();
 
     let mut font_families = text_style_state.font_families.clone();
-    font_families.extend_from_slice(fallback_fonts);
+    font_families.extend(fallback_fonts.iter().cloned());
 
     text_style.set_color(text_style_state.color.as_color().unwrap_or(Color::WHITE));
     text_style.set_font_size(f32::from(text_style_state.font_size) * scale_factor as f32);
@@ -773,13 +1563,16 @@ fn base_text_style(
         text_style.add_shadow((*text_shadow).into());
     }
 
+    apply_typography(&mut text_style, text_style_state, scale_factor);
+    apply_decorations(&mut text_style, text_style_state, scale_factor);
+
     text_style
 }
 
 /// Builds the [TextStyle] for a single [Span], layering its overrides over the inherited state.
-fn span_text_style(
+pub(crate) fn span_text_style(
     text_style_state: &TextStyleState,
-    fallback_fonts: &[Cow<'static, str>],
+    fallback_fonts: &FallbackFonts,
     scale_factor: f64,
     span: &Span,
     line_height: Option<f32>,
@@ -787,7 +1580,7 @@ fn span_text_style(
     let span_style = TextStyleState::from_data(text_style_state, &span.text_style_data);
     let mut text_style = TextStyle::new();
     let mut font_families = text_style_state.font_families.clone();
-    font_families.extend_from_slice(fallback_fonts);
+    fallback_fonts.extend_families(&mut font_families, &span.text);
 
     for text_shadow in span_style.text_shadows.iter() {
         text_style.add_shadow((*text_shadow).into());
@@ -801,7 +1594,8 @@ fn span_text_style(
         span_style.font_width.into(),
         span_style.font_slant.into(),
     ));
-    text_style.set_decoration_type(span_style.text_decoration.into());
+    apply_typography(&mut text_style, &span_style, scale_factor);
+    apply_decorations(&mut text_style, &span_style, scale_factor);
     if let Some(line_height) = line_height {
         text_style.set_height_override(true);
         text_style.set_height(line_height);
@@ -809,6 +1603,63 @@ fn span_text_style(
     text_style
 }
 
+/// Applies the letter and word spacing, OpenType features, variable font axes and locale of the
+/// text style state to a [TextStyle].
+pub(crate) fn apply_typography(
+    text_style: &mut TextStyle,
+    text_style_state: &TextStyleState,
+    scale_factor: f64,
+) {
+    text_style.set_letter_spacing(f32::from(text_style_state.letter_spacing) * scale_factor as f32);
+    text_style.set_word_spacing(f32::from(text_style_state.word_spacing) * scale_factor as f32);
+
+    for font_feature in text_style_state.font_features.iter() {
+        text_style.add_font_feature(&font_feature.tag, font_feature.value);
+    }
+
+    if let Some(locale) = &text_style_state.locale {
+        text_style.set_locale(locale);
+    }
+
+    if !text_style_state.font_variations.is_empty() {
+        let coordinates = text_style_state
+            .font_variations
+            .iter()
+            .map(VariationCoordinate::from)
+            .collect::<Vec<_>>();
+        let font_arguments =
+            FontArguments::new().set_variation_design_position(VariationPosition {
+                coordinates: &coordinates,
+            });
+        text_style.set_font_arguments(&font_arguments);
+    }
+}
+
+/// Applies the text decoration and the text stroke of the text style state to a [TextStyle].
+pub(crate) fn apply_decorations(
+    text_style: &mut TextStyle,
+    text_style_state: &TextStyleState,
+    scale_factor: f64,
+) {
+    text_style.set_decoration_type(text_style_state.text_decoration.into());
+    text_style.set_decoration_style(text_style_state.text_decoration_style.into());
+    text_style
+        .set_decoration_thickness_multiplier(text_style_state.text_decoration_thickness.into());
+    // Otherwise Skia uses the color of the text
+    if let Some(text_decoration_color) = text_style_state.text_decoration_color {
+        text_style.set_decoration_color(text_decoration_color);
+    }
+
+    if let Some(text_stroke) = text_style_state.text_stroke {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(text_stroke.width * scale_factor as f32);
+        paint.set_color(text_stroke.color);
+        text_style.set_foreground_paint(&paint);
+    }
+}
+
 /// Paints a paragraph with a [Fill] as the text color. Non-color fills are masked
 /// onto the rendered glyph alpha via an offscreen layer + [BlendMode::SrcIn].
 pub(crate) fn paint_paragraph_with_fill(
@@ -842,6 +1693,95 @@ pub(crate) fn paint_paragraph_with_fill(
     canvas.restore_to_count(layer);
 }
 
+/// Width of the faded out edge of [TextOverflow::Fade], in logical pixels.
+const FADE_WIDTH: f32 = 32.;
+
+/// Paints text with `paint` and, for [TextOverflow::Fade], fades out the end edge of `area`
+/// when the text is `text_width` wide and doesn't fit in it.
+pub(crate) fn paint_with_overflow_fade(
+    canvas: &Canvas,
+    area: Area,
+    text_width: f32,
+    text_style_state: &TextStyleState,
+    scale_factor: f64,
+    paint: impl FnOnce(),
+) {
+    if text_style_state.text_overflow != TextOverflow::Fade || text_width <= area.width() + 0.5 {
+        paint();
+        return;
+    }
+
+    let bounds_rect = SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+    let layer = canvas.save_layer(&SaveLayerRec::default().bounds(&bounds_rect));
+
+    paint();
+
+    let fade_width = (FADE_WIDTH * scale_factor as f32).min(area.width() / 2.);
+    let (from, to) = match text_style_state.text_direction() {
+        TextDirection::RTL => (area.min_x() + fade_width, area.min_x()),
+        TextDirection::LTR => (area.max_x() - fade_width, area.max_x()),
+    };
+    let colors = [
+        SkColor4f::from(SkColor::BLACK),
+        SkColor4f::from(SkColor::TRANSPARENT),
+    ];
+    let gradient = Gradient::new(
+        Colors::new(&colors, None, TileMode::Clamp, None),
+        Interpolation::default(),
+    );
+
+    // Keep the text where the gradient is opaque
+    let mut mask_paint = Paint::default();
+    mask_paint.set_blend_mode(BlendMode::DstIn);
+    mask_paint.set_shader(shaders::linear_gradient(
+        (SkPoint::new(from, 0.), SkPoint::new(to, 0.)),
+        &gradient,
+        None,
+    ));
+    canvas.draw_rect(bounds_rect, &mask_paint);
+
+    canvas.restore_to_count(layer);
+}
+
+/// Picks the bytes of `text` kept by [TextOverflow::StartEllipsis] and [TextOverflow::MiddleEllipsis]
+/// for the text to fit in `max_width`: the text before the first index and after the second one.
+/// `width_of` measures the text resulting from a candidate pair of indices, with the ellipsis in between.
+pub(crate) fn truncate_text(
+    text: &str,
+    text_overflow: &TextOverflow,
+    max_width: f32,
+    width_of: impl Fn(usize, usize) -> f32,
+) -> (usize, usize) {
+    let boundaries = text
+        .grapheme_indices(true)
+        .map(|(index, _)| index)
+        .chain([text.len()])
+        .collect::<Vec<_>>();
+    let graphemes = boundaries.len() - 1;
+
+    let kept_bytes = |kept: usize| match text_overflow {
+        TextOverflow::MiddleEllipsis => (
+            boundaries[kept.div_ceil(2)],
+            boundaries[graphemes - kept / 2],
+        ),
+        _ => (0, boundaries[graphemes - kept]),
+    };
+
+    // Keep as many grapheme clusters as fit
+    let (mut low, mut high) = (0, graphemes);
+    while low < high {
+        let kept = (low + high).div_ceil(2);
+        let (head, tail) = kept_bytes(kept);
+        if width_of(head, tail) <= max_width {
+            low = kept;
+        } else {
+            high = kept - 1;
+        }
+    }
+
+    kept_bytes(low)
+}
+
 impl KeyExt for Paragraph {
     fn write_key(&mut self) -> &mut DiffKey {
         &mut self.key
@@ -856,6 +1796,12 @@ impl EventHandlersExt for Paragraph {
 
 impl MaybeExt for Paragraph {}
 
+impl EffectExt for Paragraph {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
 impl LayerExt for Paragraph {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
@@ -884,10 +1830,8 @@ impl ChildrenExt for Paragraph {
         &mut self.children
     }
 
-    fn child<C: IntoElement>(mut self, child: C) -> Self {
-        self.element.contents.push(ParagraphContent::Element);
-        self.children.push(child.into_element());
-        self
+    fn child<C: IntoElement>(self, child: C) -> Self {
+        self.inline_child(child, InlineAlignment::default())
     }
 
     fn children(self, children: impl IntoIterator<Item = impl IntoElement>) -> Self {
@@ -937,6 +1881,34 @@ impl Paragraph {
         self
     }
 
+    /// Append a child that flows inline with the text, aligned with its line by `alignment`.
+    /// Children added with [ChildrenExt::child] are centered on their line.
+    ///
+    /// ```rust
+    /// # use freya::prelude::*;
+    /// fn app() -> impl IntoElement {
+    ///     paragraph()
+    ///         .span("Build ")
+    ///         .inline_child(
+    ///             rect()
+    ///                 .width(Size::px(48.))
+    ///                 .height(Size::px(20.))
+    ///                 .padding((2., 6.))
+    ///                 .background((40, 160, 80))
+    ///                 .child(label().font_size(12.).text("passed")),
+    ///             InlineAlignment::Baseline(14.),
+    ///         )
+    ///         .span(" on main")
+    /// }
+    /// ```
+    pub fn inline_child(mut self, child: impl IntoElement, alignment: InlineAlignment) -> Self {
+        self.element
+            .contents
+            .push(ParagraphContent::Element(alignment));
+        self.children.push(child.into_element());
+        self
+    }
+
     fn push_span(&mut self, span: Span<'static>) {
         self.element.contents.push(ParagraphContent::Span);
         self.element.spans.push(span);
@@ -1006,6 +1978,26 @@ impl Paragraph {
         self
     }
 
+    /// Set the direction in which the lines flow. Defaults to [WritingMode::HorizontalTb].
+    ///
+    /// In the vertical modes the text is laid out in columns that wrap at the available height.
+    /// Cursors, highlights, text selection, decorations, inline children, interactive spans and
+    /// the queries of a [ParagraphHolder] are only supported in the horizontal mode, debug builds
+    /// warn when they are used in the vertical ones.
+    ///
+    /// ```rust
+    /// # use freya::prelude::*;
+    /// fn app() -> impl IntoElement {
+    ///     paragraph()
+    ///         .writing_mode(WritingMode::VerticalRl)
+    ///         .span("春はあけぼの")
+    /// }
+    /// ```
+    pub fn writing_mode(mut self, writing_mode: impl Into<WritingMode>) -> Self {
+        self.element.writing_mode = writing_mode.into();
+        self
+    }
+
     /// Set the vertical alignment for the paragraph text.
     /// This affects how the text is rendered within the paragraph area, but cursor/highlight behavior
     /// depends on the `cursor_mode` setting.
@@ -1025,27 +2017,58 @@ impl Paragraph {
 /// # use freya_core::prelude::*;
 /// let span = Span::new("Hello").font_size(24.0).color(Color::RED);
 /// ```
-#[derive(Clone, PartialEq, Hash)]
+///
+/// Spans can also handle pointer events, without breaking the wrapping of the paragraph:
+///
+/// ```
+/// # use freya_core::prelude::*;
+/// let link = Span::new("freyaui.dev")
+///     .id("https://freyaui.dev")
+///     .on_press(|e: Event<SpanEventData>| println!("Open {:?}", e.id));
+/// ```
+#[derive(Clone)]
 pub struct Span<'a> {
     pub text_style_data: TextStyleData,
     pub text: Cow<'a, str>,
+    /// Identifier passed to the event handlers of the span.
+    pub id: Option<Cow<'static, str>>,
+    pub event_handlers: SpanEventHandlers,
+}
+
+/// Event handlers of a [`Span`], resolved by hit-testing the laid out paragraph.
+#[derive(Clone, Default)]
+pub struct SpanEventHandlers {
+    pub on_press: Option<EventHandler<Event<SpanEventData>>>,
+    pub on_pointer_enter: Option<EventHandler<Event<SpanEventData>>>,
+    pub on_pointer_leave: Option<EventHandler<Event<SpanEventData>>>,
+}
+
+// Event handlers are left out, the paragraph compares its own
+impl PartialEq for Span<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.text_style_data == other.text_style_data
+            && self.text == other.text
+            && self.id == other.id
+    }
+}
+
+impl Hash for Span<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.text_style_data.hash(state);
+        self.text.hash(state);
+        self.id.hash(state);
+    }
 }
 
 impl From<&'static str> for Span<'static> {
     fn from(text: &'static str) -> Self {
-        Span {
-            text_style_data: TextStyleData::default(),
-            text: text.into(),
-        }
+        Span::new(text)
     }
 }
 
 impl From<String> for Span<'static> {
     fn from(text: String) -> Self {
-        Span {
-            text_style_data: TextStyleData::default(),
-            text: text.into(),
-        }
+        Span::new(text)
     }
 }
 
@@ -1055,8 +2078,50 @@ impl<'a> Span<'a> {
         Self {
             text: text.into(),
             text_style_data: TextStyleData::default(),
+            id: None,
+            event_handlers: SpanEventHandlers::default(),
         }
     }
+
+    /// Set the identifier passed to the event handlers of the span, such as the target of a link.
+    pub fn id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Fires when the span is clicked or touched.
+    pub fn on_press(mut self, on_press: impl Into<EventHandler<Event<SpanEventData>>>) -> Self {
+        self.event_handlers.on_press = Some(on_press.into());
+        self
+    }
+
+    /// Fires when the pointer starts hovering the span.
+    pub fn on_pointer_enter(
+        mut self,
+        on_pointer_enter: impl Into<EventHandler<Event<SpanEventData>>>,
+    ) -> Self {
+        self.event_handlers.on_pointer_enter = Some(on_pointer_enter.into());
+        self
+    }
+
+    /// Fires when the pointer stops hovering the span.
+    pub fn on_pointer_leave(
+        mut self,
+        on_pointer_leave: impl Into<EventHandler<Event<SpanEventData>>>,
+    ) -> Self {
+        self.event_handlers.on_pointer_leave = Some(on_pointer_leave.into());
+        self
+    }
+
+    /// Whether the span has any event handler.
+    pub fn is_interactive(&self) -> bool {
+        let SpanEventHandlers {
+            on_press,
+            on_pointer_enter,
+            on_pointer_leave,
+        } = &self.event_handlers;
+        on_press.is_some() || on_pointer_enter.is_some() || on_pointer_leave.is_some()
+    }
 }
 
 impl<'a> TextStyleExt for Span<'a> {
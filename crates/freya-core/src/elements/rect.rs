@@ -21,7 +21,10 @@ use freya_engine::prelude::{
     SkRect,
 };
 use torin::{
-    prelude::Area,
+    prelude::{
+        Area,
+        CursorPoint,
+    },
     scaled::Scaled,
 };
 
@@ -73,6 +76,7 @@ pub struct RectElement {
     pub event_handlers: EventHandlers,
     pub accessibility: AccessibilityData,
     pub effect: Option<EffectData>,
+    pub text_selection: Option<TextSelection>,
 }
 
 impl Default for RectElement {
@@ -89,6 +93,7 @@ impl Default for RectElement {
             event_handlers: Default::default(),
             accessibility,
             effect: Default::default(),
+            text_selection: None,
         }
     }
 }
@@ -324,6 +329,32 @@ impl RectElement {
         }
     }
 
+    /// Whether the rect has a clip path or a mask.
+    fn is_masked(&self) -> bool {
+        self.effect
+            .as_ref()
+            .is_some_and(|effect| effect.clip_path.is_some() || effect.mask.is_some())
+    }
+
+    /// Clip the canvas to the clip path and the mask of the rect, if any.
+    fn clip_shapes(&self, canvas: &Canvas, area: &Area, scale_factor: f32) {
+        let Some(effect) = &self.effect else {
+            return;
+        };
+
+        if let Some(path) = effect
+            .clip_path
+            .as_ref()
+            .and_then(|clip_path| clip_path.path(*area, scale_factor))
+        {
+            canvas.clip_path(&path, ClipOp::Intersect, true);
+        }
+
+        if let Some(shader) = effect.mask.as_ref().and_then(|mask| mask.shader(*area)) {
+            canvas.clip_shader(shader, ClipOp::Intersect);
+        }
+    }
+
     fn outer_border_path_corner_radius(
         alignment: BorderAlignment,
         corner_radius: f32,
@@ -425,6 +456,11 @@ impl ElementExt for RectElement {
             diff.insert(DiffModifies::TEXT_STYLE);
         }
 
+        // Only the highlights of the texts inside are painted again
+        if self.text_selection != rect.text_selection {
+            diff.insert(DiffModifies::STYLE);
+        }
+
         diff
     }
 
@@ -456,6 +492,10 @@ impl ElementExt for RectElement {
         Some(Cow::Borrowed(&self.event_handlers))
     }
 
+    fn text_selection(&self) -> Option<&TextSelection> {
+        self.text_selection.as_ref()
+    }
+
     /// Checks if the cursor point is inside the rounded rectangle of this element,
     /// using local coordinates relative to the element's visible area for improved precision with large absolute coordinates.
     fn is_point_inside(&self, context: EventMeasurementContext) -> bool {
@@ -465,22 +505,57 @@ impl ElementExt for RectElement {
         let rounded_rect = self.render_rect(&local_area, context.scale_factor as f32);
         let local_x = cursor.x - area.min_x();
         let local_y = cursor.y - area.min_y();
-        rounded_rect.contains(SkRect::new(
+        if !rounded_rect.contains(SkRect::new(
             local_x,
             local_y,
             local_x.next_up(),
             local_y.next_up(),
-        ))
+        )) {
+            return false;
+        }
+
+        let Some(effect) = &self.effect else {
+            return true;
+        };
+
+        if let Some(path) = effect
+            .clip_path
+            .as_ref()
+            .and_then(|clip_path| clip_path.path(local_area, context.scale_factor as f32))
+            && !path.contains((local_x, local_y))
+        {
+            return false;
+        }
+
+        effect.mask.as_ref().is_none_or(|mask| {
+            mask.is_visible_at(local_area, CursorPoint::new(local_x as f64, local_y as f64))
+        })
     }
 
     fn clip(&self, context: ClipContext) {
         let area = context.visible_area;
 
-        let rounded_rect = self.render_rect(area, context.scale_factor as f32);
+        // Clip paths and masks are bounded to the rect, but only overflow clips the rounded corners
+        if self.is_masked()
+            && self
+                .effect
+                .as_ref()
+                .is_some_and(|effect| effect.overflow != Overflow::Clip)
+        {
+            context.canvas.clip_rect(
+                SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                ClipOp::Intersect,
+                true,
+            );
+        } else {
+            let rounded_rect = self.render_rect(area, context.scale_factor as f32);
+
+            context
+                .canvas
+                .clip_rrect(rounded_rect, ClipOp::Intersect, true);
+        }
 
-        context
-            .canvas
-            .clip_rrect(rounded_rect, ClipOp::Intersect, true);
+        self.clip_shapes(context.canvas, area, context.scale_factor as f32);
     }
 
     fn render(&self, context: RenderContext) {
@@ -489,6 +564,9 @@ impl ElementExt for RectElement {
         let area = context.layout_node.visible_area();
         let corner_radius = style.corner_radius.with_scale(context.scale_factor as f32);
 
+        let layer = context.canvas.save();
+        self.clip_shapes(context.canvas, &area, context.scale_factor as f32);
+
         let mut path = PathBuilder::new();
         let mut paint = Paint::default();
         paint.set_anti_alias(true);
@@ -530,6 +608,8 @@ impl ElementExt for RectElement {
                 Self::render_border(context.canvas, rect, &border, &corner_radius);
             }
         }
+
+        context.canvas.restore_to_count(layer);
     }
 }
 
@@ -651,6 +731,12 @@ impl Rect {
         self
     }
 
+    /// Make the texts inside selectable as a whole, sharing the given [`TextSelection`].
+    pub fn text_selection(mut self, text_selection: TextSelection) -> Self {
+        self.element.text_selection = Some(text_selection);
+        self
+    }
+
     /// Set whether content overflowing the rect's bounds is clipped. See [`Overflow`].
     pub fn overflow<S: Into<Overflow>>(mut self, overflow: S) -> Self {
         self.element
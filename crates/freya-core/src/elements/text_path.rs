@@ -0,0 +1,343 @@
+//! Lay text along a curve with [text_path()].
+
+use std::{
+    any::Any,
+    borrow::Cow,
+    rc::Rc,
+};
+
+use freya_engine::prelude::{
+    ParagraphBuilder,
+    ParagraphStyle,
+    SkMatrix,
+    SkPath,
+};
+use torin::prelude::Size2D;
+
+use crate::{
+    data::{
+        AccessibilityData,
+        EffectData,
+        LayoutData,
+        StyleState,
+        TextStyleData,
+    },
+    diff_key::DiffKey,
+    element::{
+        Element,
+        ElementExt,
+        EventHandlers,
+        LayoutContext,
+        RenderContext,
+    },
+    elements::{
+        glyphs::{
+            self,
+            PlacedGlyphs,
+        },
+        paragraph::span_text_style,
+    },
+    layers::Layer,
+    prelude::{
+        AccessibilityExt,
+        ContainerExt,
+        EffectExt,
+        EventHandlersExt,
+        KeyExt,
+        LayerExt,
+        LayoutExt,
+        MaybeExt,
+        Span,
+        TextStyleExt,
+    },
+    tree::DiffModifies,
+};
+
+/// Lay text along a curve described with [SVG path data](https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/d),
+/// such as circular badges or the labels of a chart axis.
+///
+/// The glyphs sit with their baseline on the path, and the ones that don't fit in it are left out.
+/// Its size fits both the path and the glyphs, unless given an explicit size.
+///
+/// See the available methods in [TextPath].
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     text_path()
+///         .path("M 10 80 Q 95 10 180 80")
+///         .text("Hello, curves!")
+///         .font_size(18.)
+/// }
+/// ```
+pub fn text_path() -> TextPath {
+    TextPath::default()
+}
+
+#[derive(PartialEq, Clone)]
+pub struct TextPathElement {
+    pub text: Cow<'static, str>,
+    pub path: Cow<'static, str>,
+    pub start_offset: f32,
+    pub accessibility: AccessibilityData,
+    pub text_style_data: TextStyleData,
+    pub layout: LayoutData,
+    pub effect: Option<EffectData>,
+    pub event_handlers: EventHandlers,
+    pub relative_layer: Layer,
+}
+
+impl Default for TextPathElement {
+    fn default() -> Self {
+        let mut accessibility = AccessibilityData::default();
+        accessibility.builder.set_role(accesskit::Role::Label);
+        Self {
+            text: Default::default(),
+            path: Default::default(),
+            start_offset: 0.,
+            accessibility,
+            text_style_data: Default::default(),
+            layout: Default::default(),
+            effect: None,
+            event_handlers: Default::default(),
+            relative_layer: Layer::default(),
+        }
+    }
+}
+
+impl ElementExt for TextPathElement {
+    fn changed(&self, other: &Rc<dyn ElementExt>) -> bool {
+        let Some(text_path) = (other.as_ref() as &dyn Any).downcast_ref::<TextPathElement>() else {
+            return false;
+        };
+        self != text_path
+    }
+
+    fn diff(&self, other: &Rc<dyn ElementExt>) -> DiffModifies {
+        let Some(text_path) = (other.as_ref() as &dyn Any).downcast_ref::<TextPathElement>() else {
+            return DiffModifies::all();
+        };
+
+        let mut diff = DiffModifies::empty();
+
+        if self.text != text_path.text {
+            diff.insert(DiffModifies::STYLE);
+            diff.insert(DiffModifies::LAYOUT);
+            diff.insert(DiffModifies::ACCESSIBILITY);
+        }
+
+        if self.path != text_path.path || self.start_offset != text_path.start_offset {
+            diff.insert(DiffModifies::STYLE);
+            diff.insert(DiffModifies::LAYOUT);
+        }
+
+        if self.accessibility != text_path.accessibility {
+            diff.insert(DiffModifies::ACCESSIBILITY);
+        }
+
+        if self.relative_layer != text_path.relative_layer {
+            diff.insert(DiffModifies::LAYER);
+        }
+
+        if self.text_style_data != text_path.text_style_data {
+            diff.insert(DiffModifies::TEXT_STYLE);
+            diff.insert(DiffModifies::LAYOUT);
+        }
+
+        if self.layout != text_path.layout {
+            diff.insert(DiffModifies::LAYOUT);
+        }
+
+        if self.effect != text_path.effect {
+            diff.insert(DiffModifies::EFFECT);
+        }
+
+        if self.event_handlers != text_path.event_handlers {
+            diff.insert(DiffModifies::EVENT_HANDLERS);
+        }
+
+        diff
+    }
+
+    fn layout(&'_ self) -> Cow<'_, LayoutData> {
+        Cow::Borrowed(&self.layout)
+    }
+
+    fn effect(&'_ self) -> Option<Cow<'_, EffectData>> {
+        self.effect.as_ref().map(Cow::Borrowed)
+    }
+
+    fn style(&'_ self) -> Cow<'_, StyleState> {
+        Cow::Owned(StyleState::default())
+    }
+
+    fn is_transparent(&self) -> bool {
+        false
+    }
+
+    fn text_style(&'_ self) -> Cow<'_, TextStyleData> {
+        Cow::Borrowed(&self.text_style_data)
+    }
+
+    fn accessibility(&'_ self) -> Cow<'_, AccessibilityData> {
+        Cow::Borrowed(&self.accessibility)
+    }
+
+    fn finish_accessibility(&self, builder: &mut accesskit::Node) {
+        builder.set_value(self.text.clone());
+    }
+
+    fn layer(&self) -> Layer {
+        self.relative_layer
+    }
+
+    fn events_handlers(&'_ self) -> Option<Cow<'_, EventHandlers>> {
+        Some(Cow::Borrowed(&self.event_handlers))
+    }
+
+    fn measure(&self, context: LayoutContext) -> Option<(Size2D, Rc<dyn Any>)> {
+        let scale_factor = context.scale_factor as f32;
+        let path = SkPath::from_svg(&*self.path)
+            .unwrap_or_default()
+            .with_transform(&SkMatrix::scale((scale_factor, scale_factor)));
+
+        let text_style = span_text_style(
+            context.text_style_state,
+            context.fallback_fonts,
+            context.scale_factor,
+            &Span::new(&*self.text),
+            None,
+        );
+        let mut paragraph_style = ParagraphStyle::default();
+        paragraph_style.set_text_style(&text_style);
+        paragraph_style.set_text_direction(context.text_style_state.text_direction());
+
+        let mut paragraph_builder =
+            ParagraphBuilder::new(&paragraph_style, &*context.font_collection);
+        paragraph_builder.add_text(&*self.text);
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(f32::MAX);
+
+        let runs = glyphs::shape(
+            &mut paragraph,
+            &self.text,
+            &[(0..self.text.len(), text_style.color())],
+        );
+        let placed_glyphs = glyphs::place_along_path(
+            runs,
+            &path,
+            self.start_offset * scale_factor,
+            context.text_style_state.text_align,
+        );
+
+        Some((placed_glyphs.size(), Rc::new(placed_glyphs)))
+    }
+
+    fn should_hook_measurement(&self) -> bool {
+        true
+    }
+
+    fn should_measure_inner_children(&self) -> bool {
+        false
+    }
+
+    fn render(&self, context: RenderContext) {
+        let Some(placed_glyphs) = context
+            .layout_node
+            .data
+            .as_ref()
+            .and_then(|data| data.downcast_ref::<PlacedGlyphs>())
+        else {
+            return;
+        };
+        placed_glyphs.render(context.canvas, context.layout_node.visible_area().origin);
+    }
+}
+
+impl From<TextPath> for Element {
+    fn from(value: TextPath) -> Self {
+        Element::Element {
+            key: value.key,
+            element: Rc::new(value.element),
+            elements: vec![],
+        }
+    }
+}
+
+impl KeyExt for TextPath {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl EventHandlersExt for TextPath {
+    fn get_event_handlers(&mut self) -> &mut EventHandlers {
+        &mut self.element.event_handlers
+    }
+}
+
+impl AccessibilityExt for TextPath {
+    fn get_accessibility_data(&mut self) -> &mut AccessibilityData {
+        &mut self.element.accessibility
+    }
+}
+
+impl TextStyleExt for TextPath {
+    fn get_text_style_data(&mut self) -> &mut TextStyleData {
+        &mut self.element.text_style_data
+    }
+}
+
+impl EffectExt for TextPath {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
+impl LayerExt for TextPath {
+    fn get_layer(&mut self) -> &mut Layer {
+        &mut self.element.relative_layer
+    }
+}
+
+impl MaybeExt for TextPath {}
+
+#[derive(Default, Clone)]
+pub struct TextPath {
+    key: DiffKey,
+    element: TextPathElement,
+}
+
+impl TextPath {
+    pub fn try_downcast(element: &dyn ElementExt) -> Option<TextPathElement> {
+        (element as &dyn Any)
+            .downcast_ref::<TextPathElement>()
+            .cloned()
+    }
+
+    /// Set the text laid along the path.
+    pub fn text(mut self, text: impl Into<Cow<'static, str>>) -> Self {
+        self.element.text = text.into();
+        self
+    }
+
+    /// Set the curve as SVG path data, in logical pixels relative to the element, e.g. `M 0 50 A 50 50 0 0 1 100 50`.
+    pub fn path(mut self, path: impl Into<Cow<'static, str>>) -> Self {
+        self.element.path = path.into();
+        self
+    }
+
+    /// Distance along the path where the text starts, added to the offset from the text alignment.
+    pub fn start_offset(mut self, start_offset: f32) -> Self {
+        self.element.start_offset = start_offset;
+        self
+    }
+}
+
+impl LayoutExt for TextPath {
+    fn get_layout(&mut self) -> &mut LayoutData {
+        &mut self.element.layout
+    }
+}
+
+impl ContainerExt for TextPath {}
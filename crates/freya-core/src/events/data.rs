@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     cell::RefCell,
     ops::{
         Deref,
@@ -8,15 +9,26 @@ use std::{
     rc::Rc,
 };
 
+use ragnarok::PointerCaptureRequest;
+pub use ragnarok::PointerId;
 use torin::prelude::{
     Area,
     CursorPoint,
     Size2D,
 };
 
-use crate::data::{
-    StyleState,
-    TextStyleState,
+use crate::{
+    data::{
+        StyleState,
+        TextStyleState,
+    },
+    events::gesture::{
+        Gesture,
+        GesturePhase,
+        GestureVector,
+        SwipeDirection,
+    },
+    node_id::NodeId,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -73,6 +85,21 @@ pub struct Event<D> {
     pub(crate) data: D,
     pub(crate) propagate: Rc<RefCell<bool>>,
     pub(crate) default: Rc<RefCell<bool>>,
+    /// The element whose handler is being called.
+    pub(crate) current_target: NodeId,
+    pub(crate) pointer_capture_requests: Rc<RefCell<Vec<PointerCaptureRequest<NodeId>>>>,
+}
+
+impl<D: Clone> Clone for Event<D> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            propagate: self.propagate.clone(),
+            default: self.default.clone(),
+            current_target: self.current_target,
+            pointer_capture_requests: self.pointer_capture_requests.clone(),
+        }
+    }
 }
 
 impl<D> Deref for Event<D> {
@@ -89,6 +116,8 @@ impl<D> Event<D> {
             data: data(self.data),
             propagate: self.propagate,
             default: self.default,
+            current_target: self.current_target,
+            pointer_capture_requests: self.pointer_capture_requests,
         }
     }
 
@@ -97,6 +126,8 @@ impl<D> Event<D> {
             data: data(self.data)?,
             propagate: self.propagate,
             default: self.default,
+            current_target: self.current_target,
+            pointer_capture_requests: self.pointer_capture_requests,
         })
     }
 
@@ -116,6 +147,30 @@ impl<D> Event<D> {
     pub fn get_prevent_default(&self) -> Rc<RefCell<bool>> {
         self.default.clone()
     }
+
+    /// Route all the subsequent events of `pointer` to the element of this handler, even once
+    /// the pointer leaves it, until the pointer is lifted or [Event::release_pointer_capture] is called.
+    ///
+    /// The element gets a `on_lost_pointer_capture` event once the capture ends. Global events of
+    /// the pointer, like `on_global_pointer_move`, keep reaching all their listeners meanwhile.
+    pub fn capture_pointer(&self, pointer: PointerId) {
+        self.pointer_capture_requests
+            .borrow_mut()
+            .push(PointerCaptureRequest::Capture {
+                key: self.current_target,
+                pointer,
+            });
+    }
+
+    /// Stop routing the events of `pointer` to the element of this handler, if it's capturing it.
+    pub fn release_pointer_capture(&self, pointer: PointerId) {
+        self.pointer_capture_requests
+            .borrow_mut()
+            .push(PointerCaptureRequest::Release {
+                key: self.current_target,
+                pointer,
+            });
+    }
 }
 
 /// Data of a Sized event.
@@ -260,6 +315,13 @@ impl PointerEventData {
         }
     }
 
+    pub fn pointer_id(&self) -> PointerId {
+        match self {
+            Self::Mouse(_) => PointerId::Mouse,
+            Self::Touch(t) => PointerId::Touch(t.finger_id),
+        }
+    }
+
     /// Whether this is a touch event or a primary (left) mouse button event.
     pub fn is_primary(&self) -> bool {
         match self {
@@ -269,6 +331,30 @@ impl PointerEventData {
     }
 }
 
+/// Data of a pointer event on a span of a paragraph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanEventData {
+    /// Identifier of the span, if it has one.
+    pub id: Option<Cow<'static, str>>,
+    /// Index of the span in the paragraph.
+    pub index: usize,
+    pub pointer: PointerEventData,
+}
+
+/// Data of the Focus, Blur and FocusWithinChange events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusEventData {
+    /// Whether the element is now focused, or for FocusWithinChange,
+    /// whether the element or one of its descendants is now focused.
+    pub focused: bool,
+}
+
+impl FocusEventData {
+    pub(crate) fn new(focused: bool) -> Self {
+        Self { focused }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ImePreeditEventData {
     pub text: String,
@@ -293,6 +379,166 @@ impl FileEventData {
     }
 }
 
+/// Data of a gesture event, in logical pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GestureEventData {
+    pub gesture: Gesture,
+    /// Centroid of the fingers, or the cursor for trackpad gestures.
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+}
+
+impl GestureEventData {
+    pub(crate) fn new(
+        gesture: Gesture,
+        global_location: CursorPoint,
+        element_location: CursorPoint,
+    ) -> Self {
+        Self {
+            gesture,
+            global_location,
+            element_location,
+        }
+    }
+
+    pub fn pan(self) -> Option<PanEventData> {
+        match self.gesture {
+            Gesture::Pan {
+                phase,
+                delta,
+                translation,
+            } => Some(PanEventData {
+                phase,
+                delta,
+                translation,
+                global_location: self.global_location,
+                element_location: self.element_location,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn pinch(self) -> Option<PinchEventData> {
+        match self.gesture {
+            Gesture::Pinch {
+                phase,
+                delta,
+                scale,
+            } => Some(PinchEventData {
+                phase,
+                delta,
+                scale,
+                global_location: self.global_location,
+                element_location: self.element_location,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn rotate(self) -> Option<RotateEventData> {
+        match self.gesture {
+            Gesture::Rotate {
+                phase,
+                delta,
+                angle,
+            } => Some(RotateEventData {
+                phase,
+                delta,
+                angle,
+                global_location: self.global_location,
+                element_location: self.element_location,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn long_press(self) -> Option<LongPressEventData> {
+        match self.gesture {
+            Gesture::LongPress => Some(LongPressEventData {
+                global_location: self.global_location,
+                element_location: self.element_location,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn swipe(self) -> Option<SwipeEventData> {
+        match self.gesture {
+            Gesture::Swipe {
+                direction,
+                velocity,
+            } => Some(SwipeEventData {
+                direction,
+                velocity,
+                global_location: self.global_location,
+                element_location: self.element_location,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Data of a Pan event, see [Gesture::Pan].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanEventData {
+    pub phase: GesturePhase,
+    pub delta: GestureVector,
+    pub translation: GestureVector,
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+}
+
+/// Data of a Pinch event, see [Gesture::Pinch].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinchEventData {
+    pub phase: GesturePhase,
+    pub delta: f64,
+    pub scale: f64,
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+}
+
+/// Data of a Rotate event, see [Gesture::Rotate].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotateEventData {
+    pub phase: GesturePhase,
+    /// In radians.
+    pub delta: f64,
+    /// In radians.
+    pub angle: f64,
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+}
+
+/// Data of a LongPress event, see [Gesture::LongPress].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongPressEventData {
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+}
+
+/// Data of a Swipe event, see [Gesture::Swipe].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwipeEventData {
+    pub direction: SwipeDirection,
+    /// In logical pixels per second.
+    pub velocity: GestureVector,
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+}
+
+/// Data of a LostPointerCapture event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointerCaptureEventData {
+    pub pointer_id: PointerId,
+}
+
+impl PointerCaptureEventData {
+    pub(crate) fn new(pointer_id: PointerId) -> Self {
+        Self { pointer_id }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum EventType {
     Mouse(MouseEventData),
@@ -304,4 +550,7 @@ pub enum EventType {
     Pointer(PointerEventData),
     ImePreedit(ImePreeditEventData),
     File(FileEventData),
+    Gesture(GestureEventData),
+    PointerCapture(PointerCaptureEventData),
+    Focus(FocusEventData),
 }
@@ -8,12 +8,14 @@ use crate::{
     events::{
         data::{
             EventType,
+            GestureEventData,
             KeyboardEventData,
             MouseEventData,
             PointerEventData,
             TouchEventData,
             WheelEventData,
         },
+        gesture::Gesture,
         name::EventName,
     },
     integration::PlatformEvent,
@@ -265,6 +267,49 @@ impl EmmitableEvent {
                 data: EventType::File(FileEventData::new(cursor, file_paths)),
                 bubbles,
             },
+            PlatformEvent::Gesture {
+                name: platform_event_name,
+                location,
+                gesture,
+            } => {
+                let global_location = location / scale_factor;
+                let element_x =
+                    (location.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (location.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                let gesture = match gesture {
+                    Gesture::Pan {
+                        phase,
+                        delta,
+                        translation,
+                    } => Gesture::Pan {
+                        phase,
+                        delta: delta / scale_factor,
+                        translation: translation / scale_factor,
+                    },
+                    Gesture::Swipe {
+                        direction,
+                        velocity,
+                    } => Gesture::Swipe {
+                        direction,
+                        velocity: velocity / scale_factor,
+                    },
+                    gesture => gesture,
+                };
+
+                Self {
+                    node_id,
+                    name,
+                    source_event: platform_event_name.into(),
+                    data: EventType::Gesture(GestureEventData::new(
+                        gesture,
+                        global_location,
+                        CursorPoint::new(element_x, element_y),
+                    )),
+                    bubbles,
+                }
+            }
         }
     }
 }
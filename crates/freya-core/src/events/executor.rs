@@ -2,10 +2,18 @@ pub enum EventsChunk {
     Batch(Vec<EmmitableEvent>),
     Processed(ProcessedEvents<NodeId, EventName, EmmitableEvent, PlatformEvent>),
 }
-use ragnarok::ProcessedEvents;
+use ragnarok::{
+    PointerCaptureRequest,
+    PointerId,
+    ProcessedEvents,
+};
 
 use crate::{
     events::{
+        data::{
+            EventType,
+            PointerCaptureEventData,
+        },
         emittable::EmmitableEvent,
         name::EventName,
     },
@@ -29,4 +37,17 @@ impl ragnarok::EventsExecutor for EventsExecutorAdapter<'_> {
         self.runner
             .handle_event(event.node_id, event.name, event.data, event.bubbles)
     }
+
+    fn take_pointer_capture_requests(&mut self) -> Vec<PointerCaptureRequest<Self::Key>> {
+        self.runner.take_pointer_capture_requests()
+    }
+
+    fn emit_lost_pointer_capture(&mut self, key: Self::Key, pointer: PointerId) {
+        self.runner.handle_event(
+            key,
+            EventName::LostPointerCapture,
+            EventType::PointerCapture(PointerCaptureEventData::new(pointer)),
+            false,
+        );
+    }
 }
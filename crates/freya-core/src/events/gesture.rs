@@ -0,0 +1,63 @@
+use std::time::Instant;
+
+pub use ragnarok::{
+    Gesture,
+    GestureConfig,
+    GestureEvent,
+    GesturePhase,
+    GestureRecognizer,
+    GestureVector,
+    SwipeDirection,
+};
+
+use crate::events::platform::{
+    GestureEventName,
+    PlatformEvent,
+    TouchEventName,
+};
+
+impl From<GestureEvent> for PlatformEvent {
+    fn from(GestureEvent { gesture, location }: GestureEvent) -> Self {
+        let name = match gesture {
+            Gesture::Pan { .. } => GestureEventName::Pan,
+            Gesture::Pinch { .. } => GestureEventName::Pinch,
+            Gesture::Rotate { .. } => GestureEventName::Rotate,
+            Gesture::LongPress => GestureEventName::LongPress,
+            Gesture::Swipe { .. } => GestureEventName::Swipe,
+        };
+        PlatformEvent::Gesture {
+            name,
+            location,
+            gesture,
+        }
+    }
+}
+
+/// Feed the touch events to the `recognizer`, adding the recognized gestures right after the
+/// touch events they come from.
+pub fn recognize_gestures(
+    recognizer: &mut GestureRecognizer,
+    events: Vec<PlatformEvent>,
+    now: Instant,
+) -> Vec<PlatformEvent> {
+    let mut recognized = Vec::with_capacity(events.len());
+    for event in events {
+        let gestures = match &event {
+            PlatformEvent::Touch {
+                name,
+                location,
+                finger_id,
+                ..
+            } => match name {
+                TouchEventName::TouchStart => recognizer.touch_start(*finger_id, *location, now),
+                TouchEventName::TouchMove => recognizer.touch_move(*finger_id, *location, now),
+                TouchEventName::TouchEnd => recognizer.touch_end(*finger_id, *location, now),
+                TouchEventName::TouchCancel => recognizer.touch_cancel(*finger_id, now),
+            },
+            _ => Vec::new(),
+        };
+        recognized.push(event);
+        recognized.extend(gestures.into_iter().map(PlatformEvent::from));
+    }
+    recognized
+}
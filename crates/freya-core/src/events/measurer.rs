@@ -54,9 +54,12 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
             return false;
         }
 
-        // Make sure the cursor is inside the element
+        // Make sure the cursor is inside the element, once mapped to its transformed coordinates
+        let Some(local_cursor) = self.tree.local_point(key, cursor) else {
+            return false;
+        };
         if !element.is_point_inside(EventMeasurementContext {
-            cursor,
+            cursor: local_cursor,
             layout_node,
             scale_factor: self.scale_factor,
         }) {
@@ -70,8 +73,11 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
             for node_id in effect_state.clips.iter() {
                 let element = self.tree.elements.get(node_id).unwrap();
                 let layout_node = self.tree.layout.get(node_id).unwrap();
+                let Some(local_cursor) = self.tree.local_point(node_id, cursor) else {
+                    return false;
+                };
                 if !element.is_point_inside(EventMeasurementContext {
-                    cursor,
+                    cursor: local_cursor,
                     layout_node,
                     scale_factor: self.scale_factor,
                 }) {
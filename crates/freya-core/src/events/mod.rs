@@ -1,6 +1,7 @@
 pub mod data;
 pub mod emittable;
 pub mod executor;
+pub mod gesture;
 pub mod measurer;
 pub mod modifiers;
 pub mod name;
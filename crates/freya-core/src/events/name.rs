@@ -13,17 +13,30 @@ pub enum EventName {
     PointerLeave,
     PointerOver,
     PointerOut,
+    LostPointerCapture,
 
     // Platform Keyboard
     KeyDown,
     KeyUp,
 
+    // Focus, dispatched by the accessibility tree
+    Focus,
+    Blur,
+    FocusWithinChange,
+
     // Platform Touch
     TouchCancel,
     TouchStart,
     TouchMove,
     TouchEnd,
 
+    // Gestures, derived from Touch or reported by a trackpad
+    Pan,
+    Pinch,
+    Rotate,
+    LongPress,
+    Swipe,
+
     GlobalPointerMove,
     GlobalPointerPress,
     GlobalPointerDown,
@@ -251,6 +264,7 @@ impl ragnarok::NameOfEvent for EventName {
             && !self.is_left()
             && !self.is_global()
             && !self.is_capture()
+            && *self != Self::LostPointerCapture
     }
 
     fn is_emitted_once(&self) -> bool {
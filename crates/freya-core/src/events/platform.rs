@@ -11,8 +11,10 @@ use crate::{
     events::{
         data::{
             MouseButton,
+            PointerId,
             WheelSource,
         },
+        gesture::Gesture,
         name::EventName,
     },
     prelude::{
@@ -126,6 +128,12 @@ pub enum PlatformEvent {
         cursor: CursorPoint,
         file_paths: Vec<PathBuf>,
     },
+    /// A Gesture event, see [GestureRecognizer](crate::events::gesture::GestureRecognizer).
+    Gesture {
+        name: GestureEventName,
+        location: CursorPoint,
+        gesture: Gesture,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
@@ -160,6 +168,27 @@ impl From<TouchEventName> for EventName {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+pub enum GestureEventName {
+    Pan,
+    Pinch,
+    Rotate,
+    LongPress,
+    Swipe,
+}
+
+impl From<GestureEventName> for EventName {
+    fn from(value: GestureEventName) -> Self {
+        match value {
+            GestureEventName::Pan => EventName::Pan,
+            GestureEventName::Pinch => EventName::Pinch,
+            GestureEventName::Rotate => EventName::Rotate,
+            GestureEventName::LongPress => EventName::LongPress,
+            GestureEventName::Swipe => EventName::Swipe,
+        }
+    }
+}
+
 impl ragnarok::SourceEvent for PlatformEvent {
     type Name = EventName;
 
@@ -202,6 +231,27 @@ impl ragnarok::SourceEvent for PlatformEvent {
         )
     }
 
+    fn try_pointer_id(&self) -> Option<PointerId> {
+        match self {
+            Self::Mouse { .. } => Some(PointerId::Mouse),
+            Self::Touch { finger_id, .. } => Some(PointerId::Touch(*finger_id)),
+            _ => None,
+        }
+    }
+
+    fn is_pointer_released(&self) -> bool {
+        matches!(
+            &self,
+            Self::Mouse {
+                name: MouseEventName::MouseUp,
+                ..
+            } | Self::Touch {
+                name: TouchEventName::TouchEnd | TouchEventName::TouchCancel,
+                ..
+            }
+        )
+    }
+
     fn as_event_name(&self) -> EventName {
         match self {
             Self::Mouse { name, .. } => (*name).into(),
@@ -210,6 +260,7 @@ impl ragnarok::SourceEvent for PlatformEvent {
             Self::ImePreedit { name, .. } => (*name).into(),
             Self::Touch { name, .. } => (*name).into(),
             Self::File { name, .. } => (*name).into(),
+            Self::Gesture { name, .. } => (*name).into(),
         }
     }
 
@@ -219,6 +270,7 @@ impl ragnarok::SourceEvent for PlatformEvent {
             PlatformEvent::Wheel { cursor, .. } => Some(*cursor),
             PlatformEvent::Touch { location, .. } => Some(*location),
             PlatformEvent::File { cursor, .. } => Some(*cursor),
+            PlatformEvent::Gesture { location, .. } => Some(*location),
             _ => None,
         }
     }
@@ -0,0 +1,266 @@
+use std::borrow::Cow;
+
+use freya_engine::prelude::*;
+
+use crate::{
+    prelude::{
+        consume_root_context,
+        try_consume_root_context,
+    },
+    style::{
+        font_slant::FontSlant,
+        font_weight::FontWeight,
+        font_width::FontWidth,
+    },
+};
+
+/// Writing script of a character, used to pick the fallback fonts of a text.
+/// See [FallbackFonts::set_script].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FontScript {
+    Arabic,
+    /// Chinese, Japanese and Korean.
+    Cjk,
+    Cyrillic,
+    Devanagari,
+    Emoji,
+    Greek,
+    Hebrew,
+    Thai,
+}
+
+impl FontScript {
+    /// The script of the given character, if it is one of the known ones.
+    pub fn of(character: char) -> Option<Self> {
+        match character as u32 {
+            0x0370..=0x03FF | 0x1F00..=0x1FFF => Some(Self::Greek),
+            0x0400..=0x052F => Some(Self::Cyrillic),
+            0x0590..=0x05FF | 0xFB1D..=0xFB4F => Some(Self::Hebrew),
+            0x0600..=0x06FF
+            | 0x0750..=0x077F
+            | 0x08A0..=0x08FF
+            | 0xFB50..=0xFDFF
+            | 0xFE70..=0xFEFF => Some(Self::Arabic),
+            0x0900..=0x097F => Some(Self::Devanagari),
+            0x0E00..=0x0E7F => Some(Self::Thai),
+            0x1100..=0x11FF
+            | 0x2E80..=0x9FFF
+            | 0xAC00..=0xD7AF
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFFEF
+            | 0x20000..=0x3FFFF => Some(Self::Cjk),
+            0x2600..=0x27BF | 0x1F000..=0x1FAFF => Some(Self::Emoji),
+            _ => None,
+        }
+    }
+}
+
+/// Font families used for the characters that the font families of a text can't render.
+///
+/// It holds a general list, which is added to every text. On top of it, each [FontScript] can
+/// have its own chain, only added to the texts with characters of that script, after the
+/// general list.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// let mut fallback_fonts = FallbackFonts::new(vec!["Inter".into()]);
+/// fallback_fonts.set_script(FontScript::Cjk, ["Noto Sans JP", "Noto Sans SC"]);
+/// fallback_fonts.set_script(FontScript::Emoji, ["Noto Color Emoji"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FallbackFonts {
+    fonts: Vec<Cow<'static, str>>,
+    scripts: Vec<(FontScript, Vec<Cow<'static, str>>)>,
+}
+
+impl FallbackFonts {
+    pub fn new(fonts: Vec<Cow<'static, str>>) -> Self {
+        Self {
+            fonts,
+            scripts: Vec::new(),
+        }
+    }
+
+    /// Append a font family to the general list.
+    pub fn push(&mut self, font: impl Into<Cow<'static, str>>) {
+        self.fonts.push(font.into());
+    }
+
+    /// Insert a font family at the start of the general list, so it is tried first.
+    pub fn push_front(&mut self, font: impl Into<Cow<'static, str>>) {
+        self.fonts.insert(0, font.into());
+    }
+
+    /// Replace the general list.
+    pub fn set(&mut self, fonts: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) {
+        self.fonts = fonts.into_iter().map(Into::into).collect();
+    }
+
+    /// The font families of the general list, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Cow<'static, str>> {
+        self.fonts.iter()
+    }
+
+    /// Set the fallback chain of a script, replacing the previous one.
+    pub fn set_script(
+        &mut self,
+        script: FontScript,
+        fonts: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    ) {
+        let fonts = fonts.into_iter().map(Into::into).collect();
+        match self.scripts.iter_mut().find(|(s, _)| *s == script) {
+            Some((_, chain)) => *chain = fonts,
+            None => self.scripts.push((script, fonts)),
+        }
+    }
+
+    /// The fallback chain of a script.
+    pub fn for_script(&self, script: FontScript) -> &[Cow<'static, str>] {
+        self.scripts
+            .iter()
+            .find(|(s, _)| *s == script)
+            .map(|(_, chain)| chain.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Append the general list and the chains of the scripts found in `text` to `font_families`.
+    pub fn extend_families(&self, font_families: &mut Vec<Cow<'static, str>>, text: &str) {
+        font_families.extend_from_slice(&self.fonts);
+        for (script, chain) in &self.scripts {
+            if text.chars().any(|c| FontScript::of(c) == Some(*script)) {
+                font_families.extend(
+                    chain
+                        .iter()
+                        .filter(|font| !self.fonts.contains(font))
+                        .cloned(),
+                );
+            }
+        }
+    }
+}
+
+impl From<Vec<Cow<'static, str>>> for FallbackFonts {
+    fn from(fonts: Vec<Cow<'static, str>>) -> Self {
+        Self::new(fonts)
+    }
+}
+
+/// A style of a [FontFamily].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFace {
+    /// Name of the style, e.g. `Bold Italic`, when the font provides it.
+    pub name: Option<String>,
+    pub weight: FontWeight,
+    pub width: FontWidth,
+    pub slant: FontSlant,
+}
+
+/// A font family available to the app, with all its styles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFamily {
+    pub name: String,
+    pub faces: Vec<FontFace>,
+}
+
+/// Lists the font families available to the app, both the ones installed in the system
+/// and the ones loaded by the app.
+///
+/// It is provided as a root context by the renderers:
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let registry = FontRegistry::get();
+///     rect().children(
+///         registry
+///             .families()
+///             .into_iter()
+///             .map(|family| label().text(family.name)),
+///     )
+/// }
+/// ```
+#[derive(Clone)]
+pub struct FontRegistry {
+    system: Option<FontMgr>,
+    app: FontMgr,
+}
+
+impl FontRegistry {
+    /// Create a registry over the system fonts, if any, and the fonts loaded by the app.
+    pub fn new(system: Option<FontMgr>, app: FontMgr) -> Self {
+        Self { system, app }
+    }
+
+    /// Try to retrieve the [`FontRegistry`] provided by the renderer.
+    ///
+    /// Returns `None` when called outside of an app, or from a renderer that doesn't provide it.
+    pub fn try_get() -> Option<Self> {
+        try_consume_root_context()
+    }
+
+    /// Retrieve the [`FontRegistry`] provided by the renderer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no [`FontRegistry`] in the root context, see [`FontRegistry::try_get`].
+    #[track_caller]
+    pub fn get() -> Self {
+        consume_root_context()
+    }
+
+    fn managers(&self) -> impl Iterator<Item = &FontMgr> {
+        std::iter::once(&self.app).chain(self.system.as_ref())
+    }
+
+    /// Names of the available families, sorted and without duplicates.
+    pub fn family_names(&self) -> Vec<String> {
+        let mut names = self
+            .managers()
+            .flat_map(|manager| manager.family_names())
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// The available families with their styles, sorted by name.
+    pub fn families(&self) -> Vec<FontFamily> {
+        self.family_names()
+            .into_iter()
+            .filter_map(|name| self.family(&name))
+            .collect()
+    }
+
+    /// The given family with its styles, if available. The fonts loaded by the app take
+    /// precedence over the system ones.
+    pub fn family(&self, name: &str) -> Option<FontFamily> {
+        self.managers().find_map(|manager| {
+            let mut style_set = manager.match_family(name);
+            let faces = (0..style_set.count())
+                .map(|index| {
+                    let (style, name) = style_set.style(index);
+                    FontFace {
+                        name,
+                        weight: (*style.weight()).into(),
+                        width: (*style.width()).into(),
+                        slant: match style.slant() {
+                            Slant::Italic => FontSlant::Italic,
+                            Slant::Oblique => FontSlant::Oblique,
+                            _ => FontSlant::Upright,
+                        },
+                    }
+                })
+                .collect::<Vec<_>>();
+            (!faces.is_empty()).then(|| FontFamily {
+                name: name.to_string(),
+                faces,
+            })
+        })
+    }
+
+    pub fn has_family(&self, name: &str) -> bool {
+        self.managers()
+            .any(|manager| manager.match_family(name).count() > 0)
+    }
+}
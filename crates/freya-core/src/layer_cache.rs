@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+
+use freya_engine::prelude::Picture;
+use rustc_hash::FxHashMap;
+
+use crate::node_id::NodeId;
+
+/// Recorded pictures of the subtrees rendered with
+/// [`cache_layer`](crate::elements::extensions::EffectExt::cache_layer), relative to the origin
+/// of their root node.
+///
+/// Pictures are recorded while rendering, so they are stored behind a [RefCell].
+#[derive(Default)]
+pub struct LayerCache(RefCell<FxHashMap<NodeId, Picture>>);
+
+impl LayerCache {
+    /// Get the picture of a cached layer, if still valid.
+    pub fn get(&self, node_id: &NodeId) -> Option<Picture> {
+        self.0.borrow().get(node_id).cloned()
+    }
+
+    /// Store the picture recorded for a cached layer.
+    pub fn insert(&self, node_id: NodeId, picture: Picture) {
+        self.0.borrow_mut().insert(node_id, picture);
+    }
+
+    /// Discard the picture of a cached layer so it gets recorded again.
+    pub fn invalidate(&mut self, node_id: &NodeId) {
+        self.0.get_mut().remove(node_id);
+    }
+
+    /// The nodes with a recorded picture.
+    pub fn node_ids(&self) -> Vec<NodeId> {
+        self.0.borrow().keys().copied().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
@@ -2,6 +2,7 @@ pub mod accessibility;
 pub mod animation_clock;
 pub mod current_context;
 pub mod cursor;
+pub mod damage;
 pub mod data;
 pub mod debug;
 pub mod diff_key;
@@ -12,8 +13,10 @@ pub mod events;
 pub mod events_combos;
 pub mod extended_hashmap;
 pub mod fifo_cache;
+pub mod fonts;
 pub mod helpers;
 pub mod hooks;
+pub mod layer_cache;
 pub mod layers;
 pub mod lifecycle;
 pub mod lru_cache;
@@ -27,8 +30,10 @@ pub mod rendering_ticker;
 pub mod runner;
 pub mod scope;
 pub mod scope_id;
+pub mod shortcuts;
 pub mod style;
 pub mod text_cache;
+pub mod text_selection;
 pub mod tree;
 pub mod tree_layout_adapter;
 pub mod user_event;
@@ -91,6 +96,7 @@ pub mod prelude {
             paragraph::{
                 Paragraph,
                 ParagraphHolder,
+                ParagraphLine,
                 Span,
                 paragraph,
             },
@@ -98,6 +104,10 @@ pub mod prelude {
                 Rect,
                 rect,
             },
+            text_path::{
+                TextPath,
+                text_path,
+            },
         },
         event_handler::{
             Callback,
@@ -106,10 +116,12 @@ pub mod prelude {
         },
         events::{
             data::*,
+            gesture::*,
             modifiers::*,
             *,
         },
         events_combos::*,
+        fonts::*,
         hooks::{
             previous_and_current::*,
             use_id::*,
@@ -132,17 +144,26 @@ pub mod prelude {
         reactive_context::ReactiveContext,
         rendering_ticker::RenderingTicker,
         scope_id::ScopeId,
+        shortcuts::*,
         style::{
+            blend_mode::*,
             border::*,
+            clip_path::*,
             color::*,
             corner_radius::*,
             cursor::*,
             fill::*,
+            filter::*,
+            font_feature::*,
             font_size::*,
             font_slant::*,
+            font_variation::*,
             font_weight::*,
             font_width::*,
             gradient::*,
+            hyphens::*,
+            inline_alignment::*,
+            mask::*,
             scale::*,
             shader::*,
             shadow::*,
@@ -151,9 +172,15 @@ pub mod prelude {
             text_height::*,
             text_overflow::*,
             text_shadow::*,
+            text_spacing::*,
+            text_stroke::*,
+            transform::*,
             transform_origin::*,
             vertical_align::*,
+            word_break::*,
+            writing_mode::*,
         },
+        text_selection::*,
         user_event::UserEvent,
     };
 }
@@ -171,12 +198,14 @@ pub mod integration {
             tree::*,
         },
         animation_clock::AnimationClock,
+        damage::Damage,
         data::*,
         element::*,
         elements::extensions::*,
         events::{
             data::*,
             executor::*,
+            gesture::*,
             measurer::*,
             modifiers::*,
             name::*,
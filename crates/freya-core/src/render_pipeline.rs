@@ -1,24 +1,35 @@
+use std::borrow::Cow;
+
 use freya_engine::prelude::{
     Canvas,
     ClipOp,
     FontCollection,
     FontMgr,
+    Paint,
+    PathBuilder,
+    Picture,
+    PictureRecorder,
     SaveLayerRec,
-    SkMatrix,
-    SkPoint,
+    SkRect,
     blur,
 };
 use rustc_hash::FxHashMap;
 use torin::prelude::Area;
 
 use crate::{
+    damage::Damage,
+    data::EffectState,
     element::{
         ClipContext,
         RenderContext,
     },
     node_id::NodeId,
     prelude::Color,
-    style::shadow::ShadowPosition,
+    style::{
+        blend_mode::BlendMode,
+        filter::Filter,
+        shadow::ShadowPosition,
+    },
     tree::Tree,
 };
 
@@ -29,110 +40,397 @@ pub struct RenderPipeline<'a> {
     pub tree: &'a Tree,
     pub scale_factor: f64,
     pub background: Color,
+    /// Only the damaged areas are redrawn, the rest of the canvas is left untouched.
+    pub damage: &'a Damage,
 }
 
-impl RenderPipeline<'_> {
-    /// Transform an area with the accumulated scale effects of the given nodes.
-    fn scale_transformed_area(&self, mut area: Area, scale_node_ids: &[NodeId]) -> Area {
-        for node_id in scale_node_ids {
-            let layout_node = self.tree.layout.get(node_id).unwrap();
-            let effect = self.tree.effect_state.get(node_id).unwrap();
-            let node_area = layout_node.visible_area();
-            let origin = effect.transform_origin.origin(&node_area);
-            let scale = effect.scale.unwrap();
-
-            area = area.translate(-origin.to_vector());
-            area = area.scale(scale.x, scale.y);
-            area = area.translate(origin.to_vector());
+/// Effects applied when rendering a node.
+#[derive(Default)]
+struct Effects<'a> {
+    clips: Cow<'a, [NodeId]>,
+    rotations: Cow<'a, [NodeId]>,
+    transforms: Cow<'a, [NodeId]>,
+    opacities: &'a [f32],
+    blend_modes: &'a [BlendMode],
+    filters: Cow<'a, [NodeId]>,
+    blur: Option<f32>,
+    backdrop_filter: &'a [Filter],
+    /// Scale effects and transforms already applied outside of the canvas, ignored when computing
+    /// the clips.
+    inherited_transforms: &'a [NodeId],
+}
+
+impl<'a> Effects<'a> {
+    fn new(effect_state: &'a EffectState) -> Self {
+        Self {
+            clips: Cow::Borrowed(&effect_state.clips),
+            rotations: Cow::Borrowed(&effect_state.rotations),
+            transforms: Cow::Borrowed(&effect_state.transforms),
+            opacities: &effect_state.opacities,
+            blend_modes: &effect_state.blend_modes,
+            filters: Cow::Borrowed(&effect_state.filters),
+            blur: effect_state.blur,
+            backdrop_filter: &effect_state.backdrop_filter,
+            inherited_transforms: &[],
+        }
+    }
+
+    /// Effects of a node inside of a cached layer, without those inherited from outside of it.
+    fn inside_layer(
+        effect_state: &'a EffectState,
+        layer_effect_state: &'a EffectState,
+        inherited: Option<&'a EffectState>,
+    ) -> Self {
+        let inherited_transforms = inherited
+            .map(|state| &*state.transforms)
+            .unwrap_or_default();
+        Self {
+            clips: without(&effect_state.clips, &layer_effect_state.clips),
+            rotations: without(
+                &effect_state.rotations,
+                inherited.map(|state| &*state.rotations).unwrap_or_default(),
+            ),
+            transforms: without(&effect_state.transforms, inherited_transforms),
+            opacities: effect_state
+                .opacities
+                .get(
+                    inherited
+                        .map(|state| state.opacities.len())
+                        .unwrap_or_default()..,
+                )
+                .unwrap_or_default(),
+            blend_modes: effect_state
+                .blend_modes
+                .get(
+                    inherited
+                        .map(|state| state.blend_modes.len())
+                        .unwrap_or_default()..,
+                )
+                .unwrap_or_default(),
+            // The filters of the layer's node are applied when drawing its picture
+            filters: without(&effect_state.filters, &layer_effect_state.filters),
+            blur: effect_state.blur,
+            backdrop_filter: &effect_state.backdrop_filter,
+            inherited_transforms,
+        }
+    }
+
+    /// Effects inherited from outside of a cached layer or filter group, applied when drawing its
+    /// picture.
+    fn outside_layer(
+        layer_effect_state: &'a EffectState,
+        inherited: Option<&'a EffectState>,
+    ) -> Self {
+        match inherited {
+            Some(inherited) => Self {
+                clips: Cow::Borrowed(&layer_effect_state.clips),
+                rotations: Cow::Borrowed(&inherited.rotations),
+                transforms: Cow::Borrowed(&inherited.transforms),
+                opacities: &inherited.opacities,
+                blend_modes: &inherited.blend_modes,
+                filters: Cow::Borrowed(&layer_effect_state.filters),
+                blur: None,
+                backdrop_filter: &[],
+                inherited_transforms: &[],
+            },
+            None => Self {
+                clips: Cow::Borrowed(&layer_effect_state.clips),
+                filters: Cow::Borrowed(&layer_effect_state.filters),
+                ..Self::default()
+            },
         }
-        area
     }
+}
 
+/// Filter out the excluded nodes.
+fn without<'a>(node_ids: &'a [NodeId], excluded: &[NodeId]) -> Cow<'a, [NodeId]> {
+    if excluded.is_empty() {
+        Cow::Borrowed(node_ids)
+    } else {
+        node_ids
+            .iter()
+            .filter(|node_id| !excluded.contains(node_id))
+            .copied()
+            .collect()
+    }
+}
+
+/// What gets drawn for a node.
+enum NodeContent {
+    /// The element itself.
+    Element,
+    /// The recorded picture of the element and its descendants, see [RenderPipeline::record_layer].
+    Layer(Picture),
+}
+
+impl RenderPipeline<'_> {
     #[cfg_attr(feature = "hotpath", hotpath::measure)]
-    pub fn render(self) {
+    pub fn render(mut self) {
+        if self.damage.is_empty() {
+            return;
+        }
+
+        let damage_layer = self.canvas.save();
+
+        if !self.damage.is_full() {
+            let mut damage_path = PathBuilder::new();
+            for area in self.damage.areas() {
+                damage_path.add_rect(
+                    SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                    None,
+                    None,
+                );
+            }
+            self.canvas
+                .clip_path(&damage_path.detach(), ClipOp::Intersect, false);
+        }
+
         self.canvas.clear(self.background);
 
         // Clip areas are deterministic no matter what node inherits them
         let mut clip_areas: FxHashMap<NodeId, Area> = FxHashMap::default();
 
-        // TODO: Use incremental rendering
         for i16 in itertools::sorted(self.tree.layers.keys()) {
             let nodes = self.tree.layers.get(i16).unwrap();
             'rendering: for node_id in nodes {
-                let layout_node = self.tree.layout.get(node_id).unwrap();
+                let tree = self.tree;
+                let effect_state = tree.effect_state.get(node_id);
+
+                if let Some(layer_node_id) = effect_state.and_then(|state| tree.layer_root(state)) {
+                    // Painted along with the cached layer or filter group it belongs to
+                    if layer_node_id != *node_id {
+                        continue 'rendering;
+                    }
 
-                if layout_node.hidden {
+                    let effect_state = effect_state.unwrap();
+                    let picture = if effect_state.cached_layer == Some(*node_id) {
+                        match tree.layer_cache.get(node_id) {
+                            Some(picture) => picture,
+                            None => {
+                                let Some(picture) = self.record_layer(node_id, effect_state) else {
+                                    continue 'rendering;
+                                };
+                                tree.layer_cache.insert(*node_id, picture.clone());
+                                picture
+                            }
+                        }
+                    } else {
+                        let Some(picture) = self.record_layer(node_id, effect_state) else {
+                            continue 'rendering;
+                        };
+                        picture
+                    };
+
+                    let inherited = self
+                        .tree
+                        .parents
+                        .get(node_id)
+                        .and_then(|parent_id| self.tree.effect_state.get(parent_id));
+                    self.render_node(
+                        self.canvas,
+                        node_id,
+                        Effects::outside_layer(effect_state, inherited),
+                        &mut clip_areas,
+                        NodeContent::Layer(picture),
+                    );
                     continue 'rendering;
                 }
 
-                let effect_state = self.tree.effect_state.get(node_id);
+                // No need to render this element if it does not paint over any damaged area
+                if !self.damage.is_full()
+                    && self
+                        .tree
+                        .node_painted_area(node_id)
+                        .is_some_and(|painted_area| !self.damage.intersects(&painted_area))
+                {
+                    continue 'rendering;
+                }
+
+                self.render_node(
+                    self.canvas,
+                    node_id,
+                    effect_state.map(Effects::new).unwrap_or_default(),
+                    &mut clip_areas,
+                    NodeContent::Element,
+                );
+            }
+        }
+
+        self.canvas.restore_to_count(damage_layer);
+    }
 
-                let mut visible_area = layout_node.visible_area();
+    /// Record a cached layer or a filter group and its descendants into a picture, relative to
+    /// the origin of the layer's node. The filters of the node are not included, they are applied
+    /// when drawing the picture so the whole subtree is filtered at once.
+    fn record_layer(&mut self, node_id: &NodeId, effect_state: &EffectState) -> Option<Picture> {
+        let tree = self.tree;
+        let origin = tree.layout.get(node_id)?.visible_area().origin;
+        let inherited = tree
+            .parents
+            .get(node_id)
+            .and_then(|parent_id| tree.effect_state.get(parent_id));
 
-                if let Some(effect_state) = effect_state {
-                    visible_area = self.scale_transformed_area(visible_area, &effect_state.scales);
+        // Sort the subtree by layers, and make room for everything it paints
+        let mut bounds: Option<Area> = None;
+        let mut nodes = Vec::new();
+        let mut buffer = vec![*node_id];
+        while let Some(node_id) = buffer.pop() {
+            if let Some(layout_node) = self.tree.layout.get(&node_id) {
+                let element = self.tree.elements.get(&node_id).unwrap();
+                let outset = self.tree.paint_outset(element.as_ref());
+                let area = layout_node
+                    .visible_area()
+                    .union(&Area::new(layout_node.area.origin, layout_node.inner_sizes))
+                    .inflate(outset, outset);
+                bounds = Some(bounds.map_or(area, |bounds| bounds.union(&area)));
+            }
+            let layer = self.tree.layer_state.get(&node_id).unwrap().layer;
+            nodes.push((layer, node_id));
+            if let Some(children) = self.tree.children.get(&node_id) {
+                buffer.extend(children);
+            }
+        }
+        nodes.sort_by_key(|(layer, _)| *layer);
 
-                    // No need to render this element if it is completely clipped
-                    for clip_node_id in effect_state.clips.iter() {
-                        let clip_area = *clip_areas.entry(*clip_node_id).or_insert_with(|| {
-                            let clip_layout_node = self.tree.layout.get(clip_node_id).unwrap();
-                            let clip_effect = self.tree.effect_state.get(clip_node_id).unwrap();
-                            self.scale_transformed_area(
-                                clip_layout_node.visible_area(),
-                                &clip_effect.scales,
-                            )
-                        });
+        let bounds = bounds?.translate(-origin.to_vector());
+        let mut recorder = PictureRecorder::new();
+        let canvas = recorder.begin_recording(
+            SkRect::new(
+                bounds.min_x(),
+                bounds.min_y(),
+                bounds.max_x(),
+                bounds.max_y(),
+            ),
+            false,
+        );
+        canvas.translate((-origin.x, -origin.y));
 
-                        if !visible_area.intersects(&clip_area) {
-                            continue 'rendering;
-                        }
-                    }
+        let mut clip_areas: FxHashMap<NodeId, Area> = FxHashMap::default();
+        for (_, child_id) in nodes {
+            let child_effect_state = tree.effect_state.get(&child_id);
+            let effects = child_effect_state
+                .map(|child_effect_state| {
+                    Effects::inside_layer(child_effect_state, effect_state, inherited)
+                })
+                .unwrap_or_default();
+
+            // Filtered descendants are recorded as a group of their own
+            if let Some(filter_node_id) = effects.filters.first().copied() {
+                if filter_node_id != child_id {
+                    continue;
                 }
+                let Some(picture) = self.record_layer(&child_id, child_effect_state.unwrap())
+                else {
+                    continue;
+                };
+                self.render_node(
+                    canvas,
+                    &child_id,
+                    effects,
+                    &mut clip_areas,
+                    NodeContent::Layer(picture),
+                );
+                continue;
+            }
 
-                let layer = self.canvas.save();
+            self.render_node(
+                canvas,
+                &child_id,
+                effects,
+                &mut clip_areas,
+                NodeContent::Element,
+            );
+        }
 
-                let element = self.tree.elements.get(node_id).unwrap();
-                let text_style_state = self.tree.text_style_state.get(node_id).unwrap();
+        recorder.finish_recording_as_picture(None)
+    }
 
-                if let Some(effect_state) = effect_state {
-                    hotpath::measure_block!("Element Clipping", {
-                        for clip_node_id in effect_state.clips.iter() {
-                            let clip_element = self.tree.elements.get(clip_node_id).unwrap();
-                            let clip_area = clip_areas.get(clip_node_id).unwrap();
+    fn render_node(
+        &mut self,
+        canvas: &Canvas,
+        node_id: &NodeId,
+        effects: Effects,
+        clip_areas: &mut FxHashMap<NodeId, Area>,
+        content: NodeContent,
+    ) {
+        let layout_node = self.tree.layout.get(node_id).unwrap();
 
-                            let clip_context = ClipContext {
-                                canvas: self.canvas,
-                                visible_area: clip_area,
-                                scale_factor: self.scale_factor,
-                            };
+        if layout_node.hidden {
+            return;
+        }
 
-                            clip_element.clip(clip_context);
-                        }
-                    });
+        let visible_area = self
+            .tree
+            .transformed_area(layout_node.visible_area(), &effects.transforms);
+        let content_area = match &content {
+            NodeContent::Element => visible_area,
+            NodeContent::Layer(picture) => {
+                let origin = layout_node.visible_area().origin;
+                let cull_rect = picture.cull_rect().with_offset((origin.x, origin.y));
+                self.tree.transformed_area(
+                    Area::new(
+                        (cull_rect.x(), cull_rect.y()).into(),
+                        (cull_rect.width(), cull_rect.height()).into(),
+                    ),
+                    &effects.transforms,
+                )
+            }
+        };
 
-                    // Pass rotate effect to children
-                    for id in effect_state.rotations.iter() {
-                        let layout_node = self.tree.layout.get(id).unwrap();
-                        let effect = self.tree.effect_state.get(id).unwrap();
-                        let area = layout_node.visible_area();
-                        let origin = effect.transform_origin.origin(&area);
-                        let mut matrix = SkMatrix::new_identity();
-                        matrix.set_rotate(
-                            effect.rotation.unwrap(),
-                            Some(SkPoint {
-                                x: origin.x,
-                                y: origin.y,
-                            }),
-                        );
-                        self.canvas.concat(&matrix);
-                    }
+        // No need to render this element if it is completely clipped
+        for clip_node_id in effects.clips.iter() {
+            let clip_area = *clip_areas.entry(*clip_node_id).or_insert_with(|| {
+                let clip_layout_node = self.tree.layout.get(clip_node_id).unwrap();
+                let clip_effect = self.tree.effect_state.get(clip_node_id).unwrap();
+                self.tree.transformed_area(
+                    clip_layout_node.visible_area(),
+                    &without(&clip_effect.transforms, effects.inherited_transforms),
+                )
+            });
+
+            if !content_area.intersects(&clip_area) {
+                return;
+            }
+        }
+
+        let layer = canvas.save();
+
+        let element = self.tree.elements.get(node_id).unwrap();
+        let text_style_state = self.tree.text_style_state.get(node_id).unwrap();
+
+        hotpath::measure_block!("Element Clipping", {
+            for clip_node_id in effects.clips.iter() {
+                let clip_element = self.tree.elements.get(clip_node_id).unwrap();
+                let clip_area = clip_areas.get(clip_node_id).unwrap();
+
+                let clip_context = ClipContext {
+                    canvas,
+                    visible_area: clip_area,
+                    scale_factor: self.scale_factor,
+                };
+
+                clip_element.clip(clip_context);
+            }
+        });
+
+        // Pass rotate effect to children
+        for id in effects.rotations.iter() {
+            canvas.concat(&self.tree.rotation_matrix(id));
+        }
 
-                    let render_rect = element.render_rect(&visible_area, self.scale_factor as f32);
+        if effects.blur.is_some()
+            || !effects.backdrop_filter.is_empty()
+            || !effects.opacities.is_empty()
+            || !effects.blend_modes.is_empty()
+        {
+            let scale_factor = self.scale_factor as f32;
+            let layer_bounds = match &content {
+                NodeContent::Element => {
+                    let render_rect = element.render_rect(&visible_area, scale_factor);
 
                     // Apply inherited opacity effects with bounds expanded
                     // to accommodate outset shadows
                     let mut layer_bounds = *render_rect.rect();
-                    let scale_factor = self.scale_factor as f32;
 
                     for shadow in element.style().shadows.iter() {
                         if shadow.position == ShadowPosition::Normal {
@@ -143,51 +441,92 @@ impl RenderPipeline<'_> {
                         }
                     }
 
-                    // Composite the blur before the opacity layers so it samples the real content underneath.
-                    if let Some(blur_radius) = effect_state.blur {
-                        let style = element.style();
-
-                        let image_filter = blur(
+                    // Composite the backdrop filters before the opacity layers so they sample the real content underneath.
+                    let backdrop_blur = effects.blur.and_then(|blur_radius| {
+                        blur(
                             (blur_radius * scale_factor, blur_radius * scale_factor),
                             None,
                             None,
                             render_rect.rect(),
-                        );
-                        if let Some(image_filter) = image_filter {
-                            let rec = SaveLayerRec::default()
-                                .bounds(render_rect.rect())
-                                .backdrop(&image_filter);
-
-                            let blur_layer = self.canvas.save();
-                            if style.corner_radius.is_round() {
-                                self.canvas.clip_rrect(render_rect, ClipOp::Intersect, true);
-                            }
-                            self.canvas.save_layer(&rec);
-                            self.canvas.restore_to_count(blur_layer);
+                        )
+                    });
+                    let image_filter =
+                        Filter::compose(effects.backdrop_filter, backdrop_blur, scale_factor);
+                    if let Some(image_filter) = image_filter {
+                        let style = element.style();
+
+                        let rec = SaveLayerRec::default()
+                            .bounds(render_rect.rect())
+                            .backdrop(&image_filter);
+
+                        let backdrop_layer = canvas.save();
+                        if style.corner_radius.is_round() {
+                            canvas.clip_rrect(render_rect, ClipOp::Intersect, true);
                         }
+                        canvas.save_layer(&rec);
+                        canvas.restore_to_count(backdrop_layer);
                     }
 
-                    for opacity in effect_state.opacities.iter() {
-                        self.canvas.save_layer_alpha_f(layer_bounds, *opacity);
-                    }
+                    Some(layer_bounds)
+                }
+                NodeContent::Layer(_) => Some(SkRect::new(
+                    content_area.min_x(),
+                    content_area.min_y(),
+                    content_area.max_x(),
+                    content_area.max_y(),
+                )),
+            };
 
-                    // Transform the canvas area given the scale effects
-                    for id in effect_state.scales.iter() {
-                        let layout_node = self.tree.layout.get(id).unwrap();
-                        let effect = self.tree.effect_state.get(id).unwrap();
-                        let area = layout_node.visible_area();
-                        let origin = effect.transform_origin.origin(&area);
-                        let scale = effect.scale.unwrap();
-
-                        self.canvas.translate((origin.x, origin.y));
-                        self.canvas.scale((scale.x, scale.y));
-                        self.canvas.translate((-origin.x, -origin.y));
-                    }
+            // Blend with the content behind before any other layer, otherwise it would blend with
+            // the empty layers instead
+            for blend_mode in effects.blend_modes.iter() {
+                let mut paint = Paint::default();
+                paint.set_blend_mode((*blend_mode).into());
+                let mut rec = SaveLayerRec::default().paint(&paint);
+                if let Some(layer_bounds) = &layer_bounds {
+                    rec = rec.bounds(layer_bounds);
                 }
+                canvas.save_layer(&rec);
+            }
 
+            for opacity in effects.opacities.iter() {
+                canvas.save_layer_alpha_f(layer_bounds, *opacity);
+            }
+        }
+
+        // Apply the filters to the picture of the filtered subtree, starting from the innermost
+        // element
+        if !effects.filters.is_empty() {
+            let tree = self.tree;
+            let filters = effects
+                .filters
+                .iter()
+                .rev()
+                .flat_map(|id| tree.effect_state.get(id).unwrap().filter.iter());
+            if let Some(image_filter) = Filter::compose(filters, None, self.scale_factor as f32) {
+                let mut paint = Paint::default();
+                paint.set_image_filter(image_filter);
+                let bounds = SkRect::new(
+                    content_area.min_x(),
+                    content_area.min_y(),
+                    content_area.max_x(),
+                    content_area.max_y(),
+                );
+                canvas.save_layer(&SaveLayerRec::default().bounds(&bounds).paint(&paint));
+            }
+        }
+
+        // Transform the canvas area given the scale effects and transforms
+        for id in effects.transforms.iter() {
+            canvas.concat(&self.tree.transform_matrix(id));
+        }
+
+        match content {
+            NodeContent::Element => {
                 let render_context = RenderContext {
+                    node_id: *node_id,
                     font_collection: self.font_collection,
-                    canvas: self.canvas,
+                    canvas,
                     layout_node,
                     tree: self.tree,
                     text_style_state,
@@ -197,9 +536,14 @@ impl RenderPipeline<'_> {
                 hotpath::measure_block!("Element Render", {
                     element.render(render_context);
                 });
-
-                self.canvas.restore_to_count(layer);
+            }
+            NodeContent::Layer(picture) => {
+                let origin = layout_node.visible_area().origin;
+                canvas.translate((origin.x, origin.y));
+                canvas.draw_picture(&picture, None, None);
             }
         }
+
+        canvas.restore_to_count(layer);
     }
 }
@@ -21,6 +21,7 @@ use futures_lite::{
 };
 use itertools::Itertools;
 use pathgraph::PathGraph;
+use ragnarok::PointerCaptureRequest;
 use rustc_hash::{
     FxHashMap,
     FxHashSet,
@@ -40,12 +41,14 @@ use crate::{
             EventType,
         },
         name::EventName,
+        platform::PlatformEvent,
     },
     node_id::NodeId,
     path_element::PathElement,
     prelude::{
         Task,
         TaskId,
+        try_consume_root_context,
     },
     reactive_context::ReactiveContext,
     scope::{
@@ -54,7 +57,11 @@ use crate::{
         ScopeStorage,
     },
     scope_id::ScopeId,
-    tree::DiffModifies,
+    shortcuts::Shortcuts,
+    tree::{
+        DiffModifies,
+        Tree,
+    },
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -180,6 +187,8 @@ pub struct Runner {
 
     pub(crate) sender: futures_channel::mpsc::UnboundedSender<Message>,
     pub(crate) receiver: futures_channel::mpsc::UnboundedReceiver<Message>,
+
+    pub(crate) pointer_capture_requests: Rc<RefCell<Vec<PointerCaptureRequest<NodeId>>>>,
 }
 
 impl Debug for Runner {
@@ -280,6 +289,8 @@ impl Runner {
 
             sender,
             receiver,
+
+            pointer_capture_requests: Rc::default(),
         }
     }
 
@@ -330,6 +341,27 @@ impl Runner {
         )
     }
 
+    /// Run the shortcut triggered by this platform event, if any, see [Shortcuts].
+    ///
+    /// Returns `true` if the event was used and must not be dispatched to the elements.
+    pub fn handle_shortcut(
+        &self,
+        platform_event: &PlatformEvent,
+        tree: &Tree,
+        focused_node_id: Option<NodeId>,
+    ) -> bool {
+        self.run_in(|| {
+            try_consume_root_context::<Shortcuts>().is_some_and(|shortcuts| {
+                shortcuts.handle_platform_event(platform_event, tree, focused_node_id)
+            })
+        })
+    }
+
+    /// Pointer captures requested by event handlers since the last call.
+    pub fn take_pointer_capture_requests(&mut self) -> Vec<PointerCaptureRequest<NodeId>> {
+        self.pointer_capture_requests.take()
+    }
+
     pub fn handle_event(
         &mut self,
         node_id: impl Into<NodeId>,
@@ -366,6 +398,11 @@ impl Runner {
         let mut current_target = Some((path, *scope_id));
         while let Some((path, scope_id)) = current_target.take() {
             let scope = self.scopes.get(&scope_id).cloned().unwrap();
+            let target_node_id = scope
+                .borrow()
+                .nodes
+                .get(&path)
+                .map_or(node_id, |node| node.node_id);
             scope.borrow().with_element(&path, |element| {
                 match element {
                     PathElement::Component { .. } => {
@@ -391,6 +428,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -407,6 +448,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -423,6 +468,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -439,6 +488,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -455,6 +508,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -471,6 +528,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -487,6 +548,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -503,6 +568,10 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -519,6 +588,70 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
+                                                    });
+                                                }
+                                                Some(_) => unreachable!(),
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    EventType::Gesture(data) => {
+                                        let event_handlers = element.events_handlers();
+                                        if let Some(event_handlers) = event_handlers {
+                                            match event_handlers.get(&event_name) {
+                                                Some(EventHandlerType::Gesture(handler)) => {
+                                                    handler.call(Event {
+                                                        data: data.clone(),
+                                                        propagate: propagate.clone(),
+                                                        default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
+                                                    });
+                                                }
+                                                Some(_) => unreachable!(),
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    EventType::PointerCapture(data) => {
+                                        let event_handlers = element.events_handlers();
+                                        if let Some(event_handlers) = event_handlers {
+                                            match event_handlers.get(&event_name) {
+                                                Some(EventHandlerType::PointerCapture(handler)) => {
+                                                    handler.call(Event {
+                                                        data: data.clone(),
+                                                        propagate: propagate.clone(),
+                                                        default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
+                                                    });
+                                                }
+                                                Some(_) => unreachable!(),
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    EventType::Focus(data) => {
+                                        let event_handlers = element.events_handlers();
+                                        if let Some(event_handlers) = event_handlers {
+                                            match event_handlers.get(&event_name) {
+                                                Some(EventHandlerType::Focus(handler)) => {
+                                                    handler.call(Event {
+                                                        data: data.clone(),
+                                                        propagate: propagate.clone(),
+                                                        default: default.clone(),
+                                                        current_target: target_node_id,
+                                                        pointer_capture_requests: self
+                                                            .pointer_capture_requests
+                                                            .clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
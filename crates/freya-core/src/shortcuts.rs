@@ -0,0 +1,667 @@
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    fmt,
+    str::FromStr,
+};
+
+use keyboard_types::{
+    Key,
+    Modifiers,
+    NamedKey,
+};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    element::ElementExt,
+    event_handler::NoArgCallback,
+    events::platform::{
+        KeyboardEventName,
+        PlatformEvent,
+    },
+    integration::ScopeId,
+    node_id::NodeId,
+    prelude::{
+        AccessibilityRole,
+        Event,
+        FocusEventData,
+        KeyboardEventData,
+        ModifiersExt,
+        State,
+        provide_context_for_scope_id,
+        try_consume_root_context,
+        use_drop,
+        use_hook,
+        use_state,
+    },
+    tree::Tree,
+};
+
+/// Modifiers that take part in matching a [KeyStroke], others such as CapsLock are ignored.
+const SHORTCUT_MODIFIERS: Modifiers = Modifiers::CONTROL
+    .union(Modifiers::ALT)
+    .union(Modifiers::SHIFT)
+    .union(Modifiers::META);
+
+/// A key pressed together with some modifiers, e.g `Ctrl+K`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyStroke {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl KeyStroke {
+    pub fn new(key: Key) -> Self {
+        Self {
+            key: normalize_key(key),
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    /// Shortcut for `KeyStroke::new(Key::Character(..))`.
+    pub fn character(character: impl Into<String>) -> Self {
+        Self::new(Key::Character(character.into()))
+    }
+
+    /// Add the platform's command modifier, Command on macOS and Control everywhere else.
+    pub fn primary(self) -> Self {
+        self.with_modifiers(Modifiers::ctrl_or_meta())
+    }
+
+    pub fn ctrl(self) -> Self {
+        self.with_modifiers(Modifiers::CONTROL)
+    }
+
+    pub fn alt(self) -> Self {
+        self.with_modifiers(Modifiers::ALT)
+    }
+
+    pub fn shift(self) -> Self {
+        self.with_modifiers(Modifiers::SHIFT)
+    }
+
+    pub fn meta(self) -> Self {
+        self.with_modifiers(Modifiers::META)
+    }
+
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers |= modifiers & SHORTCUT_MODIFIERS;
+        self
+    }
+
+    /// Get the [KeyStroke] of a key down event, or [None] if only a modifier was pressed.
+    pub fn from_event(data: &KeyboardEventData) -> Option<Self> {
+        if let Key::Named(
+            NamedKey::Alt
+            | NamedKey::AltGraph
+            | NamedKey::CapsLock
+            | NamedKey::Control
+            | NamedKey::Fn
+            | NamedKey::FnLock
+            | NamedKey::Hyper
+            | NamedKey::Meta
+            | NamedKey::NumLock
+            | NamedKey::ScrollLock
+            | NamedKey::Shift
+            | NamedKey::Super
+            | NamedKey::Symbol
+            | NamedKey::SymbolLock,
+        ) = data.key
+        {
+            return None;
+        }
+        Some(Self::new(data.key.clone()).with_modifiers(data.modifiers))
+    }
+
+    /// Whether this stroke is likely to type text, i.e. it has no modifier besides Shift.
+    pub fn is_single_key(&self) -> bool {
+        !self
+            .modifiers
+            .intersects(Modifiers::CONTROL | Modifiers::ALT | Modifiers::META)
+    }
+}
+
+/// Letters are matched regardless of their case, as Shift is tracked as a modifier.
+fn normalize_key(key: Key) -> Key {
+    match key {
+        Key::Character(character) => Key::Character(character.to_lowercase()),
+        key => key,
+    }
+}
+
+impl fmt::Display for KeyStroke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (Modifiers::CONTROL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::META, "Meta"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        match &self.key {
+            Key::Character(character) => f.write_str(&character.to_uppercase()),
+            key => write!(f, "{key}"),
+        }
+    }
+}
+
+/// Error returned when parsing an invalid [KeyStroke] or [Shortcut].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortcutParseError(pub String);
+
+impl fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid shortcut `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ShortcutParseError {}
+
+impl FromStr for KeyStroke {
+    type Err = ShortcutParseError;
+
+    /// Parse strokes such as `Ctrl+K`, `Primary+Shift+P` or `Alt+ArrowUp`.
+    /// `Primary` is the platform's command modifier, see [KeyStroke::primary].
+    fn from_str(stroke: &str) -> Result<Self, Self::Err> {
+        let error = || ShortcutParseError(stroke.to_string());
+        let (modifiers, key) = match stroke.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None if stroke == "+" => ("", "+"),
+            None => stroke.rsplit_once('+').unwrap_or(("", stroke)),
+        };
+        if key.is_empty() {
+            return Err(error());
+        }
+        let key = if key.chars().count() == 1 {
+            Key::Character(key.to_string())
+        } else {
+            Key::from_str(key).map_err(|_| error())?
+        };
+
+        let mut key_stroke = Self::new(key);
+        for modifier in modifiers.split('+').filter(|modifier| !modifier.is_empty()) {
+            key_stroke = match modifier.to_lowercase().as_str() {
+                "primary" => key_stroke.primary(),
+                "ctrl" | "control" => key_stroke.ctrl(),
+                "alt" | "option" => key_stroke.alt(),
+                "shift" => key_stroke.shift(),
+                "meta" | "cmd" | "command" | "super" => key_stroke.meta(),
+                _ => return Err(error()),
+            };
+        }
+        Ok(key_stroke)
+    }
+}
+
+/// A sequence of one or more [KeyStroke]s, e.g `Ctrl+S` or `Ctrl+K Ctrl+S`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut(Vec<KeyStroke>);
+
+impl Shortcut {
+    pub fn new(strokes: impl IntoIterator<Item = KeyStroke>) -> Self {
+        Self(strokes.into_iter().collect())
+    }
+
+    pub fn strokes(&self) -> &[KeyStroke] {
+        &self.0
+    }
+
+    /// Whether pressing this shortcut also presses the whole `other` one, or the other way around.
+    pub fn overlaps(&self, other: &Shortcut) -> bool {
+        self.0.starts_with(&other.0) || other.0.starts_with(&self.0)
+    }
+}
+
+impl From<KeyStroke> for Shortcut {
+    fn from(stroke: KeyStroke) -> Self {
+        Self(vec![stroke])
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutParseError;
+
+    /// Parse whitespace separated [KeyStroke]s, e.g `Primary+K Primary+S`.
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        let strokes = shortcut
+            .split_whitespace()
+            .map(KeyStroke::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if strokes.is_empty() {
+            return Err(ShortcutParseError(shortcut.to_string()));
+        }
+        Ok(Self(strokes))
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, stroke) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{stroke}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A subtree of elements that enables its shortcuts only while it contains the focused element.
+///
+/// Create it with [use_focus_scope] and attach it to the subtree root with
+/// `.on_focus_within_change(focus_scope.track())`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FocusScope {
+    /// The subtree root while it contains the focused element.
+    focus_within: State<Option<NodeId>>,
+}
+
+impl FocusScope {
+    /// Event handler that keeps this scope up to date, pass it to `on_focus_within_change`.
+    pub fn track(self) -> impl FnMut(Event<FocusEventData>) + 'static {
+        let mut focus_within = self.focus_within;
+        move |e: Event<FocusEventData>| focus_within.set(e.focused.then_some(e.current_target))
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focus_within.peek().is_some()
+    }
+
+    /// How far the subtree root is from the focused element, [None] if it doesn't contain it.
+    fn depth(&self, focus_path: &[NodeId]) -> Option<usize> {
+        let node_id = (*self.focus_within.peek())?;
+        focus_path
+            .iter()
+            .position(|focused_node_id| *focused_node_id == node_id)
+    }
+}
+
+/// Create a [FocusScope] that persists for the lifetime of the component.
+pub fn use_focus_scope() -> FocusScope {
+    let focus_within = use_state(|| None);
+    FocusScope { focus_within }
+}
+
+/// Where a shortcut is enabled.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ShortcutScope {
+    /// Enabled anywhere in the window.
+    Window,
+    /// Enabled only while the [FocusScope] contains the focused element.
+    /// Takes precedence over [ShortcutScope::Window] shortcuts with the same keys,
+    /// and nested scopes over the ones around them.
+    Focus(FocusScope),
+}
+
+impl ShortcutScope {
+    pub fn is_active(&self) -> bool {
+        match self {
+            Self::Window => true,
+            Self::Focus(focus_scope) => focus_scope.is_focused(),
+        }
+    }
+
+    /// Precedence of this scope given the path from the focused element up to the root,
+    /// lower wins, [None] if the scope is not active.
+    fn rank(&self, focus_path: &[NodeId]) -> Option<usize> {
+        match self {
+            Self::Window => Some(usize::MAX),
+            Self::Focus(focus_scope) => focus_scope.depth(focus_path),
+        }
+    }
+}
+
+/// A registered shortcut, as listed by [Shortcuts::list].
+#[derive(Clone, PartialEq)]
+pub struct ShortcutInfo {
+    pub name: Cow<'static, str>,
+    /// The keys that currently trigger it, with any rebinding applied.
+    pub shortcut: Shortcut,
+    /// The keys it was registered with.
+    pub default_shortcut: Shortcut,
+    pub scope: ShortcutScope,
+}
+
+/// Two shortcuts of the same scope that can't be told apart, as returned by [Shortcuts::conflicts].
+#[derive(Clone, PartialEq)]
+pub struct ShortcutConflict {
+    pub first: ShortcutInfo,
+    pub second: ShortcutInfo,
+}
+
+#[derive(Clone, PartialEq)]
+struct RegisteredShortcut {
+    id: u64,
+    info: ShortcutInfo,
+}
+
+enum ShortcutMatch {
+    Matched(u64),
+    Partial,
+    None,
+}
+
+/// Registry of the named keyboard shortcuts of the app.
+///
+/// Register shortcuts with [use_shortcut], the key down events are matched against them
+/// before reaching any element. Key down events that trigger a shortcut, or that start a
+/// multi-stroke one, are not dispatched to the elements. Strokes without Ctrl, Alt or Meta
+/// are left to the focused element when it is a text input.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     use_shortcut(
+///         "save",
+///         "Primary+S".parse::<Shortcut>().unwrap(),
+///         ShortcutScope::Window,
+///         || println!("Saving"),
+///     );
+///
+///     rect().expanded()
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Shortcuts {
+    registered: State<Vec<RegisteredShortcut>>,
+    handlers: State<FxHashMap<u64, NoArgCallback<()>>>,
+    rebinds: State<FxHashMap<Cow<'static, str>, Shortcut>>,
+    pending_strokes: State<Vec<KeyStroke>>,
+    id_counter: State<u64>,
+}
+
+impl Shortcuts {
+    pub fn get() -> Self {
+        match try_consume_root_context() {
+            Some(shortcuts) => shortcuts,
+            None => {
+                let shortcuts = Shortcuts {
+                    registered: State::create_in_scope(Vec::new(), ScopeId::ROOT),
+                    handlers: State::create_in_scope(FxHashMap::default(), ScopeId::ROOT),
+                    rebinds: State::create_in_scope(FxHashMap::default(), ScopeId::ROOT),
+                    pending_strokes: State::create_in_scope(Vec::new(), ScopeId::ROOT),
+                    id_counter: State::create_in_scope(0, ScopeId::ROOT),
+                };
+                provide_context_for_scope_id(shortcuts, ScopeId::ROOT);
+                shortcuts
+            }
+        }
+    }
+
+    /// Register a shortcut, you most likely want to use [use_shortcut] instead.
+    pub fn register(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        shortcut: Shortcut,
+        scope: ShortcutScope,
+        handler: impl Into<NoArgCallback<()>>,
+    ) -> u64 {
+        let name = name.into();
+        let id = {
+            let mut id_counter = self.id_counter.write_silently();
+            *id_counter += 1;
+            *id_counter
+        };
+        let info = ShortcutInfo {
+            shortcut: self
+                .rebinds
+                .peek()
+                .get(&name)
+                .cloned()
+                .unwrap_or_else(|| shortcut.clone()),
+            default_shortcut: shortcut,
+            name,
+            scope,
+        };
+
+        for registered in self.registered.peek().iter() {
+            if registered.info.scope == info.scope
+                && registered.info.shortcut.overlaps(&info.shortcut)
+            {
+                tracing::warn!(
+                    "Shortcut `{}` ({}) conflicts with `{}` ({}).",
+                    info.name,
+                    info.shortcut,
+                    registered.info.name,
+                    registered.info.shortcut
+                );
+            }
+        }
+
+        self.handlers.write_silently().insert(id, handler.into());
+        self.registered
+            .write_unchecked()
+            .push(RegisteredShortcut { id, info });
+        id
+    }
+
+    /// Replace the handler of a registered shortcut without notifying the listings.
+    pub fn update_handler(&self, id: u64, handler: impl Into<NoArgCallback<()>>) {
+        if let Some(current_handler) = self.handlers.write_silently().get_mut(&id) {
+            *current_handler = handler.into();
+        }
+    }
+
+    pub fn unregister(&self, id: u64) {
+        if let Some(mut handlers) = self.handlers.try_write_unchecked() {
+            handlers.remove(&id);
+        }
+        if let Some(mut registered) = self.registered.try_write_unchecked() {
+            registered.retain(|registered| registered.id != id);
+        }
+    }
+
+    /// List the registered shortcuts, subscribing to their changes.
+    pub fn list(&self) -> Vec<ShortcutInfo> {
+        self.registered
+            .read()
+            .iter()
+            .map(|registered| registered.info.clone())
+            .collect()
+    }
+
+    /// Registered shortcuts of the same scope that are equal or where one is the start of the other,
+    /// subscribing to their changes.
+    pub fn conflicts(&self) -> Vec<ShortcutConflict> {
+        let registered = self.registered.read();
+        let mut conflicts = Vec::new();
+        for (i, first) in registered.iter().enumerate() {
+            for second in &registered[i + 1..] {
+                if first.info.scope == second.info.scope
+                    && first.info.shortcut.overlaps(&second.info.shortcut)
+                {
+                    conflicts.push(ShortcutConflict {
+                        first: first.info.clone(),
+                        second: second.info.clone(),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Trigger the shortcuts named `name` with `shortcut` instead of their registered keys,
+    /// including the ones registered later on.
+    pub fn rebind(&self, name: impl Into<Cow<'static, str>>, shortcut: Shortcut) {
+        let name = name.into();
+        for registered in self.registered.write_unchecked().iter_mut() {
+            if registered.info.name == name {
+                registered.info.shortcut = shortcut.clone();
+            }
+        }
+        self.rebinds.write_unchecked().insert(name, shortcut);
+    }
+
+    /// Restore the registered keys of the shortcuts named `name`.
+    pub fn reset(&self, name: &str) {
+        for registered in self.registered.write_unchecked().iter_mut() {
+            if registered.info.name == name {
+                registered.info.shortcut = registered.info.default_shortcut.clone();
+            }
+        }
+        self.rebinds.write_unchecked().remove(name);
+    }
+
+    /// Run the shortcut triggered by this platform event, if any.
+    ///
+    /// Returns `true` if the event was used, either by triggering a shortcut
+    /// or as the first strokes of a multi-stroke shortcut.
+    pub(crate) fn handle_platform_event(
+        &self,
+        platform_event: &PlatformEvent,
+        tree: &Tree,
+        focused_node_id: Option<NodeId>,
+    ) -> bool {
+        let PlatformEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key,
+            code,
+            modifiers,
+        } = platform_event
+        else {
+            return false;
+        };
+        let Some(stroke) =
+            KeyStroke::from_event(&KeyboardEventData::new(key.clone(), *code, *modifiers))
+        else {
+            return false;
+        };
+
+        // The root being focused means that nothing is
+        let mut focus_path = Vec::new();
+        let mut next_node = focused_node_id.filter(|node_id| *node_id != NodeId::ROOT);
+        while let Some(node_id) = next_node {
+            focus_path.push(node_id);
+            next_node = tree.parents.get(&node_id).copied();
+        }
+
+        // Let text inputs receive what is being typed, unless it continues a multi-stroke shortcut
+        let is_typing = stroke.is_single_key()
+            && self.pending_strokes.peek().is_empty()
+            && focus_path.first().is_some_and(|node_id| {
+                tree.elements
+                    .get(node_id)
+                    .is_some_and(|element| is_text_input(element.accessibility().builder.role()))
+            });
+        if is_typing {
+            return false;
+        }
+
+        self.handle_stroke(stroke, &focus_path)
+    }
+
+    fn handle_stroke(&self, stroke: KeyStroke, focus_path: &[NodeId]) -> bool {
+        let mut strokes = self.pending_strokes.peek().to_vec();
+        strokes.push(stroke.clone());
+        let mut shortcut_match = self.find_match(&strokes, focus_path);
+
+        // The pending strokes lead nowhere, so try again with only this stroke
+        if matches!(shortcut_match, ShortcutMatch::None) && strokes.len() > 1 {
+            strokes = vec![stroke];
+            shortcut_match = self.find_match(&strokes, focus_path);
+        }
+
+        match shortcut_match {
+            ShortcutMatch::Matched(id) => {
+                self.pending_strokes.write_silently().clear();
+                let handler = self.handlers.peek().get(&id).cloned();
+                if let Some(handler) = handler {
+                    handler.call();
+                }
+                true
+            }
+            ShortcutMatch::Partial => {
+                *self.pending_strokes.write_silently() = strokes;
+                true
+            }
+            ShortcutMatch::None => {
+                self.pending_strokes.write_silently().clear();
+                false
+            }
+        }
+    }
+
+    fn find_match(&self, strokes: &[KeyStroke], focus_path: &[NodeId]) -> ShortcutMatch {
+        let registered = self.registered.peek();
+        let mut active = registered.iter().filter_map(|registered| {
+            registered
+                .info
+                .scope
+                .rank(focus_path)
+                .map(|rank| (rank, registered))
+        });
+
+        // The focus scope closest to the focused element wins, then the window ones.
+        // Among equally ranked shortcuts the last registered wins.
+        let matched = active
+            .clone()
+            .filter(|(_, registered)| registered.info.shortcut.strokes() == strokes)
+            .min_by_key(|(rank, registered)| (*rank, Reverse(registered.id)));
+        if let Some((_, registered)) = matched {
+            return ShortcutMatch::Matched(registered.id);
+        }
+
+        if active.any(|(_, registered)| registered.info.shortcut.strokes().starts_with(strokes)) {
+            ShortcutMatch::Partial
+        } else {
+            ShortcutMatch::None
+        }
+    }
+}
+
+/// Roles of the elements that take typed text.
+fn is_text_input(role: AccessibilityRole) -> bool {
+    matches!(
+        role,
+        AccessibilityRole::TextInput
+            | AccessibilityRole::MultilineTextInput
+            | AccessibilityRole::PasswordInput
+            | AccessibilityRole::SearchInput
+            | AccessibilityRole::EmailInput
+            | AccessibilityRole::NumberInput
+            | AccessibilityRole::PhoneNumberInput
+            | AccessibilityRole::UrlInput
+            | AccessibilityRole::Terminal
+    )
+}
+
+/// Register a named shortcut for the lifetime of the component.
+///
+/// The name identifies it in [Shortcuts::list] and for [Shortcuts::rebind].
+/// Keys and scope are taken from the first render, the handler is kept up to date.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// fn editor() -> impl IntoElement {
+///     let focus_scope = use_focus_scope();
+///     use_shortcut(
+///         "editor.save_all",
+///         "Primary+K Primary+S".parse::<Shortcut>().unwrap(),
+///         ShortcutScope::Focus(focus_scope),
+///         || println!("Saving all"),
+///     );
+///
+///     rect().on_focus_within_change(focus_scope.track())
+/// }
+/// ```
+pub fn use_shortcut(
+    name: impl Into<Cow<'static, str>>,
+    shortcut: impl Into<Shortcut>,
+    scope: ShortcutScope,
+    handler: impl Into<NoArgCallback<()>>,
+) {
+    let shortcuts = Shortcuts::get();
+    let handler = handler.into();
+    let mut is_first_run = false;
+    let id = use_hook(|| {
+        is_first_run = true;
+        shortcuts.register(name, shortcut.into(), scope, handler.clone())
+    });
+    if !is_first_run {
+        shortcuts.update_handler(id, handler);
+    }
+    use_drop(move || shortcuts.unregister(id));
+}
@@ -0,0 +1,94 @@
+use freya_engine::prelude::SkBlendMode;
+
+/// How an element is composited with the content behind it, like `mix-blend-mode` in CSS.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Paint the element over the content behind it. This is the default.
+    #[default]
+    Normal,
+    /// Multiply the colors, always resulting in a darker color.
+    Multiply,
+    /// Multiply the inverted colors, always resulting in a lighter color.
+    Screen,
+    /// [`Multiply`](Self::Multiply) or [`Screen`](Self::Screen) depending on the content behind.
+    Overlay,
+    /// Keep the darkest of both colors.
+    Darken,
+    /// Keep the lightest of both colors.
+    Lighten,
+    /// Brighten the content behind to reflect the element's color.
+    ColorDodge,
+    /// Darken the content behind to reflect the element's color.
+    ColorBurn,
+    /// [`Multiply`](Self::Multiply) or [`Screen`](Self::Screen) depending on the element's color.
+    HardLight,
+    /// Softer version of [`HardLight`](Self::HardLight).
+    SoftLight,
+    /// Subtract the darker of both colors from the lighter one.
+    Difference,
+    /// Like [`Difference`](Self::Difference) but with lower contrast.
+    Exclusion,
+    /// Hue of the element with the saturation and luminosity of the content behind.
+    Hue,
+    /// Saturation of the element with the hue and luminosity of the content behind.
+    Saturation,
+    /// Hue and saturation of the element with the luminosity of the content behind.
+    Color,
+    /// Luminosity of the element with the hue and saturation of the content behind.
+    Luminosity,
+    /// Only keep the content behind where the element is not painted, knocking it out.
+    KnockOut,
+    /// Only paint the element where there is content behind.
+    SourceAtop,
+}
+
+impl From<BlendMode> for SkBlendMode {
+    fn from(value: BlendMode) -> Self {
+        match value {
+            BlendMode::Normal => SkBlendMode::SrcOver,
+            BlendMode::Multiply => SkBlendMode::Multiply,
+            BlendMode::Screen => SkBlendMode::Screen,
+            BlendMode::Overlay => SkBlendMode::Overlay,
+            BlendMode::Darken => SkBlendMode::Darken,
+            BlendMode::Lighten => SkBlendMode::Lighten,
+            BlendMode::ColorDodge => SkBlendMode::ColorDodge,
+            BlendMode::ColorBurn => SkBlendMode::ColorBurn,
+            BlendMode::HardLight => SkBlendMode::HardLight,
+            BlendMode::SoftLight => SkBlendMode::SoftLight,
+            BlendMode::Difference => SkBlendMode::Difference,
+            BlendMode::Exclusion => SkBlendMode::Exclusion,
+            BlendMode::Hue => SkBlendMode::Hue,
+            BlendMode::Saturation => SkBlendMode::Saturation,
+            BlendMode::Color => SkBlendMode::Color,
+            BlendMode::Luminosity => SkBlendMode::Luminosity,
+            BlendMode::KnockOut => SkBlendMode::DstOut,
+            BlendMode::SourceAtop => SkBlendMode::SrcATop,
+        }
+    }
+}
+
+impl BlendMode {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::Multiply => "multiply".to_string(),
+            Self::Screen => "screen".to_string(),
+            Self::Overlay => "overlay".to_string(),
+            Self::Darken => "darken".to_string(),
+            Self::Lighten => "lighten".to_string(),
+            Self::ColorDodge => "color-dodge".to_string(),
+            Self::ColorBurn => "color-burn".to_string(),
+            Self::HardLight => "hard-light".to_string(),
+            Self::SoftLight => "soft-light".to_string(),
+            Self::Difference => "difference".to_string(),
+            Self::Exclusion => "exclusion".to_string(),
+            Self::Hue => "hue".to_string(),
+            Self::Saturation => "saturation".to_string(),
+            Self::Color => "color".to_string(),
+            Self::Luminosity => "luminosity".to_string(),
+            Self::KnockOut => "knock-out".to_string(),
+            Self::SourceAtop => "source-atop".to_string(),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use freya_engine::prelude::{
+    PathBuilder,
+    PathOp,
+    SkMatrix,
+    SkPath,
+    SkPoint,
+    SkRect,
+};
+use torin::prelude::Area;
+
+/// Shape that an element and its descendants are clipped to, bounded to the element's area.
+///
+/// Shapes can be combined to cut parts out, like a badge out of a circular avatar:
+///
+/// ```
+/// # use freya::prelude::*;
+/// let avatar = ClipPath::circle().difference(ClipPath::svg(
+///     "M 60 60 m -14 0 a 14 14 0 1 0 28 0 a 14 14 0 1 0 -28 0",
+/// ));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipPath {
+    /// Largest circle centered in the element.
+    Circle,
+    /// Ellipse filling the element.
+    Ellipse,
+    /// Polygon with its points as fractions of the element's size, where `(0.0, 0.0)` is the
+    /// top-left corner and `(1.0, 1.0)` the bottom-right corner.
+    Polygon(Vec<(f32, f32)>),
+    /// SVG path data, in logical pixels relative to the element's top-left corner.
+    Svg(Cow<'static, str>),
+    /// Area of the first shape not covered by the second one.
+    Difference(Box<ClipPath>, Box<ClipPath>),
+}
+
+impl ClipPath {
+    pub fn circle() -> Self {
+        Self::Circle
+    }
+
+    pub fn ellipse() -> Self {
+        Self::Ellipse
+    }
+
+    pub fn polygon(points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        Self::Polygon(points.into_iter().collect())
+    }
+
+    pub fn svg(path: impl Into<Cow<'static, str>>) -> Self {
+        Self::Svg(path.into())
+    }
+
+    /// Cut the given shape out of this one.
+    pub fn difference(self, other: ClipPath) -> Self {
+        Self::Difference(Box::new(self), Box::new(other))
+    }
+
+    /// Path of the shape for an element laid out in `area`. `None` if the SVG path data is
+    /// invalid.
+    pub fn path(&self, area: Area, scale_factor: f32) -> Option<SkPath> {
+        match self {
+            Self::Circle => {
+                let center = area.center();
+                let radius = area.width().min(area.height()) / 2.;
+                let mut path = PathBuilder::new();
+                path.add_circle((center.x, center.y), radius, None);
+                Some(path.detach())
+            }
+            Self::Ellipse => {
+                let mut path = PathBuilder::new();
+                path.add_oval(
+                    SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                    None,
+                    None,
+                );
+                Some(path.detach())
+            }
+            Self::Polygon(points) => {
+                let points = points
+                    .iter()
+                    .map(|(x, y)| {
+                        SkPoint::new(
+                            area.min_x() + x * area.width(),
+                            area.min_y() + y * area.height(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let mut path = PathBuilder::new();
+                path.add_polygon(&points, true);
+                Some(path.detach())
+            }
+            Self::Svg(data) => {
+                let mut matrix = SkMatrix::translate((area.min_x(), area.min_y()));
+                matrix.pre_scale((scale_factor, scale_factor), None);
+                Some(SkPath::from_svg(data)?.with_transform(&matrix))
+            }
+            Self::Difference(shape, cutout) => shape
+                .path(area, scale_factor)?
+                .op(&cutout.path(area, scale_factor)?, PathOp::Difference),
+        }
+    }
+}
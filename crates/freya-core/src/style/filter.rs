@@ -0,0 +1,242 @@
+use freya_engine::prelude::{
+    ImageFilter,
+    SkColor4f,
+    color_filters,
+    image_filters::{
+        self,
+        CropRect,
+    },
+};
+
+use crate::style::color::Color;
+
+/// A filter effect applied to the pixels of an element, like in CSS.
+///
+/// Filters are applied in order, each one taking the output of the previous one:
+///
+/// ```
+/// # use freya::prelude::*;
+/// let disabled = [Filter::grayscale(1.0), Filter::brightness(1.2)];
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Filter {
+    /// Gaussian blur of the given radius.
+    Blur(f32),
+    /// Multiply the colors, where `1.0` leaves them unchanged and `0.0` makes them black.
+    Brightness(f32),
+    /// Adjust the contrast, where `1.0` leaves it unchanged and `0.0` makes everything grey.
+    Contrast(f32),
+    /// Convert to grayscale, from `0.0` (unchanged) to `1.0` (completely grey).
+    Grayscale(f32),
+    /// Adjust the saturation, where `1.0` leaves it unchanged and `0.0` removes it.
+    Saturate(f32),
+    /// Rotate the hue by the given angle in degrees.
+    HueRotate(f32),
+    /// Invert the colors, from `0.0` (unchanged) to `1.0` (completely inverted).
+    Invert(f32),
+    /// Shadow following the shape of the non-transparent pixels.
+    DropShadow {
+        x: f32,
+        y: f32,
+        blur: f32,
+        color: Color,
+    },
+}
+
+impl Filter {
+    pub fn blur(radius: f32) -> Self {
+        Self::Blur(radius)
+    }
+
+    pub fn brightness(amount: f32) -> Self {
+        Self::Brightness(amount)
+    }
+
+    pub fn contrast(amount: f32) -> Self {
+        Self::Contrast(amount)
+    }
+
+    pub fn grayscale(amount: f32) -> Self {
+        Self::Grayscale(amount)
+    }
+
+    pub fn saturate(amount: f32) -> Self {
+        Self::Saturate(amount)
+    }
+
+    pub fn hue_rotate(degrees: f32) -> Self {
+        Self::HueRotate(degrees)
+    }
+
+    pub fn invert(amount: f32) -> Self {
+        Self::Invert(amount)
+    }
+
+    pub fn drop_shadow(x: f32, y: f32, blur: f32, color: impl Into<Color>) -> Self {
+        Self::DropShadow {
+            x,
+            y,
+            blur,
+            color: color.into(),
+        }
+    }
+
+    /// Whether the filter paints outside of the original pixels.
+    pub fn expands(&self) -> bool {
+        matches!(self, Self::Blur(_) | Self::DropShadow { .. })
+    }
+
+    /// Row-major 4x5 color matrix of the color filters, `None` for the rest.
+    fn color_matrix(&self) -> Option<[f32; 20]> {
+        let matrix = match *self {
+            Self::Brightness(b) => [
+                b, 0., 0., 0., 0., //
+                0., b, 0., 0., 0., //
+                0., 0., b, 0., 0., //
+                0., 0., 0., 1., 0.,
+            ],
+            Self::Contrast(c) => {
+                let t = (1. - c) / 2.;
+                [
+                    c, 0., 0., 0., t, //
+                    0., c, 0., 0., t, //
+                    0., 0., c, 0., t, //
+                    0., 0., 0., 1., 0.,
+                ]
+            }
+            Self::Grayscale(amount) => {
+                let s = 1. - amount.clamp(0., 1.);
+                [
+                    0.2126 + 0.7874 * s,
+                    0.7152 - 0.7152 * s,
+                    0.0722 - 0.0722 * s,
+                    0.,
+                    0.,
+                    0.2126 - 0.2126 * s,
+                    0.7152 + 0.2848 * s,
+                    0.0722 - 0.0722 * s,
+                    0.,
+                    0.,
+                    0.2126 - 0.2126 * s,
+                    0.7152 - 0.7152 * s,
+                    0.0722 + 0.9278 * s,
+                    0.,
+                    0.,
+                    0.,
+                    0.,
+                    0.,
+                    1.,
+                    0.,
+                ]
+            }
+            Self::Saturate(s) => [
+                0.213 + 0.787 * s,
+                0.715 - 0.715 * s,
+                0.072 - 0.072 * s,
+                0.,
+                0.,
+                0.213 - 0.213 * s,
+                0.715 + 0.285 * s,
+                0.072 - 0.072 * s,
+                0.,
+                0.,
+                0.213 - 0.213 * s,
+                0.715 - 0.715 * s,
+                0.072 + 0.928 * s,
+                0.,
+                0.,
+                0.,
+                0.,
+                0.,
+                1.,
+                0.,
+            ],
+            Self::HueRotate(degrees) => {
+                let (s, c) = degrees.to_radians().sin_cos();
+                [
+                    0.213 + c * 0.787 - s * 0.213,
+                    0.715 - c * 0.715 - s * 0.715,
+                    0.072 - c * 0.072 + s * 0.928,
+                    0.,
+                    0.,
+                    0.213 - c * 0.213 + s * 0.143,
+                    0.715 + c * 0.285 + s * 0.140,
+                    0.072 - c * 0.072 - s * 0.283,
+                    0.,
+                    0.,
+                    0.213 - c * 0.213 - s * 0.787,
+                    0.715 - c * 0.715 + s * 0.715,
+                    0.072 + c * 0.928 + s * 0.072,
+                    0.,
+                    0.,
+                    0.,
+                    0.,
+                    0.,
+                    1.,
+                    0.,
+                ]
+            }
+            Self::Invert(amount) => {
+                let a = amount.clamp(0., 1.);
+                let m = 1. - 2. * a;
+                [
+                    m, 0., 0., 0., a, //
+                    0., m, 0., 0., a, //
+                    0., 0., m, 0., a, //
+                    0., 0., 0., 1., 0.,
+                ]
+            }
+            Self::Blur(_) | Self::DropShadow { .. } => return None,
+        };
+        Some(matrix)
+    }
+
+    /// Image filter applying this filter to the output of `input`, or to the original pixels
+    /// if `None`.
+    pub fn image_filter(
+        &self,
+        input: Option<ImageFilter>,
+        scale_factor: f32,
+    ) -> Option<ImageFilter> {
+        if let Some(matrix) = self.color_matrix() {
+            return image_filters::color_filter(
+                color_filters::matrix_row_major(&matrix, None),
+                input,
+                CropRect::default(),
+            );
+        }
+
+        match *self {
+            Self::Blur(radius) => image_filters::blur(
+                (radius * scale_factor, radius * scale_factor),
+                None,
+                input,
+                CropRect::default(),
+            ),
+            Self::DropShadow { x, y, blur, color } => {
+                let sigma = blur * scale_factor / 2.;
+                image_filters::drop_shadow(
+                    (x * scale_factor, y * scale_factor),
+                    (sigma, sigma),
+                    SkColor4f::from(color),
+                    None,
+                    input,
+                    CropRect::default(),
+                )
+            }
+            _ => input,
+        }
+    }
+
+    /// Compose the filters in order on top of `input` into a single image filter.
+    pub fn compose<'a>(
+        filters: impl IntoIterator<Item = &'a Filter>,
+        input: Option<ImageFilter>,
+        scale_factor: f32,
+    ) -> Option<ImageFilter> {
+        filters.into_iter().fold(input, |input, filter| {
+            filter.image_filter(input.clone(), scale_factor).or(input)
+        })
+    }
+}
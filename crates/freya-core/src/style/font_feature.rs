@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+/// An OpenType feature toggle, like `font-feature-settings` in CSS.
+///
+/// Features are identified by their four letter tag, and only apply if the font supports them:
+///
+/// ```
+/// # use freya::prelude::*;
+/// let features = [FontFeature::tabular_numbers(), FontFeature::new("ss01", 1)];
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FontFeature {
+    pub tag: Cow<'static, str>,
+    /// `0` disables the feature, `1` enables it, and higher values pick an alternate.
+    pub value: i32,
+}
+
+impl FontFeature {
+    pub fn new(tag: impl Into<Cow<'static, str>>, value: i32) -> Self {
+        Self {
+            tag: tag.into(),
+            value,
+        }
+    }
+
+    /// Enable the feature with the given tag.
+    pub fn enable(tag: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(tag, 1)
+    }
+
+    /// Disable the feature with the given tag.
+    pub fn disable(tag: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(tag, 0)
+    }
+
+    /// Digits of the same width (`tnum`), so numbers line up in tables and counters.
+    pub fn tabular_numbers() -> Self {
+        Self::enable("tnum")
+    }
+
+    /// Digits of their natural width (`pnum`).
+    pub fn proportional_numbers() -> Self {
+        Self::enable("pnum")
+    }
+
+    /// Toggle the standard ligatures (`liga`), like `fi`.
+    pub fn ligatures(enabled: bool) -> Self {
+        Self::new("liga", enabled as i32)
+    }
+
+    /// Lowercase letters as small capitals (`smcp`).
+    pub fn small_caps() -> Self {
+        Self::enable("smcp")
+    }
+
+    pub fn pretty(&self) -> String {
+        format!("\"{}\" {}", self.tag, self.value)
+    }
+}
+
+impl<T: Into<Cow<'static, str>>> From<(T, i32)> for FontFeature {
+    fn from((tag, value): (T, i32)) -> Self {
+        Self::new(tag, value)
+    }
+}
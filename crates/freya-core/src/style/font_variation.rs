@@ -0,0 +1,85 @@
+use std::{
+    borrow::Cow,
+    hash::Hash,
+};
+
+use freya_engine::prelude::{
+    FourByteTag,
+    VariationCoordinate,
+};
+
+/// A value for an axis of a variable font, like `font-variation-settings` in CSS.
+///
+/// Axes are identified by their four letter tag, and only apply if the font supports them:
+///
+/// ```
+/// # use freya::prelude::*;
+/// let variations = [
+///     FontVariation::weight(650.),
+///     FontVariation::new("GRAD", -50.),
+/// ];
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontVariation {
+    pub axis: Cow<'static, str>,
+    pub value: f32,
+}
+
+impl Hash for FontVariation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.axis.hash(state);
+        self.value.to_bits().hash(state);
+    }
+}
+
+impl FontVariation {
+    pub fn new(axis: impl Into<Cow<'static, str>>, value: f32) -> Self {
+        Self {
+            axis: axis.into(),
+            value,
+        }
+    }
+
+    /// Weight axis (`wght`).
+    pub fn weight(value: f32) -> Self {
+        Self::new("wght", value)
+    }
+
+    /// Width axis (`wdth`), as a percentage of the normal width.
+    pub fn width(value: f32) -> Self {
+        Self::new("wdth", value)
+    }
+
+    /// Slant axis (`slnt`), in degrees.
+    pub fn slant(value: f32) -> Self {
+        Self::new("slnt", value)
+    }
+
+    /// Optical size axis (`opsz`).
+    pub fn optical_size(value: f32) -> Self {
+        Self::new("opsz", value)
+    }
+
+    pub fn pretty(&self) -> String {
+        format!("\"{}\" {}", self.axis, self.value)
+    }
+}
+
+impl<T: Into<Cow<'static, str>>> From<(T, f32)> for FontVariation {
+    fn from((axis, value): (T, f32)) -> Self {
+        Self::new(axis, value)
+    }
+}
+
+impl From<&FontVariation> for VariationCoordinate {
+    fn from(value: &FontVariation) -> Self {
+        // Tags shorter than four letters are padded with spaces
+        let mut tag = value.axis.chars().chain(std::iter::repeat(' '));
+        let mut next = || tag.next().unwrap_or(' ');
+        VariationCoordinate {
+            axis: FourByteTag::from_chars(next(), next(), next(), next()),
+            value: value.value,
+        }
+    }
+}
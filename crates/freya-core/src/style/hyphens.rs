@@ -0,0 +1,121 @@
+/// How words are hyphenated when lines break inside of them, like `hyphens` in CSS. Defaults to
+/// [`Hyphens::Manual`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Hyphens {
+    /// Words are never hyphenated, soft hyphens (`U+00AD`) in the text are ignored.
+    None,
+    /// Words are only hyphenated at the soft hyphens (`U+00AD`) of the text. This is the default.
+    #[default]
+    Manual,
+    /// Words can also be hyphenated where the hyphenation patterns of their language allow it
+    /// when they do not fit.
+    ///
+    /// Like in CSS, this needs the [locale](crate::prelude::TextStyleExt::locale) of the text to
+    /// be set. Languages without patterns, or builds without the `hyphenation` feature, behave
+    /// like [`Hyphens::Manual`].
+    Auto,
+}
+
+impl Hyphens {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::Manual => "manual".to_string(),
+            Self::Auto => "auto".to_string(),
+        }
+    }
+
+    /// Byte indexes where `word` can be hyphenated in the language of `locale`, a BCP 47 tag.
+    pub(crate) fn opportunities(word: &str, locale: &str) -> Vec<usize> {
+        #[cfg(feature = "hyphenation")]
+        {
+            patterns::opportunities(word, locale)
+        }
+        #[cfg(not(feature = "hyphenation"))]
+        {
+            let _ = (word, locale);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(feature = "hyphenation")]
+mod patterns {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    use hyphenation::{
+        Hyphenator,
+        Language,
+        Load,
+        Standard,
+    };
+    use rustc_hash::FxHashMap;
+
+    thread_local! {
+        /// The patterns are only loaded once they are needed, as it takes a while.
+        static DICTIONARIES: RefCell<FxHashMap<Language, Option<Rc<Standard>>>> =
+            RefCell::default();
+    }
+
+    pub(super) fn opportunities(word: &str, locale: &str) -> Vec<usize> {
+        let Some(language) = language(locale) else {
+            return Vec::new();
+        };
+        let dictionary = DICTIONARIES.with_borrow_mut(|dictionaries| {
+            dictionaries
+                .entry(language)
+                .or_insert_with(|| Standard::from_embedded(language).ok().map(Rc::new))
+                .clone()
+        });
+        let Some(dictionary) = dictionary else {
+            return Vec::new();
+        };
+
+        // The patterns are lowercase, the indexes only carry over when the lengths match
+        let lowercase = word.to_lowercase();
+        if lowercase.len() == word.len() {
+            dictionary.hyphenate(&lowercase).breaks
+        } else {
+            dictionary.hyphenate(word).breaks
+        }
+    }
+
+    /// The language of the patterns for a BCP 47 tag, such as `"de"` or `"en-GB"`.
+    fn language(locale: &str) -> Option<Language> {
+        let locale = locale.to_ascii_lowercase().replace('_', "-");
+        let mut subtags = locale.split('-');
+        let language = subtags.next()?;
+        let region = subtags.find(|subtag| subtag.len() == 2);
+
+        Some(match (language, region) {
+            ("en", Some("gb")) => Language::EnglishGB,
+            ("en", _) => Language::EnglishUS,
+            ("de", Some("ch")) => Language::GermanSwiss,
+            ("de", _) => Language::German1996,
+            ("fr", _) => Language::French,
+            ("es", _) => Language::Spanish,
+            ("it", _) => Language::Italian,
+            ("pt", _) => Language::Portuguese,
+            ("ca", _) => Language::Catalan,
+            ("nl", _) => Language::Dutch,
+            ("da", _) => Language::Danish,
+            ("sv", _) => Language::Swedish,
+            ("nb" | "no", _) => Language::NorwegianBokmal,
+            ("nn", _) => Language::NorwegianNynorsk,
+            ("fi", _) => Language::Finnish,
+            ("pl", _) => Language::Polish,
+            ("cs", _) => Language::Czech,
+            ("sk", _) => Language::Slovak,
+            ("hu", _) => Language::Hungarian,
+            ("ru", _) => Language::Russian,
+            ("uk", _) => Language::Ukrainian,
+            ("el", _) => Language::GreekMono,
+            ("tr", _) => Language::Turkish,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use freya_engine::prelude::PlaceholderAlignment;
+
+/// How an inline child of a [paragraph](crate::elements::paragraph) is aligned with the line of text it sits in.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub enum InlineAlignment {
+    /// Center the child on the line. This is the default.
+    #[default]
+    Middle,
+    /// Line up a baseline of the child, at the given distance in logical pixels from its top,
+    /// with the text baseline. Useful for children that contain text themselves, such as badges.
+    Baseline(f32),
+    /// Place the bottom of the child on the text baseline, like an image in a line of text.
+    AboveBaseline,
+    /// Place the top of the child on the text baseline.
+    BelowBaseline,
+    /// Align the top of the child with the top of the line.
+    Top,
+    /// Align the bottom of the child with the bottom of the line.
+    Bottom,
+}
+
+impl InlineAlignment {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Middle => "middle".to_string(),
+            Self::Baseline(offset) => format!("baseline({offset})"),
+            Self::AboveBaseline => "above-baseline".to_string(),
+            Self::BelowBaseline => "below-baseline".to_string(),
+            Self::Top => "top".to_string(),
+            Self::Bottom => "bottom".to_string(),
+        }
+    }
+}
+
+impl From<InlineAlignment> for PlaceholderAlignment {
+    fn from(value: InlineAlignment) -> Self {
+        match value {
+            InlineAlignment::Middle => PlaceholderAlignment::Middle,
+            InlineAlignment::Baseline(_) => PlaceholderAlignment::Baseline,
+            InlineAlignment::AboveBaseline => PlaceholderAlignment::AboveBaseline,
+            InlineAlignment::BelowBaseline => PlaceholderAlignment::BelowBaseline,
+            InlineAlignment::Top => PlaceholderAlignment::Top,
+            InlineAlignment::Bottom => PlaceholderAlignment::Bottom,
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::Rc,
+};
+
+use freya_engine::prelude::{
+    ImageInfo,
+    Paint,
+    SamplingOptions,
+    Shader,
+    SkMatrix,
+    TileMode,
+    raster_n32_premul,
+    shaders,
+};
+use torin::prelude::{
+    Area,
+    CursorPoint,
+};
+
+use crate::{
+    elements::image::ImageHandle,
+    style::{
+        fill::Fill,
+        gradient::{
+            ConicGradient,
+            LinearGradient,
+            RadialGradient,
+        },
+        shader::ShaderFill,
+    },
+};
+
+/// Alpha mask of an element and its descendants: they are only visible where the mask is
+/// opaque, and fade out where it turns transparent.
+///
+/// ```
+/// # use freya::prelude::*;
+/// // Fade out the bottom of a list
+/// let mask: Mask = LinearGradient::new()
+///     .stop((Color::BLACK, 80.0))
+///     .stop((Color::TRANSPARENT, 100.0))
+///     .into();
+/// ```
+#[derive(Clone, PartialEq)]
+pub enum Mask {
+    /// The alpha of a fill, such as a gradient, spanning the element.
+    Fill(Fill),
+    /// The alpha of an image, stretched over the element.
+    Image(ImageHandle),
+}
+
+impl fmt::Debug for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fill(fill) => f.debug_tuple("Fill").field(fill).finish(),
+            Self::Image(image_handle) => f
+                .debug_tuple("Image")
+                .field(&image_handle.image.unique_id())
+                .finish(),
+        }
+    }
+}
+
+impl Mask {
+    /// Shader of the mask for an element laid out in `area`.
+    pub fn shader(&self, area: Area) -> Option<Shader> {
+        match self {
+            Self::Fill(Fill::Color(color)) => Some(shaders::color(*color)),
+            Self::Fill(Fill::LinearGradient(gradient)) => gradient.prepare_shader(area),
+            Self::Fill(Fill::RadialGradient(gradient)) => gradient.prepare_shader(area),
+            Self::Fill(Fill::ConicGradient(gradient)) => gradient.prepare_shader(area),
+            Self::Fill(Fill::Shader(shader)) => shader.prepare_shader(area),
+            Self::Image(image_handle) => {
+                let image = &image_handle.image;
+                let mut matrix = SkMatrix::translate((area.min_x(), area.min_y()));
+                matrix.pre_scale(
+                    (
+                        area.width() / image.width() as f32,
+                        area.height() / image.height() as f32,
+                    ),
+                    None,
+                );
+                image.to_shader(
+                    (TileMode::Clamp, TileMode::Clamp),
+                    SamplingOptions::default(),
+                    &matrix,
+                )
+            }
+        }
+    }
+
+    /// Whether the mask is not fully transparent at the given point.
+    ///
+    /// The alpha of the mask is rasterized once per area and reused by later hit tests.
+    pub fn is_visible_at(&self, area: Area, point: CursorPoint) -> bool {
+        let cached = RASTERIZED_MASKS.with_borrow_mut(|masks| {
+            let index = masks
+                .iter()
+                .position(|rasterized| rasterized.area == area && rasterized.mask == *self)?;
+            // Keep the most recently used masks at the end
+            let rasterized = masks.remove(index);
+            let alpha = rasterized.alpha.clone();
+            masks.push(rasterized);
+            Some(alpha)
+        });
+        let alpha = match cached {
+            Some(alpha) => alpha,
+            None => {
+                let Some(alpha) = self.rasterize_alpha(area) else {
+                    return true;
+                };
+                let alpha: Rc<[u8]> = alpha.into();
+                RASTERIZED_MASKS.with_borrow_mut(|masks| {
+                    if masks.len() >= RASTERIZED_MASKS_CAPACITY {
+                        masks.remove(0);
+                    }
+                    masks.push(RasterizedMask {
+                        mask: self.clone(),
+                        area,
+                        alpha: alpha.clone(),
+                    });
+                });
+                alpha
+            }
+        };
+
+        let width = area.width().ceil() as usize;
+        let height = area.height().ceil() as usize;
+        if point.x < 0. || point.y < 0. {
+            return false;
+        }
+        let (x, y) = (point.x as usize, point.y as usize);
+        if x >= width || y >= height {
+            return false;
+        }
+        alpha.get(y * width + x).is_some_and(|alpha| *alpha > 0)
+    }
+
+    /// Alpha of the mask for every pixel of `area`, row by row. `None` if it could not be
+    /// rasterized.
+    fn rasterize_alpha(&self, area: Area) -> Option<Vec<u8>> {
+        let width = area.width().ceil() as i32;
+        let height = area.height().ceil() as i32;
+        if width <= 0 || height <= 0 {
+            return Some(Vec::new());
+        }
+        // Without a shader nothing is visible
+        let Some(shader) = self.shader(area) else {
+            return Some(Vec::new());
+        };
+        let mut surface = raster_n32_premul((width, height))?;
+
+        let mut paint = Paint::default();
+        paint.set_shader(shader);
+        surface.canvas().draw_paint(&paint);
+
+        let mut alpha = vec![0u8; (width * height) as usize];
+        surface
+            .read_pixels(
+                &ImageInfo::new_a8((width, height)),
+                &mut alpha,
+                width as usize,
+                (0, 0),
+            )
+            .then_some(alpha)
+    }
+}
+
+/// Maximum amount of masks kept rasterized for hit testing.
+const RASTERIZED_MASKS_CAPACITY: usize = 8;
+
+struct RasterizedMask {
+    mask: Mask,
+    area: Area,
+    alpha: Rc<[u8]>,
+}
+
+thread_local! {
+    /// Masks rasterized for hit testing, the most recently used last.
+    static RASTERIZED_MASKS: RefCell<Vec<RasterizedMask>> = const { RefCell::new(Vec::new()) };
+}
+
+impl From<Fill> for Mask {
+    fn from(fill: Fill) -> Self {
+        Self::Fill(fill)
+    }
+}
+
+impl From<LinearGradient> for Mask {
+    fn from(gradient: LinearGradient) -> Self {
+        Self::Fill(gradient.into())
+    }
+}
+
+impl From<RadialGradient> for Mask {
+    fn from(gradient: RadialGradient) -> Self {
+        Self::Fill(gradient.into())
+    }
+}
+
+impl From<ConicGradient> for Mask {
+    fn from(gradient: ConicGradient) -> Self {
+        Self::Fill(gradient.into())
+    }
+}
+
+impl From<ShaderFill> for Mask {
+    fn from(shader: ShaderFill) -> Self {
+        Self::Fill(shader.into())
+    }
+}
+
+impl From<ImageHandle> for Mask {
+    fn from(image_handle: ImageHandle) -> Self {
+        Self::Image(image_handle)
+    }
+}
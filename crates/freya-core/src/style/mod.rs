@@ -1,14 +1,22 @@
+pub mod blend_mode;
 pub mod border;
+pub mod clip_path;
 pub mod color;
 pub mod corner_radius;
 pub mod cursor;
 pub mod default_fonts;
 pub mod fill;
+pub mod filter;
+pub mod font_feature;
 pub mod font_size;
 pub mod font_slant;
+pub mod font_variation;
 pub mod font_weight;
 pub mod font_width;
 pub mod gradient;
+pub mod hyphens;
+pub mod inline_alignment;
+pub mod mask;
 pub mod scale;
 pub mod shader;
 pub mod shadow;
@@ -17,5 +25,10 @@ pub mod text_decoration;
 pub mod text_height;
 pub mod text_overflow;
 pub mod text_shadow;
+pub mod text_spacing;
+pub mod text_stroke;
+pub mod transform;
 pub mod transform_origin;
 pub mod vertical_align;
+pub mod word_break;
+pub mod writing_mode;
@@ -1,4 +1,9 @@
-use freya_engine::prelude::SkTextDecoration;
+use std::hash::Hash;
+
+use freya_engine::prelude::{
+    SkTextDecoration,
+    SkTextDecorationStyle,
+};
 
 /// A line drawn through, under or over text.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -36,3 +41,76 @@ impl From<TextDecoration> for SkTextDecoration {
         }
     }
 }
+
+/// How the line of a [`TextDecoration`] is drawn.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum TextDecorationStyle {
+    /// A single straight line. This is the default.
+    #[default]
+    Solid,
+    /// Two parallel lines.
+    Double,
+    /// A line of dots.
+    Dotted,
+    /// A line of dashes.
+    Dashed,
+    /// A wavy line, like the squiggles of spell checkers.
+    Wavy,
+}
+
+impl TextDecorationStyle {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Solid => "solid".to_string(),
+            Self::Double => "double".to_string(),
+            Self::Dotted => "dotted".to_string(),
+            Self::Dashed => "dashed".to_string(),
+            Self::Wavy => "wavy".to_string(),
+        }
+    }
+}
+
+impl From<TextDecorationStyle> for SkTextDecorationStyle {
+    fn from(value: TextDecorationStyle) -> Self {
+        match value {
+            TextDecorationStyle::Solid => SkTextDecorationStyle::Solid,
+            TextDecorationStyle::Double => SkTextDecorationStyle::Double,
+            TextDecorationStyle::Dotted => SkTextDecorationStyle::Dotted,
+            TextDecorationStyle::Dashed => SkTextDecorationStyle::Dashed,
+            TextDecorationStyle::Wavy => SkTextDecorationStyle::Wavy,
+        }
+    }
+}
+
+/// Thickness of the line of a [`TextDecoration`], as a multiplier of the thickness the font
+/// defines. Defaults to `1`.
+///
+/// Implements `From<f32>`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TextDecorationThickness(f32);
+
+impl Default for TextDecorationThickness {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl Hash for TextDecorationThickness {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<f32> for TextDecorationThickness {
+    fn from(value: f32) -> Self {
+        TextDecorationThickness(value)
+    }
+}
+
+impl From<TextDecorationThickness> for f32 {
+    fn from(value: TextDecorationThickness) -> Self {
+        value.0
+    }
+}
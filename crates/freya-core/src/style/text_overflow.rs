@@ -9,22 +9,49 @@ pub enum TextOverflow {
     Ellipsis,
     /// Replace the cut-off text with a custom string.
     Custom(String),
+    /// Keep the end of the text and replace its start with an ellipsis (`…file.rs`).
+    ///
+    /// The text is laid out in a single line.
+    StartEllipsis,
+    /// Keep both ends of the text and replace its middle with an ellipsis (`/home/…/file.rs`).
+    ///
+    /// The text is laid out in a single line.
+    MiddleEllipsis,
+    /// Cut the text off at the edge, fading out the end of the text that is wider than its bounds.
+    Fade,
 }
 
 impl TextOverflow {
+    /// The string that replaces the cut-off text, if any.
     pub fn get_ellipsis(&self) -> Option<&str> {
         match self {
-            Self::Clip => None,
-            Self::Ellipsis => Some("…"),
+            Self::Clip | Self::Fade => None,
+            Self::Ellipsis | Self::StartEllipsis | Self::MiddleEllipsis => Some("…"),
             Self::Custom(custom) => Some(custom),
         }
     }
 
+    /// The ellipsis Skia appends to the end of the cut-off text, if any.
+    pub(crate) fn end_ellipsis(&self) -> Option<&str> {
+        match self {
+            Self::StartEllipsis | Self::MiddleEllipsis => None,
+            _ => self.get_ellipsis(),
+        }
+    }
+
+    /// Whether the text is cut off before its end, see [TextOverflow::StartEllipsis] and [TextOverflow::MiddleEllipsis].
+    pub(crate) fn truncates_inside(&self) -> bool {
+        matches!(self, Self::StartEllipsis | Self::MiddleEllipsis)
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             TextOverflow::Clip => "clip".to_string(),
             TextOverflow::Ellipsis => "ellipsis".to_string(),
             TextOverflow::Custom(text_overflow) => text_overflow.to_string(),
+            TextOverflow::StartEllipsis => "start-ellipsis".to_string(),
+            TextOverflow::MiddleEllipsis => "middle-ellipsis".to_string(),
+            TextOverflow::Fade => "fade".to_string(),
         }
     }
 }
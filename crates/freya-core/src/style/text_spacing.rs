@@ -0,0 +1,33 @@
+use std::hash::Hash;
+
+/// Extra space added between letters or words, in logical pixels. Negative values tighten the
+/// text. Defaults to `0`.
+///
+/// Implements `From<f32>` and `From<i32>`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct TextSpacing(f32);
+
+impl Hash for TextSpacing {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<f32> for TextSpacing {
+    fn from(value: f32) -> Self {
+        TextSpacing(value)
+    }
+}
+
+impl From<i32> for TextSpacing {
+    fn from(value: i32) -> Self {
+        TextSpacing(value as f32)
+    }
+}
+
+impl From<TextSpacing> for f32 {
+    fn from(value: TextSpacing) -> Self {
+        value.0
+    }
+}
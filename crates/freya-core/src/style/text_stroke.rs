@@ -0,0 +1,45 @@
+use std::hash::Hash;
+
+use crate::style::color::Color;
+
+/// An outline drawn along the glyphs of text, with a width in logical pixels and a [`Color`].
+///
+/// The glyphs are outlined instead of filled, so stroked text is hollow:
+///
+/// ```
+/// # use freya::prelude::*;
+/// let stroke = TextStroke::new(2.0, Color::BLACK);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextStroke {
+    pub width: f32,
+    pub color: Color,
+}
+
+impl Hash for TextStroke {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.width.to_bits().hash(state);
+        self.color.hash(state);
+    }
+}
+
+impl TextStroke {
+    /// Create a [`TextStroke`] with the given width and [`Color`].
+    pub fn new(width: f32, color: impl Into<Color>) -> Self {
+        Self {
+            width,
+            color: color.into(),
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        format!("{} {}", self.width, self.color.pretty())
+    }
+}
+
+impl<C: Into<Color>> From<(f32, C)> for TextStroke {
+    fn from((width, color): (f32, C)) -> Self {
+        Self::new(width, color)
+    }
+}
@@ -0,0 +1,80 @@
+use freya_engine::prelude::SkMatrix;
+
+use crate::style::scale::Scale;
+
+/// A 2D transformation of an element, applied around its
+/// [`TransformOrigin`](crate::style::transform_origin::TransformOrigin) without affecting the
+/// layout.
+///
+/// Multiple transforms are composed in order, so the first one wraps the rest:
+///
+/// ```
+/// # use freya::prelude::*;
+/// let transforms = [
+///     Transform::translate(20., 0.),
+///     Transform::rotate(45.),
+///     Transform::skew(10., 0.),
+/// ];
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform {
+    /// Move by the given logical pixels on each axis.
+    Translate { x: f32, y: f32 },
+    /// Scale on each axis, where `1.0` is the original size.
+    Scale(Scale),
+    /// Rotate by the given angle in degrees.
+    Rotate(f32),
+    /// Skew by the given angles in degrees on each axis.
+    Skew { x: f32, y: f32 },
+    /// Affine matrix as `[a, b, c, d, e, f]`, mapping `(x, y)` to
+    /// `(a * x + c * y + e, b * x + d * y + f)`. The translation is in logical pixels.
+    Matrix([f32; 6]),
+}
+
+impl Transform {
+    pub fn translate(x: f32, y: f32) -> Self {
+        Self::Translate { x, y }
+    }
+
+    pub fn scale(scale: impl Into<Scale>) -> Self {
+        Self::Scale(scale.into())
+    }
+
+    pub fn rotate(degrees: f32) -> Self {
+        Self::Rotate(degrees)
+    }
+
+    pub fn skew(x_degrees: f32, y_degrees: f32) -> Self {
+        Self::Skew {
+            x: x_degrees,
+            y: y_degrees,
+        }
+    }
+
+    pub fn matrix(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self::Matrix([a, b, c, d, e, f])
+    }
+
+    /// Matrix of this transform, with the translations in physical pixels.
+    pub fn to_matrix(&self, scale_factor: f32) -> SkMatrix {
+        match *self {
+            Self::Translate { x, y } => SkMatrix::translate((x * scale_factor, y * scale_factor)),
+            Self::Scale(scale) => SkMatrix::scale((scale.x, scale.y)),
+            Self::Rotate(degrees) => SkMatrix::rotate_deg(degrees),
+            Self::Skew { x, y } => SkMatrix::skew((x.to_radians().tan(), y.to_radians().tan())),
+            Self::Matrix([a, b, c, d, e, f]) => {
+                SkMatrix::new_all(a, c, e * scale_factor, b, d, f * scale_factor, 0., 0., 1.)
+            }
+        }
+    }
+
+    /// Compose a list of transforms into a single matrix.
+    pub fn compose(transforms: &[Transform], scale_factor: f32) -> SkMatrix {
+        let mut matrix = SkMatrix::new_identity();
+        for transform in transforms {
+            matrix.pre_concat(&transform.to_matrix(scale_factor));
+        }
+        matrix
+    }
+}
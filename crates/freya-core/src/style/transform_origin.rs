@@ -24,7 +24,8 @@ impl OriginValue {
     }
 }
 
-/// Reference point that the scale and rotation effects of an element pivot around.
+/// Reference point that the scale and rotation effects and the transforms of an element pivot
+/// around.
 ///
 /// Defaults to the element's center.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
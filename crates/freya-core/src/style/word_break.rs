@@ -0,0 +1,67 @@
+/// Where lines of text can break, like `word-break` in CSS. Defaults to [`WordBreak::Normal`].
+///
+/// The break opportunities are added as invisible characters when building the paragraph, which
+/// are left out of the indexes of cursors, highlights and
+/// [`ParagraphHolder`](crate::prelude::ParagraphHolder) queries. Text in joining scripts, like
+/// Arabic, keeps breaking between words so its letters stay joined.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum WordBreak {
+    /// Lines break between words. This is the default.
+    #[default]
+    Normal,
+    /// Lines can also break between any two letters, so long words and URLs never overflow.
+    BreakAll,
+    /// Lines never break between letters, not even in Chinese, Japanese or Korean text.
+    KeepAll,
+    /// Lines can break between any two characters, including punctuation.
+    Anywhere,
+}
+
+impl WordBreak {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::BreakAll => "break-all".to_string(),
+            Self::KeepAll => "keep-all".to_string(),
+            Self::Anywhere => "anywhere".to_string(),
+        }
+    }
+
+    /// The invisible character to place between two graphemes, if any.
+    pub(crate) fn separator(&self, previous: &str, next: &str) -> Option<char> {
+        let is_letter = |grapheme: &str| grapheme.starts_with(char::is_alphanumeric);
+        let is_space = |grapheme: &str| grapheme.starts_with(char::is_whitespace);
+        // An invisible character in between would break the shaping of the letters
+        if is_joining(previous) || is_joining(next) {
+            return None;
+        }
+        match self {
+            Self::Normal => None,
+            // Zero width space
+            Self::BreakAll if is_letter(previous) && is_letter(next) => Some('\u{200B}'),
+            Self::Anywhere if !is_space(previous) && !is_space(next) => Some('\u{200B}'),
+            // Word joiner
+            Self::KeepAll if is_letter(previous) && is_letter(next) => Some('\u{2060}'),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the grapheme is written in a script whose letters join, like Arabic.
+fn is_joining(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(|character| {
+        matches!(
+            character,
+            // Arabic, Syriac, Thaana, N'Ko, Samaritan, Mandaic and their supplements
+            '\u{0600}'..='\u{08FF}'
+                // Mongolian
+                | '\u{1800}'..='\u{18AF}'
+                // Arabic presentation forms
+                | '\u{FB50}'..='\u{FDFF}'
+                | '\u{FE70}'..='\u{FEFF}'
+                // Zero width non-joiner and joiner
+                | '\u{200C}'..='\u{200D}'
+        )
+    })
+}
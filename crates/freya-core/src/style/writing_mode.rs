@@ -0,0 +1,26 @@
+/// Direction in which the lines of a [paragraph](crate::elements::paragraph) flow.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum WritingMode {
+    /// Horizontal lines, stacked from top to bottom. This is the default.
+    #[default]
+    HorizontalTb,
+    /// Vertical lines, stacked from right to left, as in traditional CJK text.
+    VerticalRl,
+    /// Vertical lines, stacked from left to right, as in Mongolian text.
+    VerticalLr,
+}
+
+impl WritingMode {
+    pub fn is_vertical(&self) -> bool {
+        !matches!(self, Self::HorizontalTb)
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::HorizontalTb => "horizontal-tb".to_string(),
+            Self::VerticalRl => "vertical-rl".to_string(),
+            Self::VerticalLr => "vertical-lr".to_string(),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    cmp::Ordering,
+    fmt::Debug,
+    rc::Rc,
+};
+
+use freya_engine::prelude::*;
+use torin::prelude::{
+    Area,
+    CursorPoint,
+    LayoutNode,
+    Point2D,
+};
+
+use crate::{
+    data::TextStyleState,
+    element::ElementExt,
+    elements::{
+        label::LabelElement,
+        paragraph::{
+            ParagraphElement,
+            ParagraphHolderInner,
+            TextOffsets,
+        },
+    },
+    node_id::NodeId,
+    style::color::Color,
+};
+
+/// A position in the text of a paragraph or label inside a [TextSelection].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextSelectionPosition {
+    pub node_id: NodeId,
+    /// In UTF-16 code units, like Skia indexes the text.
+    pub offset: usize,
+}
+
+/// A laid out paragraph or label inside a [TextSelection].
+pub(crate) struct SelectableText {
+    node_id: NodeId,
+    area: Area,
+    origin: Point2D,
+    paragraph: Rc<SkParagraph>,
+    scale_factor: f64,
+    text: String,
+    text_offsets: TextOffsets,
+}
+
+impl SelectableText {
+    /// The selectable text of a paragraph or label, once laid out.
+    pub(crate) fn new(
+        node_id: NodeId,
+        element: &dyn ElementExt,
+        layout_node: &LayoutNode,
+        text_style_state: &TextStyleState,
+        scale_factor: f64,
+    ) -> Option<Self> {
+        let element = element as &dyn Any;
+        let visible_area = layout_node.visible_area();
+
+        if let Some(label) = element.downcast_ref::<LabelElement>() {
+            let paragraph = layout_node.data.clone()?.downcast::<SkParagraph>().ok()?;
+            return Some(Self {
+                node_id,
+                area: visible_area,
+                origin: visible_area.origin,
+                paragraph,
+                scale_factor,
+                text: label.text.to_string(),
+                text_offsets: label.text_offsets(text_style_state),
+            });
+        }
+
+        let paragraph = element.downcast_ref::<ParagraphElement>()?;
+        // Vertical text is not laid out by the Skia paragraph
+        if paragraph.writing_mode.is_vertical() {
+            return None;
+        }
+        let ParagraphHolderInner {
+            paragraph: sk_paragraph,
+            text_offsets,
+            ..
+        } = paragraph.sk_paragraph.0.borrow().clone()?;
+        let vertical_offset = paragraph.vertical_offset(&visible_area, sk_paragraph.height());
+        Some(Self {
+            node_id,
+            area: visible_area,
+            origin: Point2D::new(visible_area.min_x(), visible_area.min_y() + vertical_offset),
+            paragraph: sk_paragraph,
+            scale_factor,
+            text: paragraph.text(),
+            text_offsets,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.text.encode_utf16().count()
+    }
+}
+
+/// Selection of text spanning all the paragraphs and labels inside an element, in document order.
+///
+/// Set it with [`text_selection`](crate::prelude::Rect::text_selection) on a rect, the texts
+/// inside of it will paint the highlights of the selection. The texts are collected from the
+/// tree every time the layout changes, so texts that are clipped or scrolled out can be selected too.
+#[derive(Clone)]
+pub struct TextSelection {
+    texts: Rc<RefCell<Vec<SelectableText>>>,
+    pub anchor: Option<TextSelectionPosition>,
+    pub focus: Option<TextSelectionPosition>,
+    pub highlight_color: Color,
+}
+
+impl Default for TextSelection {
+    fn default() -> Self {
+        Self {
+            texts: Rc::default(),
+            anchor: None,
+            focus: None,
+            highlight_color: Color::from_rgb(87, 108, 188),
+        }
+    }
+}
+
+impl Debug for TextSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextSelection")
+            .field("anchor", &self.anchor)
+            .field("focus", &self.focus)
+            .field("highlight_color", &self.highlight_color)
+            .finish()
+    }
+}
+
+impl PartialEq for TextSelection {
+    fn eq(&self, other: &Self) -> bool {
+        self.shares_texts(other)
+            && self.anchor == other.anchor
+            && self.focus == other.focus
+            && self.highlight_color == other.highlight_color
+    }
+}
+
+impl TextSelection {
+    /// Start a new selection at the given position.
+    pub fn start(&mut self, position: TextSelectionPosition) {
+        self.anchor = Some(position);
+        self.focus = Some(position);
+    }
+
+    /// Move the end of the selection to the given position.
+    pub fn extend(&mut self, position: TextSelectionPosition) {
+        if self.anchor.is_some() {
+            self.focus = Some(position);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.anchor = None;
+        self.focus = None;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.focus
+    }
+
+    pub(crate) fn shares_texts(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.texts, &other.texts)
+    }
+
+    /// Replace the texts of the selection, in document order.
+    pub(crate) fn set_texts(&self, texts: Vec<SelectableText>) {
+        *self.texts.borrow_mut() = texts;
+    }
+
+    /// The position in the text closest to the given global location.
+    pub fn position_at(&self, location: CursorPoint) -> Option<TextSelectionPosition> {
+        let texts = self.texts.borrow();
+        let (text, point) = texts
+            .iter()
+            .map(|text| {
+                let point = (location * text.scale_factor).cast::<f32>();
+                (text, point)
+            })
+            // Prefer the text in the same line, and then the closest one horizontally
+            .min_by(|(a, a_point), (b, b_point)| {
+                let distance = |text: &SelectableText, point: &Point2D| {
+                    let dy = (text.area.min_y() - point.y).max(point.y - text.area.max_y());
+                    let dx = (text.area.min_x() - point.x).max(point.x - text.area.max_x());
+                    (dy.max(0.), dx.max(0.))
+                };
+                distance(a, a_point)
+                    .partial_cmp(&distance(b, b_point))
+                    .unwrap_or(Ordering::Equal)
+            })?;
+
+        let position = text
+            .paragraph
+            .get_glyph_position_at_coordinate((point.x - text.origin.x, point.y - text.origin.y))
+            .position;
+
+        Some(TextSelectionPosition {
+            node_id: text.node_id,
+            offset: text
+                .text_offsets
+                .from_layout(position.max(0) as usize)
+                .min(text.len()),
+        })
+    }
+
+    /// The start and end of the selection as indexes of the texts, in document order.
+    fn ordered(&self, texts: &[SelectableText]) -> Option<((usize, usize), (usize, usize))> {
+        let (anchor, focus) = self.anchor.zip(self.focus)?;
+        let index_of = |position: TextSelectionPosition| {
+            let index = texts
+                .iter()
+                .position(|text| text.node_id == position.node_id)?;
+            Some((index, position.offset))
+        };
+        let (anchor, focus) = (index_of(anchor)?, index_of(focus)?);
+        Some((anchor.min(focus), anchor.max(focus)))
+    }
+
+    /// The selected range of the text with the given index.
+    fn range_at(&self, texts: &[SelectableText], index: usize) -> Option<(usize, usize)> {
+        let ((start, start_offset), (end, end_offset)) = self.ordered(texts)?;
+        if index < start || index > end {
+            return None;
+        }
+
+        let from = if index == start { start_offset } else { 0 };
+        let to = if index == end {
+            end_offset
+        } else {
+            texts[index].len()
+        };
+        (from < to).then_some((from, to))
+    }
+
+    /// The selected range of the text of the given node.
+    pub fn range_of(&self, node_id: NodeId) -> Option<(usize, usize)> {
+        let texts = self.texts.borrow();
+        let index = texts.iter().position(|text| text.node_id == node_id)?;
+        self.range_at(&texts, index)
+    }
+
+    /// The selected text, with a line break between the texts of different elements.
+    pub fn text(&self) -> String {
+        let texts = self.texts.borrow();
+        texts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, text)| {
+                let (from, to) = self.range_at(&texts, index)?;
+                let utf16 = text.text.encode_utf16().collect::<Vec<_>>();
+                // Inline elements are not part of the text
+                Some(String::from_utf16_lossy(&utf16[from..to]).replace('\u{FFFC}', ""))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Paint the highlights of the selected range of the text of the given node.
+    pub(crate) fn render(
+        &self,
+        canvas: &Canvas,
+        node_id: NodeId,
+        paragraph: &SkParagraph,
+        origin: Point2D,
+    ) {
+        let texts = self.texts.borrow();
+        let Some(index) = texts.iter().position(|text| text.node_id == node_id) else {
+            return;
+        };
+        let Some((from, to)) = self.range_at(&texts, index) else {
+            return;
+        };
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(self.highlight_color);
+
+        for text_box in paragraph.get_rects_for_range(
+            texts[index].text_offsets.to_layout_range(from..to),
+            RectHeightStyle::Tight,
+            RectWidthStyle::Tight,
+        ) {
+            canvas.draw_rect(text_box.rect.with_offset((origin.x, origin.y)), &paint);
+        }
+    }
+}
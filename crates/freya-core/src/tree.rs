@@ -1,6 +1,5 @@
 use std::{
     any::Any,
-    borrow::Cow,
     collections::{
         VecDeque,
         hash_map::Entry,
@@ -13,6 +12,9 @@ use bitflags::bitflags;
 use freya_engine::prelude::{
     FontCollection,
     FontMgr,
+    SkMatrix,
+    SkPoint,
+    SkRect,
 };
 use futures_channel::mpsc::UnboundedSender;
 use itertools::Itertools;
@@ -23,6 +25,7 @@ use rustc_hash::{
 use torin::{
     prelude::{
         Area,
+        CursorPoint,
         LayoutMeasurer,
         LayoutNode,
         PostMeasure,
@@ -36,6 +39,7 @@ use torin::{
 
 use crate::{
     accessibility::groups::AccessibilityGroups,
+    damage::Damage,
     data::{
         AccessibilityState,
         EffectState,
@@ -58,12 +62,14 @@ use crate::{
         name::EventName,
     },
     extended_hashmap::ExtendedHashMap,
+    fonts::FallbackFonts,
     integration::{
         AccessibilityDirtyNodes,
         AccessibilityFocusStrategy,
         AccessibilityGenerator,
         EventsChunk,
     },
+    layer_cache::LayerCache,
     layers::Layers,
     node_id::NodeId,
     runner::{
@@ -73,7 +79,17 @@ use crate::{
         MutationRemove,
         Mutations,
     },
+    style::{
+        border::BorderAlignment,
+        filter::Filter,
+        shadow::ShadowPosition,
+        transform::Transform,
+    },
     text_cache::TextCache,
+    text_selection::{
+        SelectableText,
+        TextSelection,
+    },
     tree_layout_adapter::TreeAdapterFreya,
 };
 
@@ -106,6 +122,18 @@ pub struct Tree {
     pub accessibility_groups: AccessibilityGroups,
     pub accessibility_diff: AccessibilityDirtyNodes,
     pub accessibility_generator: AccessibilityGenerator,
+
+    // Rendering
+    pub layer_cache: LayerCache,
+    pub damage: Damage,
+    damaged_nodes: FxHashSet<NodeId>,
+    scale_factor: f32,
+
+    // Text selections
+    text_selection_roots: FxHashSet<NodeId>,
+    /// The element with the text selection each selectable text belongs to.
+    selectable_texts: FxHashMap<NodeId, NodeId>,
+    text_selections_outdated: bool,
 }
 
 impl Debug for Tree {
@@ -123,6 +151,7 @@ impl Debug for Tree {
             .field("accessibility_state", &self.accessibility_state.capacity())
             .field("text_style_state", &self.text_style_state.capacity())
             .field("text_cache", &self.text_cache)
+            .field("layer_cache", &self.layer_cache.len())
             .finish()
     }
 }
@@ -166,6 +195,7 @@ impl Tree {
         if let Entry::Vacant(e) = self.elements.entry(NodeId::ROOT) {
             e.insert(Rc::new(RectElement::default()));
             self.heights.insert(NodeId::ROOT, 0);
+            self.damaged_nodes.insert(NodeId::ROOT);
             dirty.push((NodeId::ROOT, DiffModifies::all()));
         }
 
@@ -177,10 +207,14 @@ impl Tree {
                     continue;
                 };
                 self.layout.invalidate(parent_id);
+                self.invalidate_cached_layers(parent_id);
                 needs_render = true;
 
                 while let Some(remove) = buff.pop() {
                     let node_id = remove.node_id();
+                    self.damage_node(node_id);
+                    self.damaged_nodes.remove(&node_id);
+                    self.layer_cache.invalidate(&node_id);
                     self.layout.raw_remove(node_id);
 
                     let parent_id = self.parents.remove(&node_id).unwrap();
@@ -237,6 +271,8 @@ impl Tree {
                     self.effect_state.remove(&node_id);
                     self.text_style_state.remove(&node_id);
                     self.text_cache.remove(&node_id);
+                    self.text_selection_roots.remove(&node_id);
+                    self.selectable_texts.remove(&node_id);
                 }
             }
 
@@ -275,7 +311,14 @@ impl Tree {
                     }
                 }
 
+                if element.text_selection().is_some() {
+                    self.text_selection_roots.insert(node_id);
+                    self.text_selections_outdated = true;
+                }
+
                 self.elements.insert(node_id, element);
+                self.damaged_nodes.insert(node_id);
+                self.invalidate_cached_layers(parent_id);
                 dirty.push((node_id, DiffModifies::all()));
             }
 
@@ -292,6 +335,8 @@ impl Tree {
                 diff.insert(DiffModifies::REORDER_LAYOUT);
                 diff.insert(DiffModifies::ACCESSIBILITY);
                 diff.insert(DiffModifies::STYLE);
+                self.damaged_nodes.insert(parent_node_id);
+                self.invalidate_cached_layers(parent_node_id);
                 dirty.push((parent_node_id, diff));
             }
 
@@ -303,7 +348,40 @@ impl Tree {
             {
                 dirty.push((node_id, flags));
 
+                // Damage what the node painted before and after the modification
+                if flags.intersects(RENDER_MODIFIES) {
+                    self.damage_subtree(node_id);
+                    self.damaged_nodes.insert(node_id);
+                    self.invalidate_cached_layers(node_id);
+                }
+
+                // Text styles are inherited by the cached layers down in the tree
+                if flags.contains(DiffModifies::TEXT_STYLE) {
+                    self.invalidate_descendant_cached_layers(node_id);
+                }
+
                 let old_element = self.elements.remove(&node_id).unwrap();
+                element.inherit(&old_element);
+
+                match (element.text_selection(), old_element.text_selection()) {
+                    (Some(text_selection), Some(old_text_selection))
+                        if text_selection.shares_texts(old_text_selection) =>
+                    {
+                        // The highlights are painted by the texts down in the tree
+                        if text_selection != old_text_selection {
+                            self.invalidate_descendant_cached_layers(node_id);
+                        }
+                    }
+                    (Some(_), _) => {
+                        self.text_selection_roots.insert(node_id);
+                        self.text_selections_outdated = true;
+                    }
+                    (None, Some(_)) => {
+                        self.text_selection_roots.remove(&node_id);
+                        self.text_selections_outdated = true;
+                    }
+                    (None, None) => {}
+                }
 
                 if flags.contains(DiffModifies::EVENT_HANDLERS) {
                     // Remove old events
@@ -355,17 +433,7 @@ impl Tree {
                     self.layout.invalidate(node_id);
                 }
 
-                if !needs_render
-                    && (flags.intersects(
-                        DiffModifies::STYLE
-                            | DiffModifies::LAYER
-                            | DiffModifies::EFFECT
-                            | DiffModifies::TEXT_STYLE
-                            | DiffModifies::LAYOUT
-                            | DiffModifies::INNER_LAYOUT
-                            | DiffModifies::REORDER_LAYOUT,
-                    ))
-                {
+                if !needs_render && flags.intersects(RENDER_MODIFIES) {
                     needs_render = true;
                 }
 
@@ -619,13 +687,14 @@ impl Tree {
         font_manager: &FontMgr,
         events_sender: &UnboundedSender<EventsChunk>,
         scale_factor: f64,
-        fallback_fonts: &[Cow<'static, str>],
+        fallback_fonts: &FallbackFonts,
     ) {
         let mut tree_adapter = TreeAdapterFreya {
             elements: &self.elements,
             parents: &self.parents,
             children: &self.children,
             heights: &self.heights,
+            text_style_state: &self.text_style_state,
             scale_factor,
         };
 
@@ -640,6 +709,15 @@ impl Tree {
             text_cache: &mut self.text_cache,
         };
 
+        // Nodes measured again might render differently even if their area stays the same
+        if !self.layer_cache.is_empty() {
+            for node_id in self.layout.dirty.keys().copied().collect::<Vec<_>>() {
+                self.invalidate_cached_layers(node_id);
+            }
+        }
+
+        let layout_changed = !self.layout.dirty.is_empty();
+
         self.layout.find_best_root(&mut tree_adapter);
         self.layout.measure(
             NodeId::ROOT,
@@ -647,11 +725,374 @@ impl Tree {
             &mut Some(layout_adapter),
             &mut tree_adapter,
         );
+        self.scale_factor = scale_factor as f32;
+
+        let changes = self.layout.take_changes();
+        for (node_id, previous_area) in changes.iter() {
+            if let Some(previous_area) = previous_area {
+                match self.painted_area(node_id, *previous_area) {
+                    Some(area) => self.damage.invalidate(area),
+                    None => self.damage.invalidate_all(),
+                }
+            }
+            // Scale effects and transforms are relative to the transformed node, so its
+            // descendants move too
+            if self
+                .effect_state
+                .get(node_id)
+                .is_some_and(|effect| effect.is_transformed())
+            {
+                self.damaged_nodes.insert(*node_id);
+            } else {
+                self.damage_node(*node_id);
+            }
+        }
+        if !self.layer_cache.is_empty() {
+            self.invalidate_moved_cached_layers(&changes);
+        }
+
+        if (layout_changed && !self.text_selection_roots.is_empty())
+            || self.text_selections_outdated
+        {
+            self.collect_selectable_texts(scale_factor);
+        }
+
         events_sender
             .unbounded_send(EventsChunk::Batch(self.events.drain(..).collect()))
             .unwrap();
     }
 
+    /// Collect the laid out texts inside of every element with a [TextSelection], in document
+    /// order. Texts inside of a nested selection belong to that one.
+    fn collect_selectable_texts(&mut self, scale_factor: f64) {
+        self.text_selections_outdated = false;
+        self.selectable_texts.clear();
+
+        for root_id in &self.text_selection_roots {
+            let Some(text_selection) = self
+                .elements
+                .get(root_id)
+                .and_then(|element| element.text_selection())
+            else {
+                continue;
+            };
+
+            let mut texts = Vec::new();
+            let mut buffer = self
+                .children
+                .get(root_id)
+                .map(|children| children.iter().rev().copied().collect::<Vec<_>>())
+                .unwrap_or_default();
+            while let Some(node_id) = buffer.pop() {
+                if self.text_selection_roots.contains(&node_id) {
+                    continue;
+                }
+                if let Some(element) = self.elements.get(&node_id)
+                    && let Some(layout_node) = self.layout.get(&node_id)
+                    && !layout_node.hidden
+                    && let Some(text_style_state) = self.text_style_state.get(&node_id)
+                    && let Some(text) = SelectableText::new(
+                        node_id,
+                        element.as_ref(),
+                        layout_node,
+                        text_style_state,
+                        scale_factor,
+                    )
+                {
+                    self.selectable_texts.insert(node_id, *root_id);
+                    texts.push(text);
+                }
+                if let Some(children) = self.children.get(&node_id) {
+                    buffer.extend(children.iter().rev());
+                }
+            }
+            text_selection.set_texts(texts);
+        }
+    }
+
+    /// The [TextSelection] the given paragraph or label is part of.
+    pub fn text_selection_of(&self, node_id: &NodeId) -> Option<&TextSelection> {
+        let root_id = self.selectable_texts.get(node_id)?;
+        self.elements.get(root_id)?.text_selection()
+    }
+
+    /// Matrix of the rotation effect of a node, around its transform origin.
+    pub fn rotation_matrix(&self, node_id: &NodeId) -> SkMatrix {
+        let layout_node = self.layout.get(node_id).unwrap();
+        let effect = self.effect_state.get(node_id).unwrap();
+        let origin = effect.transform_origin.origin(&layout_node.visible_area());
+        let mut matrix = SkMatrix::new_identity();
+        matrix.set_rotate(
+            effect.rotation.unwrap(),
+            Some(SkPoint {
+                x: origin.x,
+                y: origin.y,
+            }),
+        );
+        matrix
+    }
+
+    /// Matrix of the scale effect and transforms of a node, around its transform origin.
+    pub fn transform_matrix(&self, node_id: &NodeId) -> SkMatrix {
+        let layout_node = self.layout.get(node_id).unwrap();
+        let effect = self.effect_state.get(node_id).unwrap();
+        let origin = effect.transform_origin.origin(&layout_node.visible_area());
+        let mut matrix = SkMatrix::translate((origin.x, origin.y));
+        if let Some(scale) = effect.scale {
+            matrix.pre_scale((scale.x, scale.y), None);
+        }
+        matrix.pre_concat(&Transform::compose(&effect.transform, self.scale_factor));
+        matrix.pre_translate((-origin.x, -origin.y));
+        matrix
+    }
+
+    /// Compose the scale effects and transforms of the given nodes, outermost first.
+    pub fn transforms_matrix(&self, transform_node_ids: &[NodeId]) -> SkMatrix {
+        let mut matrix = SkMatrix::new_identity();
+        for node_id in transform_node_ids {
+            matrix.pre_concat(&self.transform_matrix(node_id));
+        }
+        matrix
+    }
+
+    /// Bounding area of an area transformed with the accumulated scale effects and
+    /// transforms of the given nodes.
+    pub fn transformed_area(&self, area: Area, transform_node_ids: &[NodeId]) -> Area {
+        if transform_node_ids.is_empty() {
+            return area;
+        }
+        let (rect, _) = self
+            .transforms_matrix(transform_node_ids)
+            .map_rect(SkRect::new(
+                area.min_x(),
+                area.min_y(),
+                area.max_x(),
+                area.max_y(),
+            ));
+        Area::new(
+            (rect.x(), rect.y()).into(),
+            (rect.width(), rect.height()).into(),
+        )
+    }
+
+    /// Map a point in the window to the local coordinates of a node, undoing its inherited
+    /// rotations and transforms. `None` if the node is flattened by its transforms.
+    pub fn local_point(&self, node_id: &NodeId, point: CursorPoint) -> Option<CursorPoint> {
+        let Some(effect) = self.effect_state.get(node_id) else {
+            return Some(point);
+        };
+        if effect.rotations.is_empty() && effect.transforms.is_empty() {
+            return Some(point);
+        }
+
+        let mut matrix = SkMatrix::new_identity();
+        for rotation_node_id in effect.rotations.iter() {
+            matrix.pre_concat(&self.rotation_matrix(rotation_node_id));
+        }
+        matrix.pre_concat(&self.transforms_matrix(&effect.transforms));
+
+        let local = matrix.invert()?.map_point((point.x as f32, point.y as f32));
+        Some(CursorPoint::new(local.x as f64, local.y as f64))
+    }
+
+    /// Area painted by a node when laid out in `area`, including its outset shadows, outer
+    /// borders, scale effects and transforms. `None` if it can't be known, like with rotation,
+    /// blur or backdrop effects or with filters painting outside of the element.
+    pub fn painted_area(&self, node_id: &NodeId, area: Area) -> Option<Area> {
+        let element = self.elements.get(node_id)?;
+        let outset = self.paint_outset(element.as_ref());
+        let area = area.inflate(outset, outset);
+
+        match self.effect_state.get(node_id) {
+            Some(effect)
+                if !effect.rotations.is_empty()
+                    || effect.blur.is_some()
+                    || !effect.backdrop_filter.is_empty()
+                    || self.expands_filters(&effect.filters) =>
+            {
+                None
+            }
+            Some(effect) => Some(self.transformed_area(area, &effect.transforms)),
+            None => Some(area),
+        }
+    }
+
+    /// Whether any of the filters of the given nodes paints outside of the original pixels.
+    fn expands_filters(&self, filter_node_ids: &[NodeId]) -> bool {
+        filter_node_ids.iter().any(|node_id| {
+            self.effect_state
+                .get(node_id)
+                .is_some_and(|effect| effect.filter.iter().any(Filter::expands))
+        })
+    }
+
+    /// How far an element paints outside of its area, because of its outset shadows or outer
+    /// borders.
+    pub(crate) fn paint_outset(&self, element: &dyn ElementExt) -> f32 {
+        let style = element.style();
+
+        // Leave room for antialiasing
+        let mut outset = 1f32;
+        for shadow in style.shadows.iter() {
+            if shadow.position == ShadowPosition::Normal {
+                let shadow_outset =
+                    shadow.x.abs().max(shadow.y.abs()) + shadow.spread + shadow.blur;
+                outset = outset.max(shadow_outset * self.scale_factor);
+            }
+        }
+        for border in style.borders.iter() {
+            if border.alignment != BorderAlignment::Inner {
+                let width = border.width;
+                let border_outset = width.top.max(width.right).max(width.bottom).max(width.left);
+                outset = outset.max(border_outset * self.scale_factor);
+            }
+        }
+        outset
+    }
+
+    /// Area currently painted by a laid out node, see [Tree::painted_area].
+    pub fn node_painted_area(&self, node_id: &NodeId) -> Option<Area> {
+        let layout_node = self.layout.get(node_id)?;
+        // Content such as text might overflow the node
+        let area = layout_node
+            .visible_area()
+            .union(&Area::new(layout_node.area.origin, layout_node.inner_sizes));
+        self.painted_area(node_id, area)
+    }
+
+    /// Damage the area currently painted by a node.
+    fn damage_node(&mut self, node_id: NodeId) {
+        if !self.layout.results.contains_key(&node_id) {
+            return;
+        }
+        match self.node_painted_area(&node_id) {
+            Some(area) => self.damage.invalidate(area),
+            None => self.damage.invalidate_all(),
+        }
+    }
+
+    /// Damage the area currently painted by a node and all its descendants.
+    fn damage_subtree(&mut self, node_id: NodeId) {
+        let mut buffer = vec![node_id];
+        while let Some(node_id) = buffer.pop() {
+            if node_id == NodeId::ROOT {
+                self.damage.invalidate_all();
+            }
+            if self.damage.is_full() {
+                return;
+            }
+            self.damage_node(node_id);
+            if let Some(children) = self.children.get(&node_id) {
+                buffer.extend(children);
+            }
+        }
+    }
+
+    /// Take the [Damage] accumulated since the last call.
+    pub fn take_damage(&mut self) -> Damage {
+        let mut visited = FxHashSet::default();
+        for node_id in std::mem::take(&mut self.damaged_nodes) {
+            let mut buffer = vec![node_id];
+            while let Some(node_id) = buffer.pop() {
+                if !visited.insert(node_id) {
+                    continue;
+                }
+                if node_id == NodeId::ROOT {
+                    self.damage.invalidate_all();
+                }
+                if self.damage.is_full() {
+                    return self.damage.take();
+                }
+                self.damage_node(node_id);
+                if let Some(children) = self.children.get(&node_id) {
+                    buffer.extend(children);
+                }
+            }
+        }
+
+        self.damage.take()
+    }
+
+    /// Outermost node whose subtree is drawn as a single picture along with the node of
+    /// `effect_state`, either a cached layer or a node with filters.
+    pub fn layer_root(&self, effect_state: &EffectState) -> Option<NodeId> {
+        let filter_root = effect_state.filters.first().copied();
+        match (effect_state.cached_layer, filter_root) {
+            (Some(cached_layer), Some(filter_root)) => {
+                let height = |node_id| self.heights.get(&node_id).copied().unwrap_or_default();
+                if height(cached_layer) <= height(filter_root) {
+                    Some(cached_layer)
+                } else {
+                    Some(filter_root)
+                }
+            }
+            (cached_layer, filter_root) => cached_layer.or(filter_root),
+        }
+    }
+
+    /// Whether `ancestor` is `node_id` or one of its ancestors.
+    fn is_ancestor(&self, ancestor: NodeId, mut node_id: NodeId) -> bool {
+        loop {
+            if node_id == ancestor {
+                return true;
+            }
+            match self.parents.get(&node_id) {
+                Some(parent_id) => node_id = *parent_id,
+                None => return false,
+            }
+        }
+    }
+
+    /// Invalidate the cached layers of a node and its ancestors.
+    fn invalidate_cached_layers(&mut self, node_id: NodeId) {
+        if self.layer_cache.is_empty() {
+            return;
+        }
+        let mut current = Some(node_id);
+        while let Some(node_id) = current {
+            self.layer_cache.invalidate(&node_id);
+            current = self.parents.get(&node_id).copied();
+        }
+    }
+
+    /// Invalidate the cached layers of a node and its descendants.
+    fn invalidate_descendant_cached_layers(&mut self, node_id: NodeId) {
+        for layer_node_id in self.layer_cache.node_ids() {
+            if self.is_ancestor(node_id, layer_node_id) {
+                self.layer_cache.invalidate(&layer_node_id);
+            }
+        }
+    }
+
+    /// Invalidate the cached layers with descendants whose area changed in the last layout
+    /// measurement. Layers are kept if their whole subtree was moved by the same offset.
+    fn invalidate_moved_cached_layers(&mut self, changes: &FxHashMap<NodeId, Option<Area>>) {
+        let translation = |node_id: &NodeId| match changes.get(node_id) {
+            None => Some((0., 0.)),
+            Some(Some(previous_area)) => {
+                let area = self.layout.get(node_id)?.visible_area();
+                (area.size == previous_area.size)
+                    .then(|| (area.origin - previous_area.origin).to_tuple())
+            }
+            Some(None) => None,
+        };
+
+        let mut invalidated = Vec::new();
+        for layer_node_id in self.layer_cache.node_ids() {
+            let layer_translation = translation(&layer_node_id);
+            let moved = changes.keys().any(|node_id| {
+                self.is_ancestor(layer_node_id, *node_id)
+                    && (layer_translation.is_none() || translation(node_id) != layer_translation)
+            });
+            if moved {
+                invalidated.push(layer_node_id);
+            }
+        }
+        for layer_node_id in invalidated {
+            self.layer_cache.invalidate(&layer_node_id);
+        }
+    }
+
     pub fn print_ascii(&self, node_id: NodeId, prefix: String, last: bool) {
         let height = self.heights.get(&node_id).unwrap();
         let layer = self.layer_state.get(&node_id).unwrap();
@@ -715,6 +1156,15 @@ bitflags! {
     }
 }
 
+/// Modifications that change how a node is painted.
+const RENDER_MODIFIES: DiffModifies = DiffModifies::STYLE
+    .union(DiffModifies::LAYER)
+    .union(DiffModifies::EFFECT)
+    .union(DiffModifies::TEXT_STYLE)
+    .union(DiffModifies::LAYOUT)
+    .union(DiffModifies::INNER_LAYOUT)
+    .union(DiffModifies::REORDER_LAYOUT);
+
 pub struct MutationsApplyResult {
     pub needs_render: bool,
     pub needs_accessibility: bool,
@@ -728,7 +1178,7 @@ pub struct LayoutMeasurerAdapter<'a> {
     text_style_state: &'a FxHashMap<NodeId, TextStyleState>,
     events: &'a mut Vec<EmmitableEvent>,
     scale_factor: f64,
-    fallback_fonts: &'a [Cow<'static, str>],
+    fallback_fonts: &'a FallbackFonts,
     text_cache: &'a mut TextCache,
 }
 
@@ -11,6 +11,7 @@ use torin::{
 };
 
 use crate::{
+    data::TextStyleState,
     element::ElementExt,
     node_id::NodeId,
 };
@@ -20,6 +21,7 @@ pub struct TreeAdapterFreya<'a> {
     pub parents: &'a FxHashMap<NodeId, NodeId>,
     pub children: &'a FxHashMap<NodeId, Vec<NodeId>>,
     pub heights: &'a FxHashMap<NodeId, u16>,
+    pub text_style_state: &'a FxHashMap<NodeId, TextStyleState>,
     pub scale_factor: f64,
 }
 
@@ -40,6 +42,12 @@ impl TreeAdapter<NodeId> for TreeAdapterFreya<'_> {
             let mut layout_node = node.layout().layout.clone();
             // Elements with a post-measure step must relayout when their children change.
             layout_node.depends_on_inner = node.needs_post_measure();
+            // Sticky descendants are clamped to the visible area of scrollable elements.
+            layout_node.scrollable = node.effect().is_some_and(|effect| effect.scrollable);
+            // The layout direction is inherited along with the text style.
+            if let Some(text_style_state) = self.text_style_state.get(node_id) {
+                layout_node.layout_direction = text_style_state.layout_direction;
+            }
             layout_node.scale(self.scale_factor as f32);
             layout_node
         })
@@ -0,0 +1,83 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+fn events_respect_clip_paths() {
+    fn app() -> impl IntoElement {
+        let mut clicks = use_state(|| 0);
+
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background(Color::RED)
+                    .clip_path(ClipPath::circle())
+                    .on_mouse_up(move |_| clicks.set(clicks() + 1)),
+            )
+            .child(label().text(format!("clicks:{}", clicks())))
+    }
+
+    let mut test = launch_test(app);
+
+    // Corner of the rect, outside of the circle
+    test.click_cursor((5.0, 5.0));
+    assert!(
+        test.find(|_, e| Label::try_downcast(e).filter(|l| l.text.as_ref() == "clicks:0"))
+            .is_some()
+    );
+
+    test.click_cursor((50.0, 50.0));
+    assert!(
+        test.find(|_, e| Label::try_downcast(e).filter(|l| l.text.as_ref() == "clicks:1"))
+            .is_some()
+    );
+}
+
+#[test]
+fn events_respect_masks() {
+    fn app() -> impl IntoElement {
+        let mut clicks = use_state(|| 0);
+
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background(Color::RED)
+                    .mask(
+                        LinearGradient::new()
+                            .angle(0.)
+                            .stop((Color::BLACK, 50.0))
+                            .stop((Color::TRANSPARENT, 50.0)),
+                    )
+                    .on_mouse_up(move |_| clicks.set(clicks() + 1)),
+            )
+            .child(label().text(format!("clicks:{}", clicks())))
+    }
+
+    let mut test = launch_test(app);
+
+    // Transparent half of the mask
+    test.click_cursor((50.0, 90.0));
+    assert!(
+        test.find(|_, e| Label::try_downcast(e).filter(|l| l.text.as_ref() == "clicks:0"))
+            .is_some()
+    );
+
+    test.click_cursor((50.0, 10.0));
+    assert!(
+        test.find(|_, e| Label::try_downcast(e).filter(|l| l.text.as_ref() == "clicks:1"))
+            .is_some()
+    );
+
+    // Later hit tests reuse the rasterized mask
+    test.click_cursor((50.0, 90.0));
+    test.click_cursor((50.0, 20.0));
+    assert!(
+        test.find(|_, e| Label::try_downcast(e).filter(|l| l.text.as_ref() == "clicks:2"))
+            .is_some()
+    );
+}
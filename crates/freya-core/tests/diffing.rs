@@ -1333,6 +1333,59 @@ fn effect_cascade_in_new_nodes_with_parent_effects() {
     );
 }
 
+#[test]
+fn effect_cascade_in_modified_text() {
+    fn app() -> Element {
+        let mut toggled = use_state(|| false);
+
+        rect()
+            .blend_mode(BlendMode::Multiply)
+            .on_mouse_up(move |_| toggled.toggle())
+            .child(
+                label()
+                    .text("Hello")
+                    .maybe(toggled(), |label| label.blend_mode(BlendMode::Difference)),
+            )
+            .into()
+    }
+
+    let mut runner = Runner::new(app);
+    let mut tree = Tree::default();
+
+    let mutations = runner.sync_and_update();
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    assert_eq!(
+        tree.effect_state
+            .get(&3u64.into())
+            .unwrap()
+            .blend_modes
+            .as_ref(),
+        &[BlendMode::Multiply]
+    );
+
+    runner.handle_event(
+        2,
+        EventName::MouseUp,
+        EventType::Mouse(MouseEventData::default()),
+        false,
+    );
+    let mutations = runner.sync_and_update();
+    assert!(!mutations.modified.is_empty());
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    assert_eq!(
+        tree.effect_state
+            .get(&3u64.into())
+            .unwrap()
+            .blend_modes
+            .as_ref(),
+        &[BlendMode::Multiply, BlendMode::Difference]
+    );
+}
+
 #[test]
 fn modified_with_removed_sibling() {
     fn app() -> Element {
@@ -2120,3 +2173,34 @@ fn grandchild_addition_under_parent_shifted_by_removal() {
         vec![(3, vec![]), (2, vec![(1, 2, false)])],
     ]);
 }
+
+#[test]
+fn text_decoration_cascade_in_nested_text() {
+    fn app() -> Element {
+        rect()
+            .text_decoration(TextDecoration::Underline)
+            .text_decoration_style(TextDecorationStyle::Wavy)
+            .text_decoration_color(Color::RED)
+            .child(label().text("Typo").text_stroke((1.0, Color::BLACK)))
+            .into()
+    }
+
+    let mut runner = Runner::new(app);
+    let mut tree = Tree::default();
+
+    let mutations = runner.sync_and_update();
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    let text_style = tree.text_style_state.get(&3u64.into()).unwrap();
+    assert_eq!(text_style.text_decoration, TextDecoration::Underline);
+    assert_eq!(text_style.text_decoration_style, TextDecorationStyle::Wavy);
+    assert_eq!(text_style.text_decoration_color, Some(Color::RED));
+    assert_eq!(
+        text_style.text_stroke,
+        Some(TextStroke::new(1.0, Color::BLACK))
+    );
+
+    let text_style = tree.text_style_state.get(&2u64.into()).unwrap();
+    assert_eq!(text_style.text_stroke, None);
+}
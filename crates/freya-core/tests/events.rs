@@ -434,3 +434,163 @@ fn file_drop_with_multiple_files() {
         vec![PathBuf::from("first.txt"), PathBuf::from("second.txt")]
     );
 }
+
+#[test]
+fn gesture_events() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<String>>>();
+        rect()
+            .expanded()
+            .background((255, 255, 255))
+            .on_pinch(move |e: Event<PinchEventData>| {
+                state
+                    .write()
+                    .push(format!("pinch {:?} {}", e.phase, e.scale))
+            })
+            .on_long_press(move |e: Event<LongPressEventData>| {
+                state
+                    .write()
+                    .push(format!("long press {:?}", e.element_location))
+            })
+            .on_swipe(move |e: Event<SwipeEventData>| {
+                state.write().push(format!("swipe {:?}", e.direction))
+            })
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<String>::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Spread two fingers to twice their distance
+    test.press_finger(0, (100., 100.));
+    test.press_finger(1, (200., 100.));
+    test.move_finger(1, (300., 100.));
+    test.release_finger(1, (300., 100.));
+    test.release_finger(0, (100., 100.));
+    assert_eq!(*state.peek(), vec!["pinch Started 2", "pinch Ended 2"]);
+    state.write().clear();
+
+    test.press_touch((50., 50.));
+    test.advance_touch_time(GestureConfig::default().long_press_duration);
+    test.release_touch((50., 50.));
+    assert_eq!(*state.peek(), vec!["long press (50.0, 50.0)"]);
+    state.write().clear();
+
+    test.press_touch((300., 200.));
+    test.move_touch((250., 200.));
+    test.release_touch((200., 200.));
+    assert_eq!(*state.peek(), vec!["swipe Left"]);
+}
+
+#[test]
+fn pointer_capture() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<String>>>();
+        rect()
+            .expanded()
+            .horizontal()
+            .children([
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((0, 0, 0))
+                    .on_pointer_down(move |e: Event<PointerEventData>| {
+                        e.capture_pointer(e.pointer_id());
+                        state.write().push("down".to_string())
+                    })
+                    .on_pointer_move(move |e: Event<PointerEventData>| {
+                        state
+                            .write()
+                            .push(format!("move {:?}", e.element_location()))
+                    })
+                    .on_pointer_press(move |_| state.write().push("press".to_string()))
+                    .on_lost_pointer_capture(move |e: Event<PointerCaptureEventData>| {
+                        state.write().push(format!("lost {:?}", e.pointer_id))
+                    }),
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((20, 20, 20))
+                    .on_pointer_enter(move |_| state.write().push("other enter".to_string()))
+                    .on_pointer_press(move |_| state.write().push("other press".to_string())),
+            ])
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<String>::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    test.press_cursor((50., 50.));
+    test.move_cursor((150., 50.));
+    test.release_cursor((150., 50.));
+    assert_eq!(
+        *state.peek(),
+        vec!["down", "move (150.0, 50.0)", "press", "lost Mouse"]
+    );
+    state.write().clear();
+
+    // Once released, the pointer reaches the other element again
+    test.move_cursor((160., 50.));
+    test.click_cursor((160., 50.));
+    assert_eq!(*state.peek(), vec!["other enter", "other press"]);
+}
+
+#[test]
+fn focus_events() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<String>>>();
+        let first = use_a11y();
+        let second = use_a11y();
+        let focusable = move |a11y_id: AccessibilityId, name: &'static str| {
+            rect()
+                .width(Size::px(100.))
+                .height(Size::px(100.))
+                .a11y_id(a11y_id)
+                .a11y_focusable(true)
+                .on_pointer_down(move |_| a11y_id.request_focus())
+                .on_focus(move |_| state.write().push(format!("focus {name}")))
+                .on_blur(move |_| state.write().push(format!("blur {name}")))
+        };
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .on_focus_within_change(move |e: Event<FocusEventData>| {
+                        state.write().push(format!("within {}", e.focused))
+                    })
+                    .child(focusable(first, "first")),
+            )
+            .child(focusable(second, "second"))
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<String>::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    test.click_cursor((50., 50.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), vec!["within true", "focus first"]);
+    state.write().clear();
+
+    test.click_cursor((50., 150.));
+    test.sync_and_update();
+    assert_eq!(
+        *state.peek(),
+        vec!["blur first", "within false", "focus second"]
+    );
+}
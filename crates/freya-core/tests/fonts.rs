@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+const NOTO_SANS: &[u8] = include_bytes!("../../freya-edit/tests/NotoSans-Regular.ttf");
+const SAMUEL_MORSE: &[u8] = include_bytes!("../../../examples/SamuelMorse.otf");
+
+fn app() -> impl IntoElement {
+    label().text("Привет")
+}
+
+fn launch(default_font: &'static str) -> TestingRunner {
+    let mut test = launch_test(app);
+    test.set_fonts(HashMap::from_iter([
+        ("NotoSans", NOTO_SANS),
+        ("Samuel Morse", SAMUEL_MORSE),
+    ]));
+    test.set_system_fonts(false);
+    test.set_default_fonts(&[default_font.into()]);
+    test
+}
+
+fn label_width(test: &TestingRunner) -> f32 {
+    test.find(|node, element| Label::try_downcast(element).map(|_| node.layout().area.width()))
+        .expect("the label should be in the tree")
+}
+
+#[test]
+fn font_registry_lists_loaded_fonts() {
+    let test = launch("NotoSans");
+
+    let (family_names, noto_sans) = test.run_in(|| {
+        let registry = FontRegistry::get();
+        (registry.family_names(), registry.family("NotoSans"))
+    });
+
+    assert_eq!(family_names, vec!["NotoSans", "Samuel Morse"]);
+    let noto_sans = noto_sans.expect("NotoSans should be registered");
+    assert_eq!(noto_sans.faces.len(), 1);
+    assert_eq!(noto_sans.faces[0].weight, FontWeight::NORMAL);
+    assert_eq!(noto_sans.faces[0].slant, FontSlant::Upright);
+    assert!(test.run_in(|| !FontRegistry::get().has_family("Missing")));
+}
+
+#[test]
+fn script_fallback_fonts() {
+    let with_noto_sans = label_width(&launch("NotoSans"));
+
+    let mut test = launch("Samuel Morse");
+    assert_ne!(
+        label_width(&test),
+        with_noto_sans,
+        "both fonts measure the same, so this test proves nothing"
+    );
+
+    // Only used for the texts with characters of the script
+    test.set_script_fallback_fonts(FontScript::Arabic, &["NotoSans".into()]);
+    assert_ne!(label_width(&test), with_noto_sans);
+
+    test.set_script_fallback_fonts(FontScript::Cyrillic, &["NotoSans".into()]);
+    assert_eq!(label_width(&test), with_noto_sans);
+}
+
+#[test]
+fn fallback_fonts_for_text() {
+    let mut fallback_fonts = FallbackFonts::new(vec!["Inter".into()]);
+    fallback_fonts.set_script(FontScript::Cjk, ["Noto Sans JP", "Inter"]);
+    fallback_fonts.set_script(FontScript::Emoji, ["Noto Color Emoji"]);
+
+    let mut font_families = vec!["Roboto".into()];
+    fallback_fonts.extend_families(&mut font_families, "Hello 世界");
+    assert_eq!(font_families, vec!["Roboto", "Inter", "Noto Sans JP"]);
+
+    let mut font_families = Vec::new();
+    fallback_fonts.extend_families(&mut font_families, "Hello 👋");
+    assert_eq!(font_families, vec!["Inter", "Noto Color Emoji"]);
+
+    fallback_fonts.push("Roboto");
+    fallback_fonts.push_front("Fira Sans");
+    assert_eq!(
+        fallback_fonts.iter().collect::<Vec<_>>(),
+        vec!["Fira Sans", "Inter", "Roboto"]
+    );
+    assert_eq!(
+        fallback_fonts.for_script(FontScript::Cjk),
+        &["Noto Sans JP", "Inter"]
+    );
+    assert!(fallback_fonts.for_script(FontScript::Thai).is_empty());
+
+    assert_eq!(FontScript::of('a'), None);
+    assert_eq!(FontScript::of('ع'), Some(FontScript::Arabic));
+    assert_eq!(FontScript::of('한'), Some(FontScript::Cjk));
+}
@@ -181,3 +181,467 @@ fn inline_element_respects_paragraph_margin() {
     assert!(label_area.min_x() > 20.0);
     assert!(label_area.min_y() >= 19.5);
 }
+
+#[test]
+fn text_spacing_widens_inherited_text() {
+    fn app() -> impl IntoElement {
+        rect()
+            .child(label().text("Hello World"))
+            .child(rect().letter_spacing(4.).child(label().text("Hello World")))
+            .child(label().word_spacing(20.).text("Hello World"))
+            .child(paragraph().span("Hello World"))
+            .child(paragraph().span(Span::new("Hello World").letter_spacing(4.)))
+    }
+
+    let test = launch(app);
+
+    let labels = test.find_many(|node, element| {
+        Label::try_downcast(element).map(|_| node.layout().area.width())
+    });
+    assert!(labels[1] > labels[0] + 30.0);
+    assert!(labels[2] > labels[0] + 15.0);
+    assert!(labels[2] < labels[1]);
+
+    let paragraphs = test.find_many(|node, element| {
+        Paragraph::try_downcast(element).map(|_| node.layout().area.width())
+    });
+    assert!(paragraphs[1] > paragraphs[0] + 30.0);
+}
+
+#[test]
+fn span_receives_pointer_events() {
+    fn app() -> impl IntoElement {
+        let mut presses = use_state(|| 0);
+        let mut hovered = use_state(|| false);
+
+        rect()
+            .child(
+                paragraph()
+                    .width(Size::fill())
+                    .span(
+                        Span::new("Link")
+                            .id("link")
+                            .on_press(move |e: Event<SpanEventData>| {
+                                assert_eq!(e.id.as_deref(), Some("link"));
+                                *presses.write() += 1;
+                            })
+                            .on_pointer_enter(move |_| hovered.set(true))
+                            .on_pointer_leave(move |_| hovered.set(false)),
+                    )
+                    .span(" and some plain text after it."),
+            )
+            .child(label().text(format!("{} {}", presses(), hovered())))
+    }
+
+    let mut test = launch(app);
+
+    test.move_cursor((10., 10.));
+    assert!(find_label_area(&test, "0 true").is_some());
+
+    test.click_cursor((10., 10.));
+    assert!(find_label_area(&test, "1 true").is_some());
+
+    // Plain text of the same paragraph
+    test.move_cursor((150., 10.));
+    assert!(find_label_area(&test, "1 false").is_some());
+
+    test.click_cursor((150., 10.));
+    assert!(find_label_area(&test, "1 false").is_some());
+}
+
+#[test]
+fn span_handlers_are_updated() {
+    fn app() -> impl IntoElement {
+        let mut step = use_state(|| 1);
+        let mut total = use_state(|| 0);
+        let current_step = step();
+
+        rect()
+            .child(
+                paragraph()
+                    .width(Size::fill())
+                    .span(Span::new("Link").on_press(move |_| {
+                        *total.write() += current_step;
+                        step.set(10);
+                    })),
+            )
+            .child(label().text(format!("{}", total())))
+    }
+
+    let mut test = launch(app);
+
+    test.click_cursor((10., 10.));
+    assert!(find_label_area(&test, "1").is_some());
+
+    // The handler of the second render captures the new step
+    test.click_cursor((10., 10.));
+    assert!(find_label_area(&test, "11").is_some());
+}
+
+#[test]
+fn word_break_wraps_long_words() {
+    fn app() -> impl IntoElement {
+        rect()
+            .width(Size::px(80.))
+            .child(label().text("Donaudampfschifffahrtsgesellschaft"))
+            .child(
+                label()
+                    .word_break(WordBreak::BreakAll)
+                    .text("Donaudampfschifffahrtsgesellschaft"),
+            )
+    }
+
+    let test = launch(app);
+
+    let areas =
+        test.find_many(|node, element| Label::try_downcast(element).map(|_| node.layout().area));
+
+    // Only breaks on spaces, so the word overflows
+    assert!(areas[0].width() > 80.);
+    // Breaks between any two letters, so the word wraps into several lines
+    assert!(areas[1].width() <= 80.);
+    assert!(areas[1].height() > areas[0].height() * 2.);
+}
+
+#[test]
+fn vertical_writing_mode_lays_out_columns() {
+    fn app() -> impl IntoElement {
+        rect()
+            .child(paragraph().span("Hello, World"))
+            .child(
+                paragraph()
+                    .writing_mode(WritingMode::VerticalRl)
+                    .span("Hello, World"),
+            )
+            .child(
+                rect().height(Size::px(60.)).child(
+                    paragraph()
+                        .writing_mode(WritingMode::VerticalRl)
+                        .span("Hello, World"),
+                ),
+            )
+    }
+
+    let test = launch(app);
+
+    let areas = test
+        .find_many(|node, element| Paragraph::try_downcast(element).map(|_| node.layout().area));
+
+    // The rotated glyphs keep their advances, so the column is as tall as the line is wide
+    assert!((areas[1].height() - areas[0].width()).abs() < 1.);
+    assert!(areas[1].width() < areas[0].width());
+    // Columns wrap at the available height
+    assert!(areas[2].height() <= 60.);
+    assert!(areas[2].width() > areas[1].width());
+}
+
+#[test]
+fn vertical_writing_mode_is_not_queryable() {
+    let holder = ParagraphHolder::default();
+
+    launch({
+        let holder = holder.clone();
+        move || {
+            paragraph()
+                .holder(holder.clone())
+                .writing_mode(WritingMode::VerticalRl)
+                .span("Hello, World")
+        }
+    });
+
+    assert_eq!(holder.size(), None);
+    assert_eq!(holder.line_count(), 0);
+    assert_eq!(holder.index_at((5., 5.)), None);
+    assert!(holder.rects_for_range(0..5).is_empty());
+}
+
+#[test]
+fn vertical_writing_mode_ignores_horizontal_only_features() {
+    fn app() -> impl IntoElement {
+        let mut presses = use_state(|| 0);
+
+        rect()
+            .child(
+                paragraph()
+                    .writing_mode(WritingMode::VerticalRl)
+                    .cursor_index(2)
+                    .highlights(vec![(0, 4)])
+                    .text_decoration(TextDecoration::Underline)
+                    .span(Span::new("Link").on_press(move |_| *presses.write() += 1))
+                    .span(" and some plain text after it."),
+            )
+            .child(label().text(format!("{}", presses())))
+    }
+
+    let mut test = launch(app);
+    test.render();
+
+    // Spans are not hit tested in the vertical modes
+    test.click_cursor((5., 5.));
+    assert!(find_label_area(&test, "0").is_some());
+}
+
+#[test]
+fn text_path_fits_the_path() {
+    fn app() -> impl IntoElement {
+        text_path().path("M 0 100 L 200 100").text("Hello, World")
+    }
+
+    let test = launch(app);
+
+    let area = test
+        .find(|node, element| TextPath::try_downcast(element).map(|_| node.layout().area))
+        .unwrap();
+
+    assert_eq!(area.width(), 200.);
+    // The glyphs hang below the baseline on the path
+    assert!(area.height() > 100. && area.height() < 120.);
+}
+
+#[test]
+fn paragraph_holder_queries_lines() {
+    let holder = ParagraphHolder::default();
+
+    let test = launch({
+        let holder = holder.clone();
+        move || {
+            paragraph()
+                .width(Size::px(100.))
+                .holder(holder.clone())
+                .span("Some text that wraps into lines\nand a line break")
+        }
+    });
+
+    let text_len = "Some text that wraps into lines\nand a line break"
+        .encode_utf16()
+        .count();
+    let lines = holder.lines();
+    assert_eq!(lines.len(), holder.line_count());
+    assert!(lines.len() > 2);
+
+    // The lines cover the whole text, one after the other
+    assert_eq!(lines.first().unwrap().range.start, 0);
+    assert_eq!(lines.last().unwrap().range.end, text_len);
+    for pair in lines.windows(2) {
+        assert_eq!(pair[0].range.end, pair[1].range.start);
+        assert!(pair[0].baseline < pair[1].baseline);
+    }
+
+    assert_eq!(holder.index_at((0., 0.)), Some(0));
+    assert_eq!(holder.line_at_index(0), Some(0));
+    assert_eq!(holder.line_at_index(text_len - 1), Some(lines.len() - 1));
+
+    let rects = holder.rects_for_range(0..text_len);
+    assert!(rects.len() >= lines.len());
+    assert!(rects.iter().all(|rect| rect.max_x() <= 100.));
+
+    let size = holder.size().unwrap();
+    let area = test
+        .find(|node, element| Paragraph::try_downcast(element).map(|_| node.layout().area))
+        .unwrap();
+    assert_eq!(size.height, area.height());
+}
+
+#[test]
+fn start_and_middle_ellipsis_fit_in_a_line() {
+    const PATH: &str = "/home/user/projects/freya/crates/freya-core/src/elements/paragraph.rs";
+
+    let holder = ParagraphHolder::default();
+
+    let test = launch({
+        let holder = holder.clone();
+        move || {
+            rect()
+                .width(Size::px(150.))
+                .child(label().text(PATH))
+                .child(
+                    label()
+                        .text_overflow(TextOverflow::StartEllipsis)
+                        .text(PATH),
+                )
+                .child(
+                    label()
+                        .text_overflow(TextOverflow::MiddleEllipsis)
+                        .text(PATH),
+                )
+                .child(
+                    paragraph()
+                        .holder(holder.clone())
+                        .text_overflow(TextOverflow::MiddleEllipsis)
+                        .span(Span::new("/home/user/projects/").color(Color::RED))
+                        .span("freya/crates/freya-core/src/elements/paragraph.rs"),
+                )
+        }
+    });
+
+    let areas =
+        test.find_many(|node, element| Label::try_downcast(element).map(|_| node.layout().area));
+
+    // Without truncation the path doesn't fit in a line
+    assert!(areas[0].width() > 150. || areas[0].height() > areas[1].height());
+    for area in &areas[1..] {
+        assert!(area.width() <= 150.);
+        // As much text as fits is kept
+        assert!(area.width() > 130.);
+        assert_eq!(area.height(), areas[1].height());
+    }
+
+    assert_eq!(holder.line_count(), 1);
+    assert!(holder.size().unwrap().width <= 150.);
+}
+
+#[test]
+fn inline_elements_align_with_the_baseline() {
+    fn app() -> impl IntoElement {
+        let badge = |text: &'static str| label().font_size(8.).text(text);
+        paragraph()
+            .span("Text")
+            .inline_child(badge("top"), InlineAlignment::Top)
+            .inline_child(badge("above"), InlineAlignment::AboveBaseline)
+            .inline_child(badge("below"), InlineAlignment::BelowBaseline)
+            .inline_child(badge("baseline"), InlineAlignment::Baseline(5.))
+            .span("more text")
+    }
+
+    let test = launch(app);
+
+    let top = find_label_area(&test, "top").unwrap();
+    let above = find_label_area(&test, "above").unwrap();
+    let below = find_label_area(&test, "below").unwrap();
+    let baseline = find_label_area(&test, "baseline").unwrap();
+
+    assert!(top.min_y() < above.min_y());
+    assert!(above.min_y() < below.min_y());
+    // The text baseline is where the children above it end and the ones below it start
+    assert!((above.max_y() - below.min_y()).abs() < 1.);
+    assert!((baseline.min_y() + 5. - below.min_y()).abs() < 1.);
+}
+
+#[test]
+fn span_hit_testing_follows_the_painted_text() {
+    fn app() -> impl IntoElement {
+        let mut presses = use_state(|| 0);
+
+        rect()
+            .child(
+                paragraph()
+                    .width(Size::fill())
+                    .height(Size::px(100.))
+                    .margin(20.)
+                    .vertical_align(VerticalAlign::Center)
+                    .span(Span::new("Link").on_press(move |_| *presses.write() += 1)),
+            )
+            .child(label().text(format!("{}", presses())))
+    }
+
+    let mut test = launch(app);
+
+    // Above the centered text, where the span would be without the margin and alignment
+    test.click_cursor((10., 10.));
+    test.click_cursor((30., 25.));
+    assert!(find_label_area(&test, "0").is_some());
+
+    test.click_cursor((30., 70.));
+    assert!(find_label_area(&test, "1").is_some());
+}
+
+#[test]
+fn break_opportunities_keep_the_text_indexes() {
+    const WORD: &str = "Donaudampfschifffahrtsgesellschaft";
+
+    for (word_break, hyphens) in [
+        (WordBreak::BreakAll, Hyphens::Manual),
+        (WordBreak::Anywhere, Hyphens::None),
+        (WordBreak::Normal, Hyphens::Auto),
+    ] {
+        let holder = ParagraphHolder::default();
+
+        let mut test = launch({
+            let holder = holder.clone();
+            move || {
+                let mut presses = use_state(|| 0);
+
+                rect()
+                    .child(
+                        paragraph()
+                            .width(Size::px(80.))
+                            .holder(holder.clone())
+                            .locale("de")
+                            .word_break(word_break)
+                            .hyphens(hyphens)
+                            .span(WORD)
+                            .span(Span::new("Link").on_press(move |_| *presses.write() += 1)),
+                    )
+                    .child(label().text(format!("{}", presses())))
+            }
+        });
+
+        let word_len = WORD.encode_utf16().count();
+        let text_len = word_len + "Link".encode_utf16().count();
+        let lines = holder.lines();
+        assert!(lines.len() > 2);
+
+        // The lines cover the text, without the invisible break opportunities
+        assert_eq!(lines.first().unwrap().range.start, 0);
+        assert_eq!(lines.last().unwrap().range.end, text_len);
+        for pair in lines.windows(2) {
+            assert_eq!(pair[0].range.end, pair[1].range.start);
+        }
+        assert_eq!(holder.line_at_index(text_len - 1), Some(lines.len() - 1));
+
+        // Cursor positions at the start and the end of the text
+        let last_line = lines.last().unwrap();
+        assert_eq!(holder.index_at((0., 0.)), Some(0));
+        assert_eq!(
+            holder.index_at((last_line.left + last_line.width, last_line.baseline)),
+            Some(text_len)
+        );
+
+        // The highlight of the link ends with the text
+        let link_rects = holder.rects_for_range(word_len..text_len);
+        let link_rect = link_rects.last().unwrap();
+        assert!((link_rect.max_x() - (last_line.left + last_line.width)).abs() < 1.);
+
+        // The link is pressed where it is painted
+        test.click_cursor(link_rect.center().cast::<f64>());
+        assert!(find_label_area(&test, "1").is_some());
+    }
+}
+
+#[test]
+fn auto_hyphens_follow_the_language_patterns() {
+    const WORD: &str = "Donaudampfschifffahrtsgesellschaft";
+    // Do-nau-dampf-schiff-fahrts-ge-sell-schaft
+    const SYLLABLE_ENDS: [usize; 7] = [2, 5, 10, 16, 22, 24, 28];
+
+    let line_ends = |locale: &'static str| {
+        let holder = ParagraphHolder::default();
+        launch({
+            let holder = holder.clone();
+            move || {
+                paragraph()
+                    .width(Size::px(80.))
+                    .holder(holder.clone())
+                    .locale(locale)
+                    .hyphens(Hyphens::Auto)
+                    .span(WORD)
+            }
+        });
+        holder
+            .lines()
+            .iter()
+            .map(|line| line.range.end)
+            .collect::<Vec<_>>()
+    };
+
+    // German words are only hyphenated between syllables
+    let german_ends = line_ends("de");
+    assert!(german_ends.len() > 2);
+    assert_eq!(german_ends.last(), Some(&WORD.len()));
+    for end in &german_ends[..german_ends.len() - 1] {
+        assert!(SYLLABLE_ENDS.contains(end));
+    }
+
+    // Languages without patterns are not hyphenated
+    assert_eq!(line_ends("xx"), vec![WORD.len()]);
+}
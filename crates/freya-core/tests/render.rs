@@ -30,3 +30,60 @@ pub fn basic_render() {
 
     assert!(!data.is_empty());
 }
+
+fn pixel_at(data: &freya_engine::prelude::SkData, x: f32, y: f32) -> (u8, u8, u8, u8) {
+    use freya_engine::prelude::{
+        AlphaType,
+        ColorType,
+        Image,
+        ImageInfo,
+        raster_n32_premul,
+    };
+
+    let image = Image::from_encoded(data.clone()).unwrap();
+    let mut surface = raster_n32_premul((1, 1)).unwrap();
+    surface.canvas().draw_image(&image, (-x, -y), None);
+    let mut pixel = [0u8; 4];
+    surface.read_pixels(
+        &ImageInfo::new((1, 1), ColorType::RGBA8888, AlphaType::Premul, None),
+        &mut pixel,
+        4,
+        (0, 0),
+    );
+    (pixel[0], pixel[1], pixel[2], pixel[3])
+}
+
+#[test]
+pub fn filters_apply_to_the_subtree_at_once() {
+    fn app() -> impl IntoElement {
+        rect()
+            .filter(Filter::DropShadow {
+                x: 50.,
+                y: 0.,
+                blur: 0.,
+                color: Color::BLACK,
+            })
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background(Color::RED)
+                    .child(
+                        rect()
+                            .width(Size::px(40.))
+                            .height(Size::px(40.))
+                            .background(Color::GREEN),
+                    ),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    let data = test.render();
+
+    // The shadow of the child is painted below its parent instead of over it
+    assert_eq!(pixel_at(&data, 70., 20.), (255, 0, 0, 255));
+    // The shadow of the whole subtree
+    assert_eq!(pixel_at(&data, 120., 80.), (0, 0, 0, 255));
+    assert_eq!(pixel_at(&data, 20., 20.), (0, 255, 0, 255));
+}
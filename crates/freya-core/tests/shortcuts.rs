@@ -0,0 +1,279 @@
+use freya_core::{
+    integration::*,
+    prelude::*,
+};
+use freya_testing::TestingRunner;
+use torin::size::Size;
+
+fn press_stroke(test: &mut TestingRunner, stroke: &str) {
+    let stroke = stroke.parse::<KeyStroke>().unwrap();
+    test.send_event(PlatformEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: stroke.key,
+        code: Code::Unidentified,
+        modifiers: stroke.modifiers,
+    });
+    test.sync_and_update();
+}
+
+#[test]
+fn parse_and_display_shortcuts() {
+    let shortcut = "Ctrl+K Ctrl+Shift+s".parse::<Shortcut>().unwrap();
+    assert_eq!(
+        shortcut.strokes(),
+        &[
+            KeyStroke::character("k").ctrl(),
+            KeyStroke::character("S").ctrl().shift()
+        ]
+    );
+    assert_eq!(shortcut.to_string(), "Ctrl+K Ctrl+Shift+S");
+    assert_eq!(
+        "Alt+ArrowUp".parse::<KeyStroke>().unwrap(),
+        KeyStroke::new(Key::Named(NamedKey::ArrowUp)).alt()
+    );
+    assert_eq!(
+        "Ctrl++".parse::<KeyStroke>().unwrap(),
+        KeyStroke::character("+").ctrl()
+    );
+    assert_eq!(
+        "Primary+S".parse::<KeyStroke>().unwrap().modifiers,
+        Modifiers::ctrl_or_meta()
+    );
+    assert!("Hyper+S".parse::<KeyStroke>().is_err());
+    assert!("Ctrl+".parse::<KeyStroke>().is_err());
+    assert!("".parse::<Shortcut>().is_err());
+}
+
+#[test]
+fn shortcuts() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<String>>>();
+        use_shortcut(
+            "save",
+            KeyStroke::character("s").ctrl(),
+            ShortcutScope::Window,
+            move || state.write().push("save".to_string()),
+        );
+        use_shortcut(
+            "save all",
+            "Ctrl+K Ctrl+S".parse::<Shortcut>().unwrap(),
+            ShortcutScope::Window,
+            move || state.write().push("save all".to_string()),
+        );
+        rect()
+            .expanded()
+            .on_global_key_down(move |e: Event<KeyboardEventData>| {
+                state.write().push(format!("key {}", e.key));
+            })
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<String>::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Used strokes never reach the elements
+    press_stroke(&mut test, "Ctrl+S");
+    assert_eq!(*state.peek(), vec!["save"]);
+
+    press_stroke(&mut test, "Ctrl+K");
+    press_stroke(&mut test, "Ctrl+S");
+    assert_eq!(*state.peek(), vec!["save", "save all"]);
+
+    // A stroke that breaks a multi-stroke shortcut is handled on its own
+    press_stroke(&mut test, "Ctrl+K");
+    press_stroke(&mut test, "Ctrl+X");
+    press_stroke(&mut test, "Ctrl+S");
+    assert_eq!(*state.peek(), vec!["save", "save all", "key x", "save"]);
+    state.write().clear();
+
+    let shortcuts = test.run_in(Shortcuts::get);
+    assert!(test.run_in(|| shortcuts.conflicts()).is_empty());
+
+    test.run_in(|| shortcuts.rebind("save", "Ctrl+K".parse().unwrap()));
+    let conflicts = test.run_in(|| shortcuts.conflicts());
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].first.name, "save");
+    assert_eq!(conflicts[0].second.name, "save all");
+
+    press_stroke(&mut test, "Ctrl+K");
+    assert_eq!(*state.peek(), vec!["save"]);
+    press_stroke(&mut test, "Ctrl+S");
+    assert_eq!(*state.peek(), vec!["save", "key s"]);
+
+    test.run_in(|| shortcuts.reset("save"));
+    let list = test.run_in(|| shortcuts.list());
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].shortcut, list[0].default_shortcut);
+    assert_eq!(list[0].shortcut.to_string(), "Ctrl+S");
+}
+
+#[test]
+fn focus_scoped_shortcuts() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<String>>>();
+        let focus_scope = use_focus_scope();
+        let a11y_id = use_a11y();
+        use_shortcut(
+            "find",
+            KeyStroke::character("f").ctrl(),
+            ShortcutScope::Window,
+            move || state.write().push("window find".to_string()),
+        );
+        use_shortcut(
+            "editor.find",
+            KeyStroke::character("f").ctrl(),
+            ShortcutScope::Focus(focus_scope),
+            move || state.write().push("editor find".to_string()),
+        );
+        rect()
+            .expanded()
+            .child(
+                rect().on_focus_within_change(focus_scope.track()).child(
+                    rect()
+                        .width(Size::px(100.))
+                        .height(Size::px(100.))
+                        .a11y_id(a11y_id)
+                        .a11y_focusable(true)
+                        .on_pointer_down(move |_| a11y_id.request_focus()),
+                ),
+            )
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<String>::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    press_stroke(&mut test, "Ctrl+F");
+    assert_eq!(*state.peek(), vec!["window find"]);
+
+    test.click_cursor((50., 50.));
+    test.sync_and_update();
+    press_stroke(&mut test, "Ctrl+F");
+    assert_eq!(*state.peek(), vec!["window find", "editor find"]);
+}
+
+#[test]
+fn nested_focus_scoped_shortcuts() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<String>>>();
+        let outer_scope = use_focus_scope();
+        let inner_scope = use_focus_scope();
+        let outer_a11y_id = use_a11y();
+        let inner_a11y_id = use_a11y();
+        // The inner scope is registered first so the registration order can't decide
+        use_shortcut(
+            "inner.find",
+            KeyStroke::character("f").ctrl(),
+            ShortcutScope::Focus(inner_scope),
+            move || state.write().push("inner find".to_string()),
+        );
+        use_shortcut(
+            "outer.find",
+            KeyStroke::character("f").ctrl(),
+            ShortcutScope::Focus(outer_scope),
+            move || state.write().push("outer find".to_string()),
+        );
+        rect()
+            .expanded()
+            .on_focus_within_change(outer_scope.track())
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .a11y_id(outer_a11y_id)
+                    .a11y_focusable(true)
+                    .on_pointer_down(move |_| outer_a11y_id.request_focus()),
+            )
+            .child(
+                rect().on_focus_within_change(inner_scope.track()).child(
+                    rect()
+                        .width(Size::px(100.))
+                        .height(Size::px(100.))
+                        .a11y_id(inner_a11y_id)
+                        .a11y_focusable(true)
+                        .on_pointer_down(move |_| inner_a11y_id.request_focus()),
+                ),
+            )
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<String>::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    test.click_cursor((50., 150.));
+    test.sync_and_update();
+    press_stroke(&mut test, "Ctrl+F");
+    assert_eq!(*state.peek(), vec!["inner find"]);
+
+    test.click_cursor((50., 50.));
+    test.sync_and_update();
+    press_stroke(&mut test, "Ctrl+F");
+    assert_eq!(*state.peek(), vec!["inner find", "outer find"]);
+}
+
+#[test]
+fn single_key_shortcuts_skip_text_inputs() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<String>>>();
+        let a11y_id = use_a11y();
+        use_shortcut(
+            "next",
+            KeyStroke::character("j"),
+            ShortcutScope::Window,
+            move || state.write().push("next".to_string()),
+        );
+        use_shortcut(
+            "search",
+            KeyStroke::character("j").ctrl(),
+            ShortcutScope::Window,
+            move || state.write().push("search".to_string()),
+        );
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .a11y_id(a11y_id)
+                    .a11y_focusable(true)
+                    .a11y_role(AccessibilityRole::TextInput)
+                    .on_pointer_down(move |_| a11y_id.request_focus())
+                    .on_key_down(move |e: Event<KeyboardEventData>| {
+                        state.write().push(format!("typed {}", e.key));
+                    }),
+            )
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::<String>::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    press_stroke(&mut test, "J");
+    assert_eq!(*state.peek(), vec!["next"]);
+
+    test.click_cursor((50., 50.));
+    test.sync_and_update();
+    press_stroke(&mut test, "J");
+    press_stroke(&mut test, "Ctrl+J");
+    assert_eq!(*state.peek(), vec!["next", "typed j", "search"]);
+}
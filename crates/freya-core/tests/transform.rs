@@ -0,0 +1,36 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+fn events_land_on_transformed_elements() {
+    fn app() -> impl IntoElement {
+        let mut clicks = use_state(|| 0);
+
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background(Color::RED)
+                    .transforms([Transform::translate(200., 0.), Transform::skew(10., 0.)])
+                    .on_mouse_up(move |_| clicks.set(clicks() + 1)),
+            )
+            .child(label().text(format!("clicks:{}", clicks())))
+    }
+
+    let mut test = launch_test(app);
+
+    // Where the element would be without its transforms
+    test.click_cursor((50.0, 50.0));
+    assert!(
+        test.find(|_, e| Label::try_downcast(e).filter(|l| l.text.as_ref() == "clicks:0"))
+            .is_some()
+    );
+
+    test.click_cursor((250.0, 50.0));
+    assert!(
+        test.find(|_, e| Label::try_downcast(e).filter(|l| l.text.as_ref() == "clicks:1"))
+            .is_some()
+    );
+}
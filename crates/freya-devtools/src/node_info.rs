@@ -1,17 +1,20 @@
 use freya_core::{
     integration::*,
     prelude::{
+        BlendMode,
         Border,
         Color,
         CornerRadius,
         Fill,
         FontSlant,
+        InlineAlignment,
         Shadow,
         TextAlign,
         TextDecoration,
         TextHeightBehavior,
         TextOverflow,
         TextShadow,
+        WritingMode,
     },
 };
 use serde::{
@@ -54,6 +57,10 @@ pub struct NodeState {
     pub text_style: TextStyleState,
     pub layout: torin::node::Node,
     pub accessibility: AccessibilityData,
+    pub blend_mode: Option<BlendMode>,
+    pub writing_mode: Option<WritingMode>,
+    /// Alignments of the inline children of a paragraph, in order.
+    pub inline_alignments: Vec<InlineAlignment>,
 }
 
 impl NodeState {
@@ -103,6 +110,10 @@ impl NodeState {
                 AttributeType::Measure(self.layout.offset_y.get()),
             ),
             ("content", AttributeType::Content(&self.layout.content)),
+            (
+                "grid_placement",
+                AttributeType::Text(self.layout.grid_placement.pretty()),
+            ),
             ("spacing", AttributeType::Length(self.layout.spacing)),
         ]
     }
@@ -125,6 +136,10 @@ impl NodeState {
             attributes.push(("border", AttributeType::Border(border)));
         }
 
+        if let Some(blend_mode) = self.blend_mode {
+            attributes.push(("blend_mode", AttributeType::Text(blend_mode.pretty())));
+        }
+
         attributes
     }
 
@@ -167,8 +182,73 @@ impl NodeState {
                 "text_decoration",
                 AttributeType::TextDecoration(self.text_style.text_decoration),
             ),
+            (
+                "text_decoration_style",
+                AttributeType::Text(self.text_style.text_decoration_style.pretty()),
+            ),
+            (
+                "text_decoration_thickness",
+                AttributeType::Measure(self.text_style.text_decoration_thickness.into()),
+            ),
+            (
+                "layout_direction",
+                AttributeType::Text(self.text_style.layout_direction.pretty()),
+            ),
+            (
+                "letter_spacing",
+                AttributeType::Measure(self.text_style.letter_spacing.into()),
+            ),
+            (
+                "word_spacing",
+                AttributeType::Measure(self.text_style.word_spacing.into()),
+            ),
+            (
+                "word_break",
+                AttributeType::Text(self.text_style.word_break.pretty()),
+            ),
+            (
+                "hyphens",
+                AttributeType::Text(self.text_style.hyphens.pretty()),
+            ),
         ];
 
+        if let Some(text_decoration_color) = self.text_style.text_decoration_color {
+            attributes.push((
+                "text_decoration_color",
+                AttributeType::Color(text_decoration_color),
+            ));
+        }
+
+        if let Some(writing_mode) = self.writing_mode {
+            attributes.push(("writing_mode", AttributeType::Text(writing_mode.pretty())));
+        }
+
+        for inline_alignment in self.inline_alignments.iter() {
+            attributes.push((
+                "inline_alignment",
+                AttributeType::Text(inline_alignment.pretty()),
+            ));
+        }
+
+        if let Some(locale) = &self.text_style.locale {
+            attributes.push(("locale", AttributeType::Text(locale.to_string())));
+        }
+
+        if let Some(text_stroke) = self.text_style.text_stroke {
+            attributes.push(("text_stroke", AttributeType::Text(text_stroke.pretty())));
+        }
+
+        for font_feature in self.text_style.font_features.iter() {
+            attributes.push(("font_feature", AttributeType::Text(font_feature.pretty())));
+        }
+
+        for font_variation in self.text_style.font_variations.iter() {
+            attributes.push((
+                "font_variation",
+                AttributeType::Text(font_variation.pretty()),
+            ));
+        }
+
         for shadow in self.style.shadows.iter() {
             attributes.push(("shadow", AttributeType::Shadow(shadow)));
         }
@@ -1,4 +1,5 @@
 use std::{
+    any::Any,
     collections::HashMap,
     ops::Div,
     sync::{
@@ -9,6 +10,10 @@ use std::{
 
 use async_tungstenite::WebSocketSender;
 use freya_core::{
+    elements::paragraph::{
+        ParagraphContent,
+        ParagraphElement,
+    },
     integration::{
         NodeId,
         Tree,
@@ -147,6 +152,7 @@ impl DevtoolsPlugin {
             let layout_node = tree.layout.get(&node_id).cloned().unwrap();
             let text_style_state = tree.text_style_state.get(&node_id).cloned().unwrap();
             let element = tree.elements.get(&node_id).unwrap();
+            let paragraph = (element.as_ref() as &dyn Any).downcast_ref::<ParagraphElement>();
             let parent_id = tree.parents.get(&node_id).cloned();
             let layer = tree.layer_state.get(&node_id).map(|s| s.layer).unwrap_or(0);
             let children_len = tree
@@ -168,6 +174,20 @@ impl DevtoolsPlugin {
                     layout: element.layout().into_owned().layout,
                     text_style: text_style_state,
                     accessibility: element.accessibility().into_owned(),
+                    blend_mode: element.effect().and_then(|effect| effect.blend_mode),
+                    writing_mode: paragraph.map(|paragraph| paragraph.writing_mode),
+                    inline_alignments: paragraph
+                        .map(|paragraph| {
+                            paragraph
+                                .contents
+                                .iter()
+                                .filter_map(|content| match content {
+                                    ParagraphContent::Element(alignment) => Some(*alignment),
+                                    ParagraphContent::Span => None,
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
                 },
                 area: layout_node.area.div(scale_factor),
                 inner_area: layout_node.inner_area.div(scale_factor),
@@ -206,6 +226,10 @@ impl FreyaPlugin for DevtoolsPlugin {
                     },
                 });
             }
+            PluginEvent::BeforeRender { damage, .. } => {
+                // The wireframes are drawn on top of the app and move along with the highlighted nodes
+                damage.invalidate_all();
+            }
             PluginEvent::AfterRender {
                 tree,
                 window,
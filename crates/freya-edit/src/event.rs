@@ -1,9 +1,4 @@
-use std::ops::Mul;
-
-use freya_core::{
-    elements::paragraph::ParagraphHolderInner,
-    prelude::*,
-};
+use freya_core::prelude::*;
 use keyboard_types::NamedKey;
 use torin::prelude::CursorPoint;
 
@@ -52,11 +47,7 @@ impl EditableEvent<'_> {
                 editor_line,
                 holder,
             } => {
-                let holder = holder.0.borrow();
-                let ParagraphHolderInner {
-                    paragraph,
-                    scale_factor,
-                } = holder.as_ref().unwrap();
+                let position = holder.index_at(location).unwrap();
 
                 let mut text_editor = editor.write();
 
@@ -72,11 +63,7 @@ impl EditableEvent<'_> {
                     PressEventType::Triple => {
                         let current_selection = text_editor.selection().clone();
 
-                        let char_position = paragraph.get_glyph_position_at_coordinate(
-                            location.mul(*scale_factor).to_i32().to_tuple(),
-                        );
-                        let press_selection = text_editor
-                            .measure_selection(char_position.position as usize, editor_line);
+                        let press_selection = text_editor.measure_selection(position, editor_line);
 
                         // Get the line start char and its length
                         let line = text_editor.char_to_line(press_selection.pos());
@@ -96,11 +83,8 @@ impl EditableEvent<'_> {
                         let new_selection = if config.select_all_on_double_click {
                             TextSelection::new_range((0, text_editor.len_utf16_cu()))
                         } else {
-                            let char_position = paragraph.get_glyph_position_at_coordinate(
-                                location.mul(*scale_factor).to_i32().to_tuple(),
-                            );
-                            let press_selection = text_editor
-                                .measure_selection(char_position.position as usize, editor_line);
+                            let press_selection =
+                                text_editor.measure_selection(position, editor_line);
 
                             let range = text_editor.find_word_boundaries(press_selection.pos());
                             TextSelection::new_range(range)
@@ -122,11 +106,7 @@ impl EditableEvent<'_> {
                     PressEventType::Single => {
                         let current_selection = text_editor.selection().clone();
 
-                        let char_position = paragraph.get_glyph_position_at_coordinate(
-                            location.mul(*scale_factor).to_i32().to_tuple(),
-                        );
-                        let new_selection = text_editor
-                            .measure_selection(char_position.position as usize, editor_line);
+                        let new_selection = text_editor.measure_selection(position, editor_line);
 
                         // Move the cursor
                         if current_selection != new_selection {
@@ -143,18 +123,8 @@ impl EditableEvent<'_> {
                 if dragging.peek().clicked {
                     EventsCombos::moved(location);
 
-                    let paragraph = holder.0.borrow();
-                    let ParagraphHolderInner {
-                        paragraph,
-                        scale_factor,
-                    } = paragraph.as_ref().unwrap();
-
-                    let dist_position = location.mul(*scale_factor);
-
                     // Calculate the end of the highlighting
-                    let dist_char = paragraph
-                        .get_glyph_position_at_coordinate(dist_position.to_i32().to_tuple());
-                    let to = dist_char.position as usize;
+                    let to = holder.index_at(location).unwrap();
 
                     if editor.peek().get_selection().is_none() {
                         editor.write().selection_mut().set_as_range();
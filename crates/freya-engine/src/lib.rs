@@ -6,6 +6,7 @@ pub mod prelude {
     }
 
     pub use source::{
+        BlendMode as SkBlendMode,
         BlurStyle as SkBlurStyle,
         Color as SkColor,
         Color4f as SkColor4f,
@@ -25,6 +26,7 @@ pub mod prelude {
         Surface as SkSurface,
         TextAlign as SkTextAlign,
         TextDecoration as SkTextDecoration,
+        TextDecorationStyle as SkTextDecorationStyle,
         TextHeightBehavior as SkTextHeightBehavior,
         TextShadow as SkTextShadow,
         *,
@@ -32,8 +32,10 @@ pub use skia_safe::{
     ClipOp,
     Color,
     Color4f,
+    ColorFilter,
     ColorSpace,
     ColorType,
+    ContourMeasureIter,
     CubicResampler,
     Data,
     EncodedImageFormat,
@@ -41,8 +43,11 @@ pub use skia_safe::{
     Font,
     FontArguments,
     FontHinting,
+    FontMetrics,
     FontMgr,
     FontStyle,
+    FourByteTag,
+    GlyphId,
     HSV,
     IPoint,
     IRect,
@@ -60,10 +65,14 @@ pub use skia_safe::{
     PathBuilder,
     PathDirection,
     PathFillType,
+    PathOp,
+    Picture,
+    PictureRecorder,
     Pixmap,
     Point,
     RGB,
     RRect,
+    RSXform,
     Rect,
     RuntimeEffect,
     SamplingOptions,
@@ -74,7 +83,12 @@ pub use skia_safe::{
     Typeface,
     V3,
     canvas::SaveLayerRec,
+    color_filters,
     font::Edging as FontEdging,
+    font_arguments::{
+        VariationPosition,
+        variation_position::Coordinate as VariationCoordinate,
+    },
     font_style::{
         Slant,
         Weight,
@@ -89,7 +103,10 @@ pub use skia_safe::{
         set_resource_cache_single_allocation_byte_limit,
         set_resource_cache_total_bytes_limit,
     },
-    image_filters::blur,
+    image_filters::{
+        self,
+        blur,
+    },
     images::raster_from_data,
     path_builder::ArcSize,
     resources::LocalResourceProvider,
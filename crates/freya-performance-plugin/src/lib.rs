@@ -72,6 +72,7 @@ struct WindowMetrics {
     max_fps: usize,
 
     started_render: Option<Instant>,
+    overlay_rendered: bool,
 
     started_layout: Option<Instant>,
     finished_layout: Option<Duration>,
@@ -207,8 +208,14 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                 metrics.finished_accessibility_updates =
                     Some(metrics.started_accessibility_updates.unwrap().elapsed())
             }
-            PluginEvent::BeforeRender { window, .. } => {
-                self.get_metrics(window.id()).started_render = Some(Instant::now())
+            PluginEvent::BeforeRender { window, damage, .. } => {
+                let enabled = self.enabled;
+                let metrics = self.get_metrics(window.id());
+                metrics.started_render = Some(Instant::now());
+                // Redraw everything to draw the overlay or get rid of it
+                if enabled || std::mem::take(&mut metrics.overlay_rendered) {
+                    damage.invalidate_all();
+                }
             }
             PluginEvent::AfterRender {
                 window,
@@ -223,6 +230,7 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                 let metrics = self.get_metrics(window.id());
                 let scale_factor = window.scale_factor() as f32;
                 let started_render = metrics.started_render.take().unwrap();
+                metrics.overlay_rendered = true;
 
                 canvas.save();
                 canvas.scale((scale_factor, scale_factor));
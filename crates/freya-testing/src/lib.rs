@@ -53,6 +53,13 @@ use freya_clipboard::copypasta::{
 };
 use freya_components::{
     cache::AssetCacher,
+    drag_drop::{
+        DragPayload,
+        DragSourceHandle,
+        InProcessDragSource,
+        OutgoingDrag,
+        receive_drag,
+    },
     integration::integration,
 };
 use freya_core::{
@@ -148,6 +155,9 @@ pub fn launch_test(app: impl Into<AppComponent>) -> TestingRunner {
     TestingRunner::new(app, Size2D::new(500., 500.), |_| {}, 1.0).0
 }
 
+/// Time between the touches sent by [TestingRunner::press_finger] and the other touch helpers.
+pub const TOUCH_INTERVAL: Duration = Duration::from_millis(16);
+
 pub struct TestingRunner {
     nodes_state: NodesState<NodeId>,
     runner: Runner,
@@ -171,8 +181,14 @@ pub struct TestingRunner {
     animation_clock: AnimationClock,
     ticker_sender: RenderingTickerSender,
 
-    default_fonts: Vec<Cow<'static, str>>,
+    default_fonts: FallbackFonts,
     scale_factor: f64,
+
+    drag_source: InProcessDragSource,
+
+    gesture_recognizer: GestureRecognizer,
+    /// Virtual clock of the gesture recognizer, so gestures don't depend on how fast tests run.
+    gestures_clock: Instant,
 }
 
 impl TestingRunner {
@@ -260,10 +276,17 @@ impl TestingRunner {
 
         runner.provide_root_context(|| tree.borrow().accessibility_generator.clone());
 
+        let drag_source = InProcessDragSource::default();
+        runner.provide_root_context(|| DragSourceHandle::new(drag_source.clone()));
+
         let hook_result = hook(&mut runner);
 
         runner.provide_root_context(|| font_collection.clone());
 
+        runner.provide_root_context(|| {
+            FontRegistry::new(Some(FontMgr::default()), font_manager.clone())
+        });
+
         let nodes_state = NodesState::default();
         let accessibility = AccessibilityTree::default();
 
@@ -289,8 +312,13 @@ impl TestingRunner {
             animation_clock,
             ticker_sender,
 
-            default_fonts: default_fonts(),
+            default_fonts: default_fonts().into(),
             scale_factor,
+
+            drag_source,
+
+            gesture_recognizer: GestureRecognizer::default(),
+            gestures_clock: Instant::now(),
         };
 
         runner.sync_and_update();
@@ -306,10 +334,7 @@ impl TestingRunner {
     }
 
     fn register_font(&mut self, font_name: &str, font_data: &[u8]) {
-        let typeface = self
-            .font_collection
-            .fallback_manager()
-            .unwrap()
+        let typeface = FontMgr::default()
             .new_from_data(SkData::new_copy(font_data), None)
             .unwrap_or_else(|| panic!("Failed to load font {font_name}."));
         self.font_provider
@@ -323,9 +348,39 @@ impl TestingRunner {
         tree.text_cache.reset();
     }
 
+    /// Whether texts can use the fonts installed in the system. Defaults to `true`.
+    ///
+    /// Disable it so texts only use the fonts from [TestingRunner::set_fonts], which makes
+    /// the layout and the snapshots of the tests the same in every machine.
+    pub fn set_system_fonts(&mut self, enabled: bool) {
+        let system_font_manager = if enabled {
+            self.font_collection.enable_font_fallback();
+            FontMgr::default()
+        } else {
+            self.font_collection.disable_font_fallback();
+            FontMgr::empty()
+        };
+        self.font_collection
+            .set_default_font_manager(system_font_manager.clone(), None);
+        let font_manager = self.font_manager.clone();
+        self.runner.provide_root_context(|| {
+            FontRegistry::new(enabled.then_some(system_font_manager), font_manager)
+        });
+        self.relayout_text();
+    }
+
     pub fn set_default_fonts(&mut self, fonts: &[Cow<'static, str>]) {
-        self.default_fonts.clear();
-        self.default_fonts.extend_from_slice(fonts);
+        self.default_fonts.set(fonts.iter().cloned());
+        self.relayout_text();
+    }
+
+    /// Set the fallback fonts of the texts with characters of the given script.
+    pub fn set_script_fallback_fonts(&mut self, script: FontScript, fonts: &[Cow<'static, str>]) {
+        self.default_fonts.set_script(script, fonts.iter().cloned());
+        self.relayout_text();
+    }
+
+    fn relayout_text(&mut self) {
         self.invalidate_text_layout();
         self.tree.borrow_mut().measure_layout(
             self.size,
@@ -452,14 +507,26 @@ impl TestingRunner {
     }
 
     pub fn send_event(&mut self, platform_event: PlatformEvent) {
+        let focused_node_id = self.accessibility.focused_node_id();
+        if self
+            .runner
+            .handle_shortcut(&platform_event, &self.tree.borrow(), focused_node_id)
+        {
+            return;
+        }
         let mut events_measurer_adapter = EventsMeasurerAdapter {
             tree: &mut self.tree.borrow_mut(),
             scale_factor: self.scale_factor,
         };
+        let mut platform_events = recognize_gestures(
+            &mut self.gesture_recognizer,
+            vec![platform_event],
+            self.gestures_clock,
+        );
         let processed_events = events_measurer_adapter.run(
-            &mut vec![platform_event],
+            &mut platform_events,
             &mut self.nodes_state,
-            self.accessibility.focused_node_id(),
+            focused_node_id,
         );
         self.events_sender
             .unbounded_send(EventsChunk::Processed(processed_events))
@@ -532,38 +599,75 @@ impl TestingRunner {
     }
 
     pub fn press_touch(&mut self, location: impl Into<CursorPoint>) {
-        self.send_event(PlatformEvent::Touch {
-            name: TouchEventName::TouchStart,
-            location: location.into(),
-            finger_id: 0,
-            phase: TouchPhase::Started,
-            force: None,
-        });
-        self.sync_and_update();
+        self.press_finger(0, location);
     }
 
     pub fn move_touch(&mut self, location: impl Into<CursorPoint>) {
-        self.send_event(PlatformEvent::Touch {
-            name: TouchEventName::TouchMove,
-            location: location.into(),
-            finger_id: 0,
-            phase: TouchPhase::Moved,
-            force: None,
-        });
-        self.sync_and_update();
+        self.move_finger(0, location);
     }
 
     pub fn release_touch(&mut self, location: impl Into<CursorPoint>) {
+        self.release_finger(0, location);
+    }
+
+    /// Place the finger `finger_id` on the screen. Use different fingers for multi-touch gestures such as pinching.
+    ///
+    /// Every touch happens [TOUCH_INTERVAL] after the previous one for the gesture recognizer.
+    pub fn press_finger(&mut self, finger_id: u64, location: impl Into<CursorPoint>) {
+        self.send_touch(
+            TouchEventName::TouchStart,
+            TouchPhase::Started,
+            finger_id,
+            location.into(),
+        );
+    }
+
+    pub fn move_finger(&mut self, finger_id: u64, location: impl Into<CursorPoint>) {
+        self.send_touch(
+            TouchEventName::TouchMove,
+            TouchPhase::Moved,
+            finger_id,
+            location.into(),
+        );
+    }
+
+    pub fn release_finger(&mut self, finger_id: u64, location: impl Into<CursorPoint>) {
+        self.send_touch(
+            TouchEventName::TouchEnd,
+            TouchPhase::Ended,
+            finger_id,
+            location.into(),
+        );
+    }
+
+    fn send_touch(
+        &mut self,
+        name: TouchEventName,
+        phase: TouchPhase,
+        finger_id: u64,
+        location: CursorPoint,
+    ) {
+        self.gestures_clock += TOUCH_INTERVAL;
         self.send_event(PlatformEvent::Touch {
-            name: TouchEventName::TouchEnd,
-            location: location.into(),
-            finger_id: 0,
-            phase: TouchPhase::Ended,
+            name,
+            location,
+            finger_id,
+            phase,
             force: None,
         });
         self.sync_and_update();
     }
 
+    /// Advance the clock of the gesture recognizer without touching the screen, e.g. to hold a finger down for a long press.
+    pub fn advance_touch_time(&mut self, duration: Duration) {
+        self.gestures_clock += duration;
+        let gestures = self.gesture_recognizer.tick(self.gestures_clock);
+        for gesture in gestures {
+            self.send_event(gesture.into());
+        }
+        self.sync_and_update();
+    }
+
     pub fn scroll(&mut self, cursor: impl Into<CursorPoint>, scroll: impl Into<CursorPoint>) {
         let cursor = cursor.into();
         let scroll = scroll.into();
@@ -583,6 +687,61 @@ impl TestingRunner {
         self.sync_and_update();
     }
 
+    /// Take the drags released outside of the window so far, see [`InProcessDragSource`].
+    pub fn take_outgoing_drags(&self) -> Vec<OutgoingDrag> {
+        self.drag_source.take_outgoing()
+    }
+
+    /// Move a drag carrying a [`DragPayload`] coming from another window to `cursor`, as
+    /// `freya-winit` does when a drag is moved over another window of the app.
+    pub fn drag_over(&mut self, payload: DragPayload, cursor: impl Into<CursorPoint>) {
+        self.run_in(|| receive_drag(Some(payload)));
+        self.send_event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseMove,
+            cursor: cursor.into(),
+            button: None,
+        });
+        self.sync_and_update();
+    }
+
+    /// Move a drag coming from another window out of this one, see [`Self::drag_over`].
+    pub fn drag_leave(&mut self) {
+        self.send_event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseMove,
+            cursor: (-1., -1.).into(),
+            button: None,
+        });
+        self.sync_and_update();
+        self.run_in(|| receive_drag(None));
+    }
+
+    /// Drop a [`DragPayload`] coming from another window at `cursor`, as `freya-winit` does
+    /// when a drag is released over another window of the app.
+    pub fn drop_drag(&mut self, payload: DragPayload, cursor: impl Into<CursorPoint>) {
+        let cursor = cursor.into();
+        let files = payload.files().to_vec();
+        self.run_in(|| receive_drag(Some(payload)));
+        self.send_event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseMove,
+            cursor,
+            button: None,
+        });
+        self.send_event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor,
+            button: Some(MouseButton::Left),
+        });
+        if !files.is_empty() {
+            self.send_event(PlatformEvent::File {
+                name: FileEventName::FileDrop,
+                cursor,
+                file_paths: files,
+            });
+        }
+        self.sync_and_update();
+        self.run_in(|| receive_drag(None));
+    }
+
     pub fn animation_clock(&mut self) -> &mut AnimationClock {
         &mut self.animation_clock
     }
@@ -598,6 +757,7 @@ impl TestingRunner {
             canvas: surface.canvas(),
             scale_factor: self.scale_factor,
             background: Color::WHITE,
+            damage: &Damage::full(),
         };
         render_pipeline.render();
 
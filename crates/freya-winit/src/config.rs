@@ -9,7 +9,11 @@ use std::{
 use bytes::Bytes;
 use freya_core::{
     integration::*,
-    prelude::Color,
+    prelude::{
+        Color,
+        FallbackFonts,
+        FontScript,
+    },
 };
 use image::ImageReader;
 use winit::{
@@ -260,11 +264,13 @@ pub struct LaunchConfig {
     pub(crate) tray: (Option<TrayIconGetter>, Option<TrayHandler>),
     pub(crate) plugins: PluginsManager,
     pub(crate) embedded_fonts: EmbeddedFonts,
-    pub(crate) fallback_fonts: Vec<Cow<'static, str>>,
+    pub(crate) fallback_fonts: FallbackFonts,
     pub(crate) tasks: Vec<TaskHandler>,
     pub(crate) exit_on_close: bool,
     pub(crate) event_loop: Option<winit::event_loop::EventLoop<crate::renderer::NativeEvent>>,
     pub(crate) gpu_resource_cache_limit: usize,
+    #[cfg(feature = "os-drag")]
+    pub(crate) os_drag: bool,
 }
 
 impl Default for LaunchConfig {
@@ -275,11 +281,13 @@ impl Default for LaunchConfig {
             tray: (None, None),
             plugins: PluginsManager::default(),
             embedded_fonts: Default::default(),
-            fallback_fonts: default_fonts(),
+            fallback_fonts: default_fonts().into(),
             tasks: Vec::new(),
             exit_on_close: true,
             event_loop: None,
             gpu_resource_cache_limit: 1024 * 1024 * 1024,
+            #[cfg(feature = "os-drag")]
+            os_drag: false,
         }
     }
 }
@@ -358,13 +366,31 @@ impl LaunchConfig {
 
     /// Register a fallback font. Will be used if the default fonts are not available.
     pub fn with_fallback_font(mut self, font_family: impl Into<Cow<'static, str>>) -> Self {
-        self.fallback_fonts.push(font_family.into());
+        self.fallback_fonts.push(font_family);
         self
     }
 
     /// Register a default font. Will be used if found.
     pub fn with_default_font(mut self, font_name: impl Into<Cow<'static, str>>) -> Self {
-        self.fallback_fonts.insert(0, font_name.into());
+        self.fallback_fonts.push_front(font_name);
+        self
+    }
+
+    /// Register the fallback fonts of a script, tried in order for the texts with characters
+    /// of that script that the default and fallback fonts can't render.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// LaunchConfig::new()
+    ///     .with_script_fallback_fonts(FontScript::Cjk, ["Noto Sans JP", "Noto Sans SC"])
+    ///     .with_script_fallback_fonts(FontScript::Emoji, ["Noto Color Emoji"]);
+    /// ```
+    pub fn with_script_fallback_fonts(
+        mut self,
+        script: FontScript,
+        fonts: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    ) -> Self {
+        self.fallback_fonts.set_script(script, fonts);
         self
     }
 
@@ -404,4 +430,16 @@ impl LaunchConfig {
         self.gpu_resource_cache_limit = gpu_resource_cache_limit;
         self
     }
+
+    /// Hand the [`DragPayload`](freya_components::drag_drop::DragPayload) files of drags leaving
+    /// a window to the OS, so they can be dropped into file managers, other applications or
+    /// other windows of the app, which receive them as file drops.
+    ///
+    /// Only supported on Windows and macOS. Elsewhere, and for payloads without files, drags that
+    /// leave their window are only delivered to the other windows of the app.
+    #[cfg(feature = "os-drag")]
+    pub fn with_os_drag(mut self, os_drag: bool) -> Self {
+        self.os_drag = os_drag;
+        self
+    }
 }
@@ -0,0 +1,282 @@
+use std::mem;
+
+use freya_components::drag_drop::{
+    DragPayload,
+    DragSource,
+    receive_drag,
+};
+use freya_core::{
+    integration::*,
+    prelude::MouseButton,
+};
+use torin::prelude::CursorPoint;
+#[cfg(feature = "os-drag")]
+use winit::event::ElementState;
+use winit::window::WindowId;
+
+use crate::{
+    extensions::WinitPlatformExt,
+    renderer::{
+        NativeEvent,
+        NativeGenericEvent,
+        RendererContext,
+    },
+};
+
+/// Marks that the app hands drags leaving its windows to the OS, see
+/// [`LaunchConfig::with_os_drag`](crate::config::LaunchConfig::with_os_drag).
+#[cfg(feature = "os-drag")]
+#[derive(Clone)]
+pub(crate) struct OsDragContext;
+
+/// [`DragSource`] of a winit window.
+///
+/// With [`OsDragContext`], the files of drags leaving the window are handed to an OS drag
+/// session. Otherwise drags are shown over the window of the app under the cursor, and
+/// delivered to it when released.
+pub(crate) struct WinitDragSource {
+    pub(crate) platform: Platform,
+}
+
+impl DragSource for WinitDragSource {
+    #[cfg(feature = "os-drag")]
+    fn drag_exited(&self, payload: &DragPayload) {
+        if payload.files().is_empty() {
+            return;
+        }
+        let files = payload.files().to_vec();
+        let _ = self.platform.post_callback(move |window_id, context| {
+            if context
+                .global_contexts
+                .try_get_context::<OsDragContext>()
+                .is_none()
+            {
+                return;
+            }
+            let Some(app) = context.windows.get_mut(&window_id) else {
+                return;
+            };
+            if os_drag::start(&app.window, files) {
+                // The OS owns the drag now, so release it in the window
+                app.os_drag_active = true;
+                app.mouse_state = ElementState::Released;
+                app.process_platform_events(vec![PlatformEvent::Mouse {
+                    name: MouseEventName::MouseUp,
+                    cursor: app.position,
+                    button: Some(MouseButton::Left),
+                }]);
+            }
+        });
+    }
+
+    fn drag_moved_outside(&self, payload: &DragPayload, position: CursorPoint) {
+        let payload = payload.clone();
+        let _ = self.platform.post_callback(move |window_id, context| {
+            if context
+                .windows
+                .get(&window_id)
+                .is_none_or(|source| source.os_drag_active)
+            {
+                return;
+            }
+            let target = window_under_cursor(context, window_id, position);
+            let previous_target = context.windows.get_mut(&window_id).and_then(|source| {
+                mem::replace(
+                    &mut source.drag_target,
+                    target.map(|(target_id, _)| target_id),
+                )
+            });
+            if let Some(previous_target) = previous_target
+                && target.is_none_or(|(target_id, _)| target_id != previous_target)
+            {
+                leave_drag(context, previous_target);
+            }
+            let Some((target_id, cursor)) = target else {
+                return;
+            };
+            let Some(app) = context.windows.get_mut(&target_id) else {
+                return;
+            };
+            if previous_target != Some(target_id) {
+                app.runner.run_in(|| receive_drag(Some(payload.clone())));
+            }
+            app.position = cursor;
+            app.process_platform_events(vec![PlatformEvent::Mouse {
+                name: MouseEventName::MouseMove,
+                cursor,
+                button: None,
+            }]);
+        });
+    }
+
+    fn drag_returned(&self, _payload: &DragPayload) {
+        let _ = self.platform.post_callback(move |window_id, context| {
+            let previous_target = context
+                .windows
+                .get_mut(&window_id)
+                .and_then(|source| source.drag_target.take());
+            if let Some(previous_target) = previous_target {
+                leave_drag(context, previous_target);
+            }
+        });
+    }
+
+    fn drop_outside(&self, payload: DragPayload, position: CursorPoint) {
+        let _ = self.platform.post_callback(move |window_id, context| {
+            let Some(source) = context.windows.get_mut(&window_id) else {
+                return;
+            };
+            let previous_target = source.drag_target.take();
+            if mem::take(&mut source.os_drag_active) {
+                return;
+            }
+            let target = window_under_cursor(context, window_id, position);
+            if let Some(previous_target) = previous_target
+                && target.is_none_or(|(target_id, _)| target_id != previous_target)
+            {
+                leave_drag(context, previous_target);
+            }
+            let Some((target_id, cursor)) = target else {
+                return;
+            };
+            let Some(app) = context.windows.get_mut(&target_id) else {
+                return;
+            };
+
+            app.runner.run_in(|| receive_drag(Some(payload.clone())));
+            app.position = cursor;
+            let mut platform_events = vec![
+                PlatformEvent::Mouse {
+                    name: MouseEventName::MouseMove,
+                    cursor,
+                    button: None,
+                },
+                PlatformEvent::Mouse {
+                    name: MouseEventName::MouseUp,
+                    cursor,
+                    button: Some(MouseButton::Left),
+                },
+            ];
+            if !payload.files().is_empty() {
+                platform_events.push(PlatformEvent::File {
+                    name: FileEventName::FileDrop,
+                    cursor,
+                    file_paths: payload.files().to_vec(),
+                });
+            }
+            app.process_platform_events(platform_events);
+            clear_received_drag(context, target_id);
+        });
+    }
+}
+
+/// Find the window of the app, other than `source_id`, under `position`, which is relative to
+/// the source window in logical pixels.
+///
+/// winit doesn't expose the stacking order of windows, so when several windows are under the
+/// cursor the drag is not delivered to any of them. Minimized and hidden windows are skipped.
+fn window_under_cursor(
+    context: &RendererContext,
+    source_id: WindowId,
+    position: CursorPoint,
+) -> Option<(WindowId, CursorPoint)> {
+    let source = context.windows.get(&source_id)?;
+    // Not every platform exposes window positions (e.g. Wayland)
+    let origin = source.window.inner_position().ok()?;
+    let scale_factor = source.effective_scale_factor();
+    let screen_x = origin.x as f64 + position.x * scale_factor;
+    let screen_y = origin.y as f64 + position.y * scale_factor;
+
+    let mut targets = context
+        .windows
+        .iter()
+        .filter(|(id, app)| {
+            **id != source_id
+                && app.window.is_visible() != Some(false)
+                && app.window.is_minimized() != Some(true)
+        })
+        .filter_map(|(id, app)| {
+            let origin = app.window.inner_position().ok()?;
+            let size = app.window.inner_size();
+            let cursor = CursorPoint::new(screen_x - origin.x as f64, screen_y - origin.y as f64);
+            let is_inside = cursor.x >= 0.
+                && cursor.y >= 0.
+                && cursor.x < size.width as f64
+                && cursor.y < size.height as f64;
+            is_inside.then_some((*id, cursor))
+        });
+
+    let target = targets.next()?;
+    targets.next().is_none().then_some(target)
+}
+
+/// Move the pointer out of a window a drag was shown over, and clear its payload.
+fn leave_drag(context: &mut RendererContext, target_id: WindowId) {
+    let Some(app) = context.windows.get_mut(&target_id) else {
+        return;
+    };
+    app.position = CursorPoint::from((-1., -1.));
+    app.process_platform_events(vec![PlatformEvent::Mouse {
+        name: MouseEventName::MouseMove,
+        cursor: app.position,
+        button: None,
+    }]);
+    clear_received_drag(context, target_id);
+}
+
+/// Clear the payload received by a window once the events emitted over it are handled.
+fn clear_received_drag(context: &mut RendererContext, target_id: WindowId) {
+    // Queued after the runner poll triggered by the events emitted before,
+    // so the payload is cleared even if no drop zone took it
+    let _ = context
+        .proxy
+        .send_event(NativeEvent::Generic(NativeGenericEvent::RendererCallback(
+            Box::new(move |context: &mut RendererContext| {
+                if let Some(app) = context.windows.get(&target_id) {
+                    app.runner.run_in(|| receive_drag(None));
+                }
+            }),
+        )));
+}
+
+#[cfg(feature = "os-drag")]
+mod os_drag {
+    use std::path::PathBuf;
+
+    use winit::window::Window;
+
+    /// Start an OS drag session of `files` from `window`. Returns whether the OS took over the drag.
+    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    pub(super) fn start(window: &Window, files: Vec<PathBuf>) -> bool {
+        use std::io::Cursor;
+
+        use image::{
+            ImageFormat,
+            RgbaImage,
+        };
+
+        // The OS shows its own file icons, so the drag image is left transparent
+        let mut image = Cursor::new(Vec::new());
+        if let Err(err) = RgbaImage::new(1, 1).write_to(&mut image, ImageFormat::Png) {
+            tracing::error!("Failed to encode the drag image: {err:?}");
+            return false;
+        }
+
+        let result = drag::start_drag(
+            window,
+            drag::DragItem::Files(files),
+            drag::Image::Raw(image.into_inner()),
+            |_, _| {},
+            drag::Options::default(),
+        );
+        if let Err(err) = &result {
+            tracing::error!("Failed to start the OS drag: {err:?}");
+        }
+        result.is_ok()
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    pub(super) fn start(_window: &Window, _files: Vec<PathBuf>) -> bool {
+        false
+    }
+}
@@ -6,6 +6,7 @@ mod software;
 #[cfg(any(target_os = "linux", target_os = "windows"))]
 mod vulkan;
 
+use freya_core::integration::Damage;
 use freya_engine::prelude::Surface as SkiaSurface;
 use winit::{
     dpi::PhysicalSize,
@@ -157,27 +158,33 @@ impl GraphicsDriver {
         Self::Software(driver)
     }
 
+    /// Render and present a frame. `damage` are the areas that changed since the last frame,
+    /// the `render` callback gets the areas that must actually be redrawn, which is everything
+    /// for the drivers that can't keep the last frame.
     pub fn present(
         &mut self,
         size: PhysicalSize<u32>,
         window: &Window,
-        render: impl FnOnce(&mut SkiaSurface),
+        damage: Damage,
+        render: impl FnOnce(&mut SkiaSurface, &mut Damage),
     ) -> Result<(), DriverError> {
         match self {
             #[cfg(any(target_os = "linux", target_os = "windows", target_os = "android"))]
             Self::OpenGl(gl) => {
-                gl.present(window, render);
+                gl.present(window, |surface| render(surface, &mut Damage::full()));
                 Ok(())
             }
             #[cfg(target_os = "macos")]
             Self::Metal(mtl) => {
-                mtl.present(size, window, render);
+                mtl.present(size, window, |surface| render(surface, &mut Damage::full()));
                 Ok(())
             }
             #[cfg(any(target_os = "linux", target_os = "windows"))]
-            Self::Vulkan(vk) => vk.present(size, window, render),
+            Self::Vulkan(vk) => {
+                vk.present(size, window, |surface| render(surface, &mut Damage::full()))
+            }
             Self::Software(sw) => {
-                sw.present(size, window, render);
+                sw.present(size, window, damage, render);
                 Ok(())
             }
         }
@@ -1,11 +1,12 @@
 use std::num::NonZeroU32;
 
+use freya_core::integration::Damage;
 use freya_engine::prelude::{
     AlphaType,
     ColorType,
     ImageInfo,
     Surface as SkiaSurface,
-    wrap_pixels,
+    raster_n32_premul,
 };
 use raw_window_handle::{
     DisplayHandle,
@@ -16,6 +17,7 @@ use raw_window_handle::{
     RawWindowHandle,
     WindowHandle,
 };
+use torin::prelude::Area;
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -44,9 +46,12 @@ impl HasWindowHandle for WindowHandleWrapper {
 }
 
 /// Graphics driver that renders in software via Skia and presents through softbuffer.
+///
+/// The last frame is kept around so only its damaged areas are rendered and presented again.
 pub struct SoftwareDriver {
     _context: softbuffer::Context<DisplayHandleWrapper>,
     surface: softbuffer::Surface<DisplayHandleWrapper, WindowHandleWrapper>,
+    frame: Option<SkiaSurface>,
 }
 
 impl SoftwareDriver {
@@ -81,6 +86,7 @@ impl SoftwareDriver {
         Ok(Self {
             _context: context,
             surface,
+            frame: None,
         })
     }
 
@@ -88,7 +94,8 @@ impl SoftwareDriver {
         &mut self,
         size: PhysicalSize<u32>,
         window: &Window,
-        render: impl FnOnce(&mut SkiaSurface),
+        damage: Damage,
+        render: impl FnOnce(&mut SkiaSurface, &mut Damage),
     ) {
         let (Some(width), Some(height)) =
             (NonZeroU32::new(size.width), NonZeroU32::new(size.height))
@@ -96,6 +103,11 @@ impl SoftwareDriver {
             return;
         };
 
+        let Some(damage) = render_frame(&mut self.frame, width, height, damage, render) else {
+            tracing::error!("Failed to create the software frame surface");
+            return;
+        };
+
         let mut buffer = match self.surface.buffer_mut() {
             Ok(buffer) => buffer,
             Err(err) => {
@@ -104,29 +116,16 @@ impl SoftwareDriver {
             }
         };
 
-        let info = ImageInfo::new(
-            (width.get() as i32, height.get() as i32),
-            ColorType::BGRA8888,
-            AlphaType::Premul,
-            None,
-        );
-        let row_bytes = width.get() as usize * 4;
-        let pixels: &mut [u32] = &mut buffer;
-        // SAFETY: u32 is 4-aligned and we own the buffer for the full borrow.
-        let bytes: &mut [u8] = unsafe {
-            std::slice::from_raw_parts_mut(pixels.as_mut_ptr() as *mut u8, pixels.len() * 4)
-        };
-
-        match wrap_pixels(&info, bytes, Some(row_bytes), None) {
-            Some(mut wrapped_surface) => render(&mut wrapped_surface),
-            None => {
-                tracing::error!("Failed to wrap software pixels into a Skia surface");
-                return;
-            }
+        // The damaged areas can only be copied alone if the buffer still holds the last frame
+        let rects = damaged_rects(&damage, width, height, buffer.age() == 1);
+        let frame = self.frame.as_mut().unwrap();
+        if !copy_frame(frame, &mut buffer, width, &rects) {
+            tracing::error!("Failed to copy the software frame into the buffer");
+            return;
         }
 
         window.pre_present_notify();
-        if let Err(err) = buffer.present() {
+        if let Err(err) = buffer.present_with_damage(&rects) {
             tracing::error!("Failed to present software buffer: {err:?}");
         }
     }
@@ -142,3 +141,169 @@ impl SoftwareDriver {
         }
     }
 }
+
+/// Render the damaged areas of the frame, creating it and rendering everything if there was no
+/// frame of the given size yet. Returns the damage that was rendered.
+fn render_frame(
+    frame: &mut Option<SkiaSurface>,
+    width: NonZeroU32,
+    height: NonZeroU32,
+    mut damage: Damage,
+    render: impl FnOnce(&mut SkiaSurface, &mut Damage),
+) -> Option<Damage> {
+    let (width, height) = (width.get() as i32, height.get() as i32);
+    if frame
+        .as_ref()
+        .is_none_or(|frame| frame.width() != width || frame.height() != height)
+    {
+        damage = Damage::full();
+        *frame = Some(raster_n32_premul((width, height))?);
+    }
+
+    render(frame.as_mut().unwrap(), &mut damage);
+
+    Some(damage)
+}
+
+/// Pixel rects of the buffer that need to be copied from the frame and presented.
+fn damaged_rects(
+    damage: &Damage,
+    width: NonZeroU32,
+    height: NonZeroU32,
+    partial: bool,
+) -> Vec<softbuffer::Rect> {
+    let full = softbuffer::Rect {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    };
+
+    if !partial || damage.is_full() || damage.is_empty() {
+        return vec![full];
+    }
+
+    let bounds = Area::from_size((width.get() as f32, height.get() as f32).into());
+    damage
+        .areas()
+        .iter()
+        .filter_map(|area| {
+            let area = area.round_out().intersection(&bounds)?;
+            Some(softbuffer::Rect {
+                x: area.min_x() as u32,
+                y: area.min_y() as u32,
+                width: NonZeroU32::new(area.width() as u32)?,
+                height: NonZeroU32::new(area.height() as u32)?,
+            })
+        })
+        .collect()
+}
+
+/// Copy the given rects of the frame into the pixels of a buffer with the same size.
+fn copy_frame(
+    frame: &mut SkiaSurface,
+    pixels: &mut [u32],
+    width: NonZeroU32,
+    rects: &[softbuffer::Rect],
+) -> bool {
+    let row_bytes = width.get() as usize * 4;
+    // SAFETY: u32 is 4-aligned and we own the buffer for the full borrow.
+    let bytes: &mut [u8] =
+        unsafe { std::slice::from_raw_parts_mut(pixels.as_mut_ptr() as *mut u8, pixels.len() * 4) };
+
+    rects.iter().all(|rect| {
+        let info = ImageInfo::new(
+            (rect.width.get() as i32, rect.height.get() as i32),
+            ColorType::BGRA8888,
+            AlphaType::Premul,
+            None,
+        );
+        let offset = rect.y as usize * row_bytes + rect.x as usize * 4;
+        frame.read_pixels(
+            &info,
+            &mut bytes[offset..],
+            row_bytes,
+            (rect.x as i32, rect.y as i32),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::NonZeroU32;
+
+    use freya_core::integration::Damage;
+    use freya_engine::prelude::SkColor;
+    use torin::prelude::{
+        Area,
+        Point2D,
+        Size2D,
+    };
+
+    use super::{
+        copy_frame,
+        damaged_rects,
+        render_frame,
+    };
+
+    #[test]
+    fn untouched_pixels_are_not_redrawn() {
+        let size = NonZeroU32::new(100).unwrap();
+        let mut frame = None;
+        let mut pixels = vec![0u32; 100 * 100];
+
+        // The first frame is fully rendered no matter the damage
+        let damage = render_frame(
+            &mut frame,
+            size,
+            size,
+            Damage::default(),
+            |surface, damage| {
+                assert!(damage.is_full());
+                surface.canvas().clear(SkColor::RED);
+            },
+        )
+        .unwrap();
+        let rects = damaged_rects(&damage, size, size, true);
+        assert!(copy_frame(
+            frame.as_mut().unwrap(),
+            &mut pixels,
+            size,
+            &rects
+        ));
+        assert!(pixels.iter().all(|pixel| *pixel == 0xFFFF0000));
+
+        // Pixels the next frame must not touch
+        pixels.fill(0xDEADBEEF);
+
+        let mut damage = Damage::default();
+        damage.invalidate(Area::new(Point2D::new(10., 10.), Size2D::new(20., 20.)));
+        let damage = render_frame(&mut frame, size, size, damage, |surface, damage| {
+            assert_eq!(
+                damage.areas(),
+                &[Area::new(Point2D::new(10., 10.), Size2D::new(20., 20.))]
+            );
+            surface.canvas().clear(SkColor::BLUE);
+        })
+        .unwrap();
+        let rects = damaged_rects(&damage, size, size, true);
+        assert_eq!(rects.len(), 1);
+        assert!(copy_frame(
+            frame.as_mut().unwrap(),
+            &mut pixels,
+            size,
+            &rects
+        ));
+
+        for y in 0..100 {
+            for x in 0..100 {
+                let pixel = pixels[y * 100 + x];
+                if (10..30).contains(&x) && (10..30).contains(&y) {
+                    assert_eq!(pixel, 0xFF0000FF);
+                } else {
+                    assert_eq!(pixel, 0xDEADBEEF);
+                }
+            }
+        }
+    }
+}
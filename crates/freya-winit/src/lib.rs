@@ -17,6 +17,7 @@ use crate::{
 };
 mod accessibility;
 pub mod config;
+mod drag;
 mod drivers;
 pub mod extensions;
 pub mod integration;
@@ -149,6 +150,13 @@ fn launch_inner(mut launch_config: LaunchConfig) {
         gpu_resource_cache_limit: launch_config.gpu_resource_cache_limit,
     };
 
+    #[cfg(feature = "os-drag")]
+    if launch_config.os_drag {
+        renderer
+            .global_contexts
+            .insert_context(crate::drag::OsDragContext);
+    }
+
     #[cfg(feature = "tray")]
     {
         use crate::{
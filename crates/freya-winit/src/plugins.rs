@@ -153,11 +153,13 @@ pub enum PluginEvent<'a> {
     },
 
     /// Before starting to render the app to the Canvas.
+    /// Plugins that draw on top of the app must extend the `damage` with the areas they draw on.
     BeforeRender {
         window: &'a Window,
         canvas: &'a Canvas,
         font_collection: &'a FontCollection,
         tree: &'a Tree,
+        damage: &'a mut Damage,
     },
 
     /// After rendering the app to the Canvas.
@@ -1,12 +1,15 @@
 use std::{
-    borrow::Cow,
     fmt,
     pin::Pin,
     task::Waker,
+    time::Instant,
 };
 
 use accesskit_winit::WindowEvent as AccessibilityWindowEvent;
-use freya_core::integration::*;
+use freya_core::{
+    integration::*,
+    prelude::FallbackFonts,
+};
 use freya_engine::prelude::{
     FontCollection,
     FontMgr,
@@ -43,6 +46,7 @@ use winit::{
     },
     event_loop::{
         ActiveEventLoop,
+        ControlFlow,
         EventLoopProxy,
     },
     window::{
@@ -67,6 +71,7 @@ use crate::{
     window::AppWindow,
     winit_mappings::{
         self,
+        map_winit_gesture_phase,
         map_winit_mouse_button,
         map_winit_touch_force,
         map_winit_touch_phase,
@@ -87,7 +92,7 @@ pub struct WinitRenderer {
     pub global_contexts: GlobalContexts,
     pub proxy: EventLoopProxy<NativeEvent>,
     pub plugins: PluginsManager,
-    pub fallback_fonts: Vec<Cow<'static, str>>,
+    pub fallback_fonts: FallbackFonts,
     pub font_provider: TypefaceFontProvider,
     pub font_manager: FontMgr,
     pub font_collection: FontCollection,
@@ -102,7 +107,7 @@ pub struct RendererContext<'a> {
     pub global_contexts: &'a GlobalContexts,
     pub proxy: &'a mut EventLoopProxy<NativeEvent>,
     pub plugins: &'a mut PluginsManager,
-    pub fallback_fonts: &'a mut Vec<Cow<'static, str>>,
+    pub fallback_fonts: &'a mut FallbackFonts,
     pub font_manager: &'a mut FontMgr,
     pub font_collection: &'a mut FontCollection,
     pub active_event_loop: &'a ActiveEventLoop,
@@ -824,16 +829,19 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             }
                         }
 
+                        let damage = app.tree.take_damage();
                         let present_result = app.driver.present(
                             app.window.inner_size().cast(),
                             &app.window,
-                            |surface| {
+                            damage,
+                            |surface, damage| {
                                 self.plugins.send(
                                     PluginEvent::BeforeRender {
                                         window: &app.window,
                                         canvas: surface.canvas(),
                                         font_collection: &self.font_collection,
                                         tree: &app.tree,
+                                        damage,
                                     },
                                     PluginHandle::new(&self.proxy),
                                 );
@@ -845,6 +853,7 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                     canvas: surface.canvas(),
                                     scale_factor,
                                     background: app.background,
+                                    damage,
                                 };
 
                                 render_pipeline.render();
@@ -1107,6 +1116,31 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                         force: force.map(map_winit_touch_force),
                     }]);
                 }
+                WindowEvent::PinchGesture { delta, phase, .. } => {
+                    let gesture = app.gesture_recognizer.trackpad_pinch(
+                        delta,
+                        map_winit_gesture_phase(phase),
+                        app.position,
+                    );
+                    app.process_platform_events(vec![gesture.into()]);
+                }
+                WindowEvent::RotationGesture { delta, phase, .. } => {
+                    // Winit reports counterclockwise degrees
+                    let gesture = app.gesture_recognizer.trackpad_rotate(
+                        -(delta as f64).to_radians(),
+                        map_winit_gesture_phase(phase),
+                        app.position,
+                    );
+                    app.process_platform_events(vec![gesture.into()]);
+                }
+                WindowEvent::PanGesture { delta, phase, .. } => {
+                    let gesture = app.gesture_recognizer.trackpad_pan(
+                        GestureVector::new(delta.x as f64, delta.y as f64),
+                        map_winit_gesture_phase(phase),
+                        app.position,
+                    );
+                    app.process_platform_events(vec![gesture.into()]);
+                }
                 WindowEvent::Ime(Ime::Commit(text)) => {
                     app.process_platform_events(vec![PlatformEvent::Keyboard {
                         name: KeyboardEventName::KeyDown,
@@ -1166,6 +1200,24 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
             self.windows.insert(window_id, app);
         }
     }
+
+    fn about_to_wait(&mut self, active_event_loop: &ActiveEventLoop) {
+        let now = Instant::now();
+        for app in self.windows.values_mut() {
+            app.process_gestures_deadline(now);
+        }
+
+        // Wake up in time for the pending long presses
+        let deadline = self
+            .windows
+            .values()
+            .filter_map(|app| app.gesture_recognizer.deadline())
+            .min();
+        active_event_loop.set_control_flow(match deadline {
+            Some(deadline) => ControlFlow::WaitUntil(deadline),
+            None => ControlFlow::Wait,
+        });
+    }
 }
 
 fn subscribe_preferences(proxy: EventLoopProxy<NativeEvent>) {
@@ -1,9 +1,9 @@
 use std::{
-    borrow::Cow,
     path::PathBuf,
     rc::Rc,
     sync::Arc,
     task::Waker,
+    time::Instant,
 };
 
 use accesskit_winit::Adapter;
@@ -13,11 +13,16 @@ use freya_clipboard::copypasta::{
 };
 use freya_components::{
     cache::AssetCacher,
+    drag_drop::DragSourceHandle,
     integration::integration,
 };
 use freya_core::{
     integration::*,
-    prelude::Color,
+    prelude::{
+        Color,
+        FallbackFonts,
+        FontRegistry,
+    },
 };
 use freya_engine::prelude::{
     FontCollection,
@@ -67,6 +72,7 @@ use crate::{
         OnCloseHook,
         WindowConfig,
     },
+    drag::WinitDragSource,
     drivers::GraphicsDriver,
     integration::is_ime_role,
     plugins::{
@@ -115,6 +121,12 @@ pub struct AppWindow {
     pub(crate) background: Color,
 
     pub(crate) dropped_file_paths: Vec<PathBuf>,
+    /// Whether the OS took over the last drag that left this window.
+    pub(crate) os_drag_active: bool,
+    /// Window of the app a drag that left this window is shown over.
+    pub(crate) drag_target: Option<WindowId>,
+
+    pub(crate) gesture_recognizer: GestureRecognizer,
 
     pub(crate) on_close: Option<OnCloseHook>,
 
@@ -182,7 +194,7 @@ impl AppWindow {
         plugins: &mut PluginsManager,
         font_collection: &mut FontCollection,
         font_manager: &FontMgr,
-        fallback_fonts: &[Cow<'static, str>],
+        fallback_fonts: &FallbackFonts,
         gpu_resource_cache_limit: usize,
         global_contexts: &GlobalContexts,
     ) -> Self {
@@ -287,6 +299,12 @@ impl AppWindow {
             }
         });
 
+        runner.provide_root_context(|| {
+            DragSourceHandle::new(WinitDragSource {
+                platform: platform.clone(),
+            })
+        });
+
         let clipboard = {
             if let Ok(handle) = window.display_handle() {
                 #[allow(clippy::match_single_binding)]
@@ -321,6 +339,10 @@ impl AppWindow {
 
         runner.provide_root_context(|| font_collection.clone());
 
+        runner.provide_root_context(|| {
+            FontRegistry::new(Some(FontMgr::default()), font_manager.clone())
+        });
+
         plugins.send(
             PluginEvent::RunnerCreated {
                 runner: &mut runner,
@@ -429,6 +451,10 @@ impl AppWindow {
             background: window_config.background,
 
             dropped_file_paths: Vec::new(),
+            os_drag_active: false,
+            drag_target: None,
+
+            gesture_recognizer: GestureRecognizer::default(),
 
             on_close,
 
@@ -464,7 +490,13 @@ impl AppWindow {
 
     /// Measures the given platform events and emits the results.
     /// Wheel events schedule a mouse move to refresh hover states.
-    pub(crate) fn process_platform_events(&mut self, mut platform_events: Vec<PlatformEvent>) {
+    pub(crate) fn process_platform_events(&mut self, platform_events: Vec<PlatformEvent>) {
+        let mut platform_events = recognize_gestures(
+            &mut self.gesture_recognizer,
+            platform_events,
+            Instant::now(),
+        );
+
         if platform_events
             .iter()
             .any(|platform_event| matches!(platform_event, PlatformEvent::Wheel { .. }))
@@ -472,6 +504,14 @@ impl AppWindow {
             self.send_mouse_move_on_next_layout = true;
         }
 
+        // Shortcuts go first and the key downs they use never reach the elements
+        let focused_node_id = self.accessibility.focused_node_id();
+        platform_events.retain(|platform_event| {
+            !self
+                .runner
+                .handle_shortcut(platform_event, &self.tree, focused_node_id)
+        });
+
         let mut events_measurer_adapter = EventsMeasurerAdapter {
             scale_factor: self.effective_scale_factor(),
             tree: &mut self.tree,
@@ -479,13 +519,21 @@ impl AppWindow {
         let processed_events = events_measurer_adapter.run(
             &mut platform_events,
             &mut self.nodes_state,
-            self.accessibility.focused_node_id(),
+            focused_node_id,
         );
         self.events_sender
             .unbounded_send(EventsChunk::Processed(processed_events))
             .unwrap();
     }
 
+    /// Emit the gestures that only depend on time passing, such as long presses.
+    pub(crate) fn process_gestures_deadline(&mut self, now: Instant) {
+        let gestures = self.gesture_recognizer.tick(now);
+        if !gestures.is_empty() {
+            self.process_platform_events(gestures.into_iter().map(PlatformEvent::from).collect());
+        }
+    }
+
     /// Sets the custom scale factor, clamped to a reasonable range.
     pub fn set_custom_scale_factor(&mut self, custom_scale_factor: f64) {
         let clamped = clamp_custom_scale_factor(custom_scale_factor);
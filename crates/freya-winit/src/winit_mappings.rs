@@ -1,5 +1,6 @@
 use freya_core::prelude::{
     Force,
+    GesturePhase,
     MouseButton,
     TouchPhase,
 };
@@ -369,6 +370,15 @@ pub fn map_winit_touch_phase(event: winit::event::TouchPhase) -> TouchPhase {
     }
 }
 
+pub fn map_winit_gesture_phase(event: winit::event::TouchPhase) -> GesturePhase {
+    match event {
+        winit::event::TouchPhase::Started => GesturePhase::Started,
+        winit::event::TouchPhase::Moved => GesturePhase::Changed,
+        winit::event::TouchPhase::Ended => GesturePhase::Ended,
+        winit::event::TouchPhase::Cancelled => GesturePhase::Cancelled,
+    }
+}
+
 pub fn map_winit_touch_force(event: winit::event::Force) -> Force {
     match event {
         winit::event::Force::Calibrated {
@@ -147,6 +147,35 @@
 //! `stop_propagation` has no effect on them. A non-global handler that calls
 //! `prevent_default` will, however, suppress the matching global event for that dispatch.
 //!
+//! ## Pointer capture
+//!
+//! To keep tracking a drag once the pointer leaves your element, capture the pointer from its
+//! `on_pointer_down` handler with [`Event::capture_pointer`](crate::prelude::Event::capture_pointer).
+//! Until the pointer is lifted, all its events are sent only to the capturing element, with
+//! element locations relative to it, and no other element sees them. Global events, like
+//! `on_global_pointer_move`, are the exception: they keep reaching all their listeners while the
+//! pointer is captured. Once the capture ends, either by releasing the pointer, by calling
+//! `release_pointer_capture` or because another element captured it, the element receives an
+//! `on_lost_pointer_capture` event.
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! # fn app() -> impl IntoElement {
+//! let mut dragging = use_state(|| false);
+//! rect()
+//!     .on_pointer_down(move |e: Event<PointerEventData>| {
+//!         e.capture_pointer(e.pointer_id());
+//!         dragging.set(true);
+//!     })
+//!     .on_pointer_move(move |e: Event<PointerEventData>| {
+//!         if dragging() {
+//!             println!("Dragging at {:?}", e.element_location());
+//!         }
+//!     })
+//!     .on_lost_pointer_capture(move |_| dragging.set(false))
+//! # }
+//! ```
+//!
 //! ## Components don't have events
 //!
 //! Components are just data and a `render` method. To expose a "click" or "change" hook from
@@ -72,6 +72,7 @@
 //! - `i18n`: Reexport [freya_i18n] under [i18n]
 //! - `remote-asset`: Enables support for **HTTP** asset sources for [ImageViewer](components::ImageViewer) and [GifViewer](components::GifViewer) components.
 //! - `tray`: Enables tray support using the [tray_icon] crate.
+//! - `os-drag`: Enables dragging files out of the app on Windows and macOS, see `LaunchConfig::with_os_drag`.
 //! - `sdk`: Reexport [freya_sdk] under [sdk].
 //! - `sdk-tokio`: Enables the Tokio utilities from [freya_sdk]. Implies `sdk`.
 //! - `gif`: Enables the [GifViewer](components::GifViewer) component.
@@ -97,6 +98,7 @@
 //! - `hotpath`: Enables Freya's internal usage of hotpath.
 //! - `hotreload`: Enables hot reload support via the `dx` CLI from `dioxus-cli`. See [Hot Reload](self::_docs::hot_reload).
 //! - `zoom-shortcuts`: Enables `Ctrl`/`Cmd` + `+`/`-`/`0` to zoom the app.
+//! - `hyphenation`: Embeds the hyphenation patterns used by [Hyphens::Auto](prelude::Hyphens::Auto).
 
 /// Common imports to build Freya apps, use it with `use freya::prelude::*;`.
 pub mod prelude {
@@ -151,6 +153,11 @@ pub mod prelude {
             CursorPoint,
             Size2D,
         },
+        grid::{
+            GridPlacement,
+            GridSize,
+        },
+        layout_direction::LayoutDirection,
         position::Position,
         size::Size,
         visible_size::VisibleSize,
@@ -223,6 +230,7 @@ pub mod components {
         segmented_button::*,
         select::*,
         selectable_text::*,
+        selection_scope::*,
         sidebar::*,
         skeleton::*,
         slider::*,
@@ -4,6 +4,8 @@ use crate::{
     NodeKey,
     NodesState,
     NodesStatesUpdate,
+    PointerCaptureRequest,
+    PointerId,
     PotentialEvent,
     SourceEvent,
 };
@@ -44,6 +46,14 @@ where
     /// Call the event handler of the given [Self::Emmitable].
     fn emit_event(&mut self, event: Self::Emmitable) -> bool;
 
+    /// The pointer captures requested by the event handlers since the last call.
+    fn take_pointer_capture_requests(&mut self) -> Vec<PointerCaptureRequest<Self::Key>> {
+        Vec::new()
+    }
+
+    /// Notify the given node that it no longer captures `pointer`.
+    fn emit_lost_pointer_capture(&mut self, _key: Self::Key, _pointer: PointerId) {}
+
     // All events have been emitted
     fn emitted_events(&mut self) {}
 }
@@ -74,6 +84,10 @@ impl<T: EventsExecutor + private::Sealed> EventsExecutorRunner for T {
 
             let default_action_enabled = self.emit_event(emmitable_event.clone());
 
+            for request in self.take_pointer_capture_requests() {
+                nodes_state.apply_pointer_capture_request(request);
+            }
+
             if !default_action_enabled {
                 // Get the events that this event can cancel
                 let cancellable_events = emmitable_event.name().get_cancellable_events();
@@ -104,6 +118,20 @@ impl<T: EventsExecutor + private::Sealed> EventsExecutorRunner for T {
             processed_events.push(emmitable_event);
         }
 
+        // Handlers of the lost capture might capture again
+        loop {
+            let lost_pointer_captures = nodes_state.take_lost_pointer_captures();
+            if lost_pointer_captures.is_empty() {
+                break;
+            }
+            for (key, pointer) in lost_pointer_captures {
+                self.emit_lost_pointer_capture(key, pointer);
+                for request in self.take_pointer_capture_requests() {
+                    nodes_state.apply_pointer_capture_request(request);
+                }
+            }
+        }
+
         self.emitted_events();
 
         nodes_state.apply_update(nodes_states_update);
@@ -0,0 +1,518 @@
+//! Recognition of gestures such as pinching, rotating or swiping, derived from the touch points
+//! and trackpad gestures reported by the platform.
+
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use crate::CursorPoint;
+
+pub type GestureVector = euclid::Vector2D<f64, ()>;
+
+/// Stage of a continuous gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GesturePhase {
+    Started,
+    Changed,
+    Ended,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl SwipeDirection {
+    /// Direction of the dominant axis of `velocity`.
+    pub fn of(velocity: GestureVector) -> Self {
+        if velocity.x.abs() >= velocity.y.abs() {
+            if velocity.x < 0. {
+                Self::Left
+            } else {
+                Self::Right
+            }
+        } else if velocity.y < 0. {
+            Self::Up
+        } else {
+            Self::Down
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// The fingers moved together. `delta` is the movement since the last pan event and
+    /// `translation` the movement since the fingers went down.
+    Pan {
+        phase: GesturePhase,
+        delta: GestureVector,
+        translation: GestureVector,
+    },
+    /// Two fingers moved closer or apart. `scale` is their distance relative to when they went
+    /// down and `delta` the factor by which it changed since the last pinch event.
+    Pinch {
+        phase: GesturePhase,
+        delta: f64,
+        scale: f64,
+    },
+    /// Two fingers turned around each other. `angle` is the rotation in radians since they went
+    /// down and `delta` the rotation since the last rotate event, positive when clockwise.
+    Rotate {
+        phase: GesturePhase,
+        delta: f64,
+        angle: f64,
+    },
+    /// A finger was held down without moving for [GestureConfig::long_press_duration].
+    LongPress,
+    /// The fingers were lifted while moving fast, `velocity` is in pixels per second.
+    Swipe {
+        direction: SwipeDirection,
+        velocity: GestureVector,
+    },
+}
+
+/// A recognized [Gesture] and where it happened, the centroid of the fingers involved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureEvent {
+    pub gesture: Gesture,
+    pub location: CursorPoint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureConfig {
+    /// How long a finger has to be held down for a long press.
+    pub long_press_duration: Duration,
+    /// How far, in pixels, the fingers have to move for a pan, pinch or rotation to start.
+    /// Moving further also cancels a long press.
+    pub touch_slop: f64,
+    /// Minimum speed, in pixels per second, of the fingers when lifted for a swipe.
+    pub swipe_velocity: f64,
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self {
+            long_press_duration: Duration::from_millis(500),
+            touch_slop: 8.,
+            swipe_velocity: 300.,
+        }
+    }
+}
+
+/// Time window of the movement used to measure the velocity of a swipe.
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy)]
+struct TouchPoint {
+    id: u64,
+    location: CursorPoint,
+}
+
+/// Two fingers being pinched or rotated.
+#[derive(Debug, Clone, Copy)]
+struct FingersPair {
+    ids: (u64, u64),
+    initial_distance: f64,
+    initial_angle: f64,
+    scale: f64,
+    angle: f64,
+    pinching: bool,
+    rotating: bool,
+}
+
+/// Derives [GestureEvent]s from the touch points of the platform. Every method returns the
+/// gestures recognized with that input, in the order they should be emitted.
+///
+/// A long press happens without new input, so check [GestureRecognizer::deadline] and call
+/// [GestureRecognizer::tick] once it's reached.
+#[derive(Debug, Clone, Default)]
+pub struct GestureRecognizer {
+    config: GestureConfig,
+    touches: Vec<TouchPoint>,
+    /// Centroid of the touches after the last input, to measure the pan deltas.
+    centroid: CursorPoint,
+    translation: GestureVector,
+    panning: bool,
+    pair: Option<FingersPair>,
+    long_press_deadline: Option<Instant>,
+    long_pressed: bool,
+    /// Recent centroids, to measure the velocity of a swipe.
+    samples: Vec<(Instant, CursorPoint)>,
+    trackpad_scale: f64,
+    trackpad_angle: f64,
+    trackpad_translation: GestureVector,
+}
+
+impl GestureRecognizer {
+    pub fn new(config: GestureConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    pub fn config(&self) -> &GestureConfig {
+        &self.config
+    }
+
+    /// When [GestureRecognizer::tick] has to be called for a pending long press, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.long_press_deadline
+    }
+
+    /// Recognize the gestures that depend on time passing, i.e. long presses.
+    pub fn tick(&mut self, now: Instant) -> Vec<GestureEvent> {
+        match self.long_press_deadline {
+            Some(deadline) if deadline <= now => {
+                self.long_press_deadline = None;
+                self.long_pressed = true;
+                vec![GestureEvent {
+                    gesture: Gesture::LongPress,
+                    location: self.centroid,
+                }]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn touch_start(
+        &mut self,
+        id: u64,
+        location: CursorPoint,
+        now: Instant,
+    ) -> Vec<GestureEvent> {
+        let events = self.tick(now);
+
+        self.touches.retain(|touch| touch.id != id);
+        self.touches.push(TouchPoint { id, location });
+
+        if self.touches.len() == 1 {
+            self.translation = GestureVector::zero();
+            self.long_pressed = false;
+            self.long_press_deadline = Some(now + self.config.long_press_duration);
+        } else {
+            // Long presses are for a single finger
+            self.long_press_deadline = None;
+        }
+
+        if self.touches.len() == 2 {
+            let (a, b) = (self.touches[0], self.touches[1]);
+            let distance = b.location - a.location;
+            self.pair = Some(FingersPair {
+                ids: (a.id, b.id),
+                initial_distance: distance.length(),
+                initial_angle: distance.y.atan2(distance.x),
+                scale: 1.,
+                angle: 0.,
+                pinching: false,
+                rotating: false,
+            });
+        }
+
+        self.rebase(now);
+        events
+    }
+
+    pub fn touch_move(
+        &mut self,
+        id: u64,
+        location: CursorPoint,
+        now: Instant,
+    ) -> Vec<GestureEvent> {
+        let mut events = self.tick(now);
+
+        let Some(touch) = self
+            .touches
+            .iter_mut()
+            .find(|touch| touch.id == id && touch.location != location)
+        else {
+            return events;
+        };
+        touch.location = location;
+
+        let centroid = self.touches_centroid();
+        let delta = centroid - self.centroid;
+        self.centroid = centroid;
+        self.translation += delta;
+        self.samples.push((now, centroid));
+        self.samples
+            .retain(|(time, _)| now.duration_since(*time) <= VELOCITY_WINDOW);
+
+        let slop = self.config.touch_slop;
+        if self.translation.length() > slop {
+            self.long_press_deadline = None;
+        }
+
+        if self.panning {
+            events.push(self.event(Gesture::Pan {
+                phase: GesturePhase::Changed,
+                delta,
+                translation: self.translation,
+            }));
+        } else if self.translation.length() > slop {
+            self.panning = true;
+            events.push(self.event(Gesture::Pan {
+                phase: GesturePhase::Started,
+                delta: self.translation,
+                translation: self.translation,
+            }));
+        }
+
+        let pair_locations = self
+            .pair
+            .and_then(|pair| Some((self.location_of(pair.ids.0)?, self.location_of(pair.ids.1)?)));
+        if let (Some(pair), Some((a, b))) = (&mut self.pair, pair_locations) {
+            let distance = b - a;
+            let length = distance.length();
+
+            let scale = if pair.initial_distance > 0. {
+                length / pair.initial_distance
+            } else {
+                1.
+            };
+            let scale_delta = scale / pair.scale;
+            pair.scale = scale;
+
+            let angle = normalize_angle(distance.y.atan2(distance.x) - pair.initial_angle);
+            let angle_delta = normalize_angle(angle - pair.angle);
+            pair.angle = angle;
+
+            let pinch_phase = if pair.pinching {
+                Some(GesturePhase::Changed)
+            } else if (length - pair.initial_distance).abs() > slop {
+                pair.pinching = true;
+                Some(GesturePhase::Started)
+            } else {
+                None
+            };
+            // Measured as the distance the fingers travelled around their center
+            let rotate_phase = if pair.rotating {
+                Some(GesturePhase::Changed)
+            } else if (angle * length / 2.).abs() > slop {
+                pair.rotating = true;
+                Some(GesturePhase::Started)
+            } else {
+                None
+            };
+
+            let pair = *pair;
+            if let Some(phase) = pinch_phase {
+                events.push(self.event(Gesture::Pinch {
+                    phase,
+                    delta: if phase == GesturePhase::Started {
+                        pair.scale
+                    } else {
+                        scale_delta
+                    },
+                    scale: pair.scale,
+                }));
+            }
+            if let Some(phase) = rotate_phase {
+                events.push(self.event(Gesture::Rotate {
+                    phase,
+                    delta: if phase == GesturePhase::Started {
+                        pair.angle
+                    } else {
+                        angle_delta
+                    },
+                    angle: pair.angle,
+                }));
+            }
+        }
+
+        events
+    }
+
+    pub fn touch_end(&mut self, id: u64, location: CursorPoint, now: Instant) -> Vec<GestureEvent> {
+        let mut events = self.touch_move(id, location, now);
+        self.lift(id, GesturePhase::Ended, now, &mut events);
+        events
+    }
+
+    pub fn touch_cancel(&mut self, id: u64, now: Instant) -> Vec<GestureEvent> {
+        let mut events = self.tick(now);
+        self.lift(id, GesturePhase::Cancelled, now, &mut events);
+        events
+    }
+
+    /// Trackpads recognize pinches themselves and report the change of scale since their last event.
+    pub fn trackpad_pinch(
+        &mut self,
+        delta: f64,
+        phase: GesturePhase,
+        location: CursorPoint,
+    ) -> GestureEvent {
+        if phase == GesturePhase::Started {
+            self.trackpad_scale = 1.;
+        }
+        let delta = 1. + delta;
+        self.trackpad_scale *= delta;
+        GestureEvent {
+            gesture: Gesture::Pinch {
+                phase,
+                delta,
+                scale: self.trackpad_scale,
+            },
+            location,
+        }
+    }
+
+    /// Trackpads recognize rotations themselves and report the rotation since their last event,
+    /// in radians and positive when clockwise.
+    pub fn trackpad_rotate(
+        &mut self,
+        delta: f64,
+        phase: GesturePhase,
+        location: CursorPoint,
+    ) -> GestureEvent {
+        if phase == GesturePhase::Started {
+            self.trackpad_angle = 0.;
+        }
+        self.trackpad_angle += delta;
+        GestureEvent {
+            gesture: Gesture::Rotate {
+                phase,
+                delta,
+                angle: self.trackpad_angle,
+            },
+            location,
+        }
+    }
+
+    /// Trackpads recognize pans themselves and report the movement since their last event.
+    pub fn trackpad_pan(
+        &mut self,
+        delta: GestureVector,
+        phase: GesturePhase,
+        location: CursorPoint,
+    ) -> GestureEvent {
+        if phase == GesturePhase::Started {
+            self.trackpad_translation = GestureVector::zero();
+        }
+        self.trackpad_translation += delta;
+        GestureEvent {
+            gesture: Gesture::Pan {
+                phase,
+                delta,
+                translation: self.trackpad_translation,
+            },
+            location,
+        }
+    }
+
+    fn lift(&mut self, id: u64, phase: GesturePhase, now: Instant, events: &mut Vec<GestureEvent>) {
+        if !self.touches.iter().any(|touch| touch.id == id) {
+            return;
+        }
+
+        // End the pinch and rotation once one of their fingers is lifted
+        if let Some(pair) = self.pair
+            && (pair.ids.0 == id || pair.ids.1 == id)
+        {
+            self.pair = None;
+            if pair.pinching {
+                events.push(self.event(Gesture::Pinch {
+                    phase,
+                    delta: 1.,
+                    scale: pair.scale,
+                }));
+            }
+            if pair.rotating {
+                events.push(self.event(Gesture::Rotate {
+                    phase,
+                    delta: 0.,
+                    angle: pair.angle,
+                }));
+            }
+        }
+
+        if self.touches.len() == 1 {
+            self.long_press_deadline = None;
+
+            if self.panning {
+                self.panning = false;
+                events.push(self.event(Gesture::Pan {
+                    phase,
+                    delta: GestureVector::zero(),
+                    translation: self.translation,
+                }));
+            }
+
+            let velocity = self.velocity();
+            if phase == GesturePhase::Ended
+                && !self.long_pressed
+                && self.translation.length() > self.config.touch_slop
+                && velocity.length() >= self.config.swipe_velocity
+            {
+                events.push(self.event(Gesture::Swipe {
+                    direction: SwipeDirection::of(velocity),
+                    velocity,
+                }));
+            }
+        }
+
+        self.touches.retain(|touch| touch.id != id);
+        self.rebase(now);
+    }
+
+    /// Restart measuring the movement from the current fingers, so that adding or lifting one
+    /// doesn't make the centroid jump.
+    fn rebase(&mut self, now: Instant) {
+        self.centroid = self.touches_centroid();
+        self.samples.clear();
+        self.samples.push((now, self.centroid));
+    }
+
+    fn velocity(&self) -> GestureVector {
+        match (self.samples.first(), self.samples.last()) {
+            (Some((start_time, start)), Some((end_time, end))) => {
+                let elapsed = end_time.duration_since(*start_time).as_secs_f64();
+                if elapsed > 0. {
+                    (*end - *start) / elapsed
+                } else {
+                    GestureVector::zero()
+                }
+            }
+            _ => GestureVector::zero(),
+        }
+    }
+
+    fn touches_centroid(&self) -> CursorPoint {
+        if self.touches.is_empty() {
+            return self.centroid;
+        }
+        let sum = self
+            .touches
+            .iter()
+            .fold(GestureVector::zero(), |sum, touch| {
+                sum + touch.location.to_vector()
+            });
+        (sum / self.touches.len() as f64).to_point()
+    }
+
+    fn location_of(&self, id: u64) -> Option<CursorPoint> {
+        self.touches
+            .iter()
+            .find(|touch| touch.id == id)
+            .map(|touch| touch.location)
+    }
+
+    fn event(&self, gesture: Gesture) -> GestureEvent {
+        GestureEvent {
+            gesture,
+            location: self.centroid,
+        }
+    }
+}
+
+/// Wraps an angle in radians to `(-π, π]`.
+fn normalize_angle(angle: f64) -> f64 {
+    use std::f64::consts::PI;
+    let angle = angle.rem_euclid(2. * PI);
+    if angle > PI { angle - 2. * PI } else { angle }
+}
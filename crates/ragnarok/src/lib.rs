@@ -8,21 +8,25 @@
 
 pub mod emmitable;
 pub mod executor;
+pub mod gesture;
 pub mod key;
 pub mod measurement;
 pub mod measurer;
 pub mod name;
 pub mod nodes_state;
+pub mod pointer_capture;
 pub mod potential_event;
 pub mod source;
 
 pub use emmitable::*;
 pub use executor::*;
+pub use gesture::*;
 pub use key::*;
 pub(crate) use measurement::*;
 pub use measurer::*;
 pub use name::*;
 pub use nodes_state::*;
+pub use pointer_capture::*;
 pub(crate) use potential_event::*;
 pub use source::*;
 
@@ -5,6 +5,7 @@ use crate::{
     EventsMeasurer,
     NameOfEvent,
     NodeKey,
+    NodesState,
     PotentialEvent,
     PotentialEvents,
     SourceEvent,
@@ -186,3 +187,87 @@ pub fn measure_emmitable_events<
 
     emmitable_events
 }
+
+/// Emit the events of captured pointers straight to the node capturing them, skipping the hit testing.
+/// Returns the source events of the pointers that aren't captured, which are measured as usual.
+/// The global events of captured pointers still reach all their listeners, see [measure_source_global_events].
+pub fn measure_captured_events<
+    Key: NodeKey,
+    Name: NameOfEvent,
+    Source: SourceEvent<Name = Name>,
+    Emmitable: EmmitableEvent<Key = Key, Name = Name>,
+>(
+    source_events: &[Source],
+    events_measurer: &impl EventsMeasurer<
+        Key = Key,
+        Name = Name,
+        Emmitable = Emmitable,
+        Source = Source,
+    >,
+    nodes_state: &mut NodesState<Key>,
+    emmitable_events: &mut Vec<Emmitable>,
+) -> Vec<Source> {
+    let mut uncaptured_events = Vec::with_capacity(source_events.len());
+
+    for source_event in source_events {
+        let Some(pointer) = source_event.try_pointer_id() else {
+            uncaptured_events.push(source_event.clone());
+            continue;
+        };
+
+        // The nodes that lost the capture of this pointer weren't retaining their states
+        emmitable_events.extend(nodes_state.retain_uncaptured_states(
+            events_measurer,
+            pointer,
+            source_event,
+        ));
+
+        let Some(key) = nodes_state.pointer_capture(pointer) else {
+            uncaptured_events.push(source_event.clone());
+            continue;
+        };
+
+        let Some(area) = events_measurer.try_area_of(&key) else {
+            // The capturing node no longer exists
+            nodes_state.release_pointer_capture(pointer, key);
+            emmitable_events.extend(nodes_state.retain_uncaptured_states(
+                events_measurer,
+                pointer,
+                source_event,
+            ));
+            uncaptured_events.push(source_event.clone());
+            continue;
+        };
+
+        for derived_event_name in source_event.as_event_name().get_derived_events() {
+            // Hovering is paused while captured, and releases only reach pressed nodes. Global
+            // events are measured apart, for all their listeners, so they aren't routed here
+            if derived_event_name.is_enter()
+                || derived_event_name.is_global()
+                || (derived_event_name.is_released() && !nodes_state.is_pressed(key))
+            {
+                continue;
+            }
+
+            if events_measurer.is_listening_to(&key, &derived_event_name) {
+                emmitable_events.push(events_measurer.new_emmitable_event(
+                    key,
+                    derived_event_name,
+                    source_event.clone(),
+                    Some(area),
+                ));
+            }
+        }
+
+        if source_event.is_pointer_released() {
+            nodes_state.release_pointer_capture(pointer, key);
+            emmitable_events.extend(nodes_state.retain_uncaptured_states(
+                events_measurer,
+                pointer,
+                source_event,
+            ));
+        }
+    }
+
+    uncaptured_events
+}
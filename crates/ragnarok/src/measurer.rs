@@ -9,6 +9,7 @@ use crate::{
     NodesState,
     ProcessedEvents,
     SourceEvent,
+    measure_captured_events,
     measure_emmitable_events,
     measure_potential_events,
     measure_source_global_events,
@@ -58,13 +59,23 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
         nodes_state: &mut NodesState<Self::Key>,
         focus_id: Option<Self::Key>,
     ) -> ProcessedEvents<Self::Key, Self::Name, Self::Emmitable, Self::Source> {
+        // Route the events of captured pointers to the nodes capturing them
+        let mut captured_emmitable_events = Vec::new();
+        let uncaptured_source_events =
+            measure_captured_events::<Self::Key, Self::Name, Self::Source, Self::Emmitable>(
+                source_events,
+                self,
+                nodes_state,
+                &mut captured_emmitable_events,
+            );
+
         // Get potential events that could be emitted based on the elements layout and viewports
         let potential_events = measure_potential_events::<
             Self::Key,
             Self::Name,
             Self::Source,
             Self::Emmitable,
-        >(source_events, self, focus_id);
+        >(&uncaptured_source_events, self, focus_id);
 
         // Get what events can be actually emitted based on what elements are listening
         let mut emmitable_events =
@@ -75,7 +86,7 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
 
         // Get potential collateral events, e.g. mousemove -> mouseenter
         let collateral_emmitable_events =
-            nodes_state.retain_states(self, &emmitable_events, source_events);
+            nodes_state.retain_states(self, &emmitable_events, &uncaptured_source_events);
         nodes_state.filter_emmitable_events::<Self::Emmitable, Self::Name>(&mut emmitable_events);
         let nodes_states_update =
             nodes_state.create_update::<Self::Name, Self::Source>(self, &potential_events);
@@ -88,6 +99,7 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
         );
         // Join all the emmitable events and sort them
         emmitable_events.extend(collateral_emmitable_events);
+        emmitable_events.extend(captured_emmitable_events);
         emmitable_events.sort_unstable();
 
         let mut flattened_potential_events = potential_events.into_values().flatten().collect_vec();
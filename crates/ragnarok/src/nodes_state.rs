@@ -8,6 +8,8 @@ use crate::{
     EventsMeasurer,
     NameOfEvent,
     NodeKey,
+    PointerCaptureRequest,
+    PointerId,
     PotentialEvent,
     SourceEvent,
 };
@@ -17,6 +19,9 @@ pub struct NodesState<Key: NodeKey> {
     pressed_nodes: FxHashSet<Key>,
     hovered_nodes: FxHashSet<Key>,
     entered_node: Option<Key>,
+    pointer_captures: FxHashMap<PointerId, Key>,
+    lost_pointer_captures: Vec<(Key, PointerId)>,
+    uncaptured_nodes: Vec<(Key, PointerId)>,
 }
 
 impl<Key: NodeKey> Default for NodesState<Key> {
@@ -25,6 +30,9 @@ impl<Key: NodeKey> Default for NodesState<Key> {
             pressed_nodes: FxHashSet::default(),
             hovered_nodes: FxHashSet::default(),
             entered_node: None,
+            pointer_captures: FxHashMap::default(),
+            lost_pointer_captures: Vec::default(),
+            uncaptured_nodes: Vec::default(),
         }
     }
 }
@@ -141,6 +149,83 @@ impl<Key: NodeKey> NodesState<Key> {
         collateral_emmitable_events
     }
 
+    /// Retain the states of the nodes that lost the capture of `pointer`, given its next event.
+    /// Their states weren't retained while captured, so they might still be pressed or hovered
+    /// even if the pointer is no longer on them.
+    pub(crate) fn retain_uncaptured_states<
+        Emmitable: EmmitableEvent<Key = Key, Name = Name>,
+        Name: NameOfEvent,
+        Source: SourceEvent,
+    >(
+        &mut self,
+        events_measurer: &impl EventsMeasurer<
+            Key = Key,
+            Name = Name,
+            Emmitable = Emmitable,
+            Source = Source,
+        >,
+        pointer: PointerId,
+        source_event: &Source,
+    ) -> Vec<Emmitable> {
+        let mut collateral_emmitable_events = Vec::default();
+
+        let capture = self.pointer_capture(pointer);
+        let uncaptured_nodes = std::mem::take(&mut self.uncaptured_nodes);
+
+        for (key, uncaptured_pointer) in uncaptured_nodes {
+            if uncaptured_pointer != pointer {
+                self.uncaptured_nodes.push((key, uncaptured_pointer));
+                continue;
+            }
+
+            // Capturing it again, so its states are retained once the capture is lost again
+            if capture == Some(key) {
+                continue;
+            }
+
+            let area = events_measurer.try_area_of(&key);
+            let cursor_still_inside = area.is_some()
+                && source_event
+                    .try_location()
+                    .is_none_or(|cursor| events_measurer.is_point_inside(&key, cursor));
+
+            if !cursor_still_inside && self.hovered_nodes.remove(&key) {
+                if let Some(area) = area {
+                    for derived_event in Name::new_leave().get_derived_events() {
+                        if events_measurer.is_listening_to(&key, &derived_event) {
+                            collateral_emmitable_events.push(events_measurer.new_emmitable_event(
+                                key,
+                                derived_event,
+                                source_event.clone(),
+                                Some(area),
+                            ));
+                        }
+                    }
+                }
+
+                if self.entered_node == Some(key) {
+                    self.entered_node = None;
+                }
+
+                #[cfg(debug_assertions)]
+                tracing::info!("Unmarked as hovered {:?}", key);
+            }
+
+            if source_event.is_pointer_released() {
+                // Released somewhere else, so it's no longer pressed
+                if !cursor_still_inside && self.pressed_nodes.remove(&key) {
+                    #[cfg(debug_assertions)]
+                    tracing::info!("Unmarked as pressed {:?}", key);
+                }
+            } else {
+                // Keep retaining them until the pointer is released
+                self.uncaptured_nodes.push((key, pointer));
+            }
+        }
+
+        collateral_emmitable_events
+    }
+
     pub(crate) fn filter_emmitable_events<
         Emmitable: EmmitableEvent<Key = Key, Name = Name>,
         Name: NameOfEvent,
@@ -233,6 +318,44 @@ impl<Key: NodeKey> NodesState<Key> {
     pub fn is_pressed(&self, key: Key) -> bool {
         self.pressed_nodes.contains(&key)
     }
+
+    /// The node capturing the given pointer, if any.
+    pub fn pointer_capture(&self, pointer: PointerId) -> Option<Key> {
+        self.pointer_captures.get(&pointer).copied()
+    }
+
+    /// Route the events of `pointer` to `key`. The node capturing it until now loses the capture.
+    pub fn capture_pointer(&mut self, pointer: PointerId, key: Key) {
+        if let Some(previous_key) = self.pointer_captures.insert(pointer, key)
+            && previous_key != key
+        {
+            self.lost_pointer_captures.push((previous_key, pointer));
+            self.uncaptured_nodes.push((previous_key, pointer));
+        }
+    }
+
+    /// Stop routing the events of `pointer` to `key`, if it's capturing it.
+    pub fn release_pointer_capture(&mut self, pointer: PointerId, key: Key) {
+        if self.pointer_capture(pointer) == Some(key) {
+            self.pointer_captures.remove(&pointer);
+            self.lost_pointer_captures.push((key, pointer));
+            self.uncaptured_nodes.push((key, pointer));
+        }
+    }
+
+    pub fn apply_pointer_capture_request(&mut self, request: PointerCaptureRequest<Key>) {
+        match request {
+            PointerCaptureRequest::Capture { key, pointer } => self.capture_pointer(pointer, key),
+            PointerCaptureRequest::Release { key, pointer } => {
+                self.release_pointer_capture(pointer, key)
+            }
+        }
+    }
+
+    /// The nodes that lost the capture of a pointer since the last call.
+    pub(crate) fn take_lost_pointer_captures(&mut self) -> Vec<(Key, PointerId)> {
+        std::mem::take(&mut self.lost_pointer_captures)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -0,0 +1,16 @@
+/// Identifies a pointer device, so its events can be captured by a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerId {
+    Mouse,
+    /// A finger, by its touch id.
+    Touch(u64),
+}
+
+/// Requested by the event handlers to change what node captures a pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerCaptureRequest<Key> {
+    /// Route all the subsequent events of `pointer` to `key`, until the pointer is released.
+    Capture { key: Key, pointer: PointerId },
+    /// Stop routing the events of `pointer` to `key`, if it's capturing it.
+    Release { key: Key, pointer: PointerId },
+}
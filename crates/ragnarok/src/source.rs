@@ -1,6 +1,7 @@
 use crate::{
     CursorPoint,
     NameOfEvent,
+    PointerId,
 };
 
 pub trait SourceEvent: Clone + PartialEq {
@@ -12,5 +13,15 @@ pub trait SourceEvent: Clone + PartialEq {
 
     fn try_location(&self) -> Option<CursorPoint>;
 
+    /// The pointer this event comes from, if any. Events of a captured pointer are routed to the capturing node.
+    fn try_pointer_id(&self) -> Option<PointerId> {
+        None
+    }
+
+    /// Check if this event means the pointer was lifted, which releases its capture.
+    fn is_pointer_released(&self) -> bool {
+        false
+    }
+
     fn as_event_name(&self) -> Self::Name;
 }
@@ -0,0 +1,181 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use ragnarok::{
+    CursorPoint,
+    Gesture,
+    GestureEvent,
+    GesturePhase,
+    GestureRecognizer,
+    SwipeDirection,
+};
+
+const FRAME: Duration = Duration::from_millis(16);
+
+fn gestures(events: &[GestureEvent]) -> Vec<Gesture> {
+    events.iter().map(|event| event.gesture).collect()
+}
+
+#[test]
+fn pan_follows_the_centroid() {
+    let mut recognizer = GestureRecognizer::default();
+    let now = Instant::now();
+
+    assert!(
+        recognizer
+            .touch_start(0, CursorPoint::new(10., 10.), now)
+            .is_empty()
+    );
+    // Within the slop
+    assert!(
+        recognizer
+            .touch_move(0, CursorPoint::new(14., 10.), now + FRAME)
+            .is_empty()
+    );
+
+    let events = recognizer.touch_move(0, CursorPoint::new(30., 10.), now + FRAME * 2);
+    assert!(matches!(
+        gestures(&events)[..],
+        [Gesture::Pan {
+            phase: GesturePhase::Started,
+            ..
+        }]
+    ));
+    assert_eq!(events[0].location, CursorPoint::new(30., 10.));
+
+    let events = recognizer.touch_move(0, CursorPoint::new(30., 25.), now + FRAME * 30);
+    let [
+        Gesture::Pan {
+            phase: GesturePhase::Changed,
+            delta,
+            translation,
+        },
+    ] = gestures(&events)[..]
+    else {
+        panic!("expected a pan, got {events:?}");
+    };
+    assert_eq!((delta.x, delta.y), (0., 15.));
+    assert_eq!((translation.x, translation.y), (20., 15.));
+
+    // Too slow for a swipe
+    let events = recognizer.touch_end(0, CursorPoint::new(30., 25.), now + FRAME * 60);
+    assert!(matches!(
+        gestures(&events)[..],
+        [Gesture::Pan {
+            phase: GesturePhase::Ended,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn pinch_and_rotate() {
+    let mut recognizer = GestureRecognizer::default();
+    let now = Instant::now();
+
+    recognizer.touch_start(0, CursorPoint::new(100., 100.), now);
+    recognizer.touch_start(1, CursorPoint::new(200., 100.), now);
+
+    // Spread the fingers to twice their distance
+    let events = recognizer.touch_move(1, CursorPoint::new(300., 100.), now + FRAME);
+    let pinch = gestures(&events)
+        .into_iter()
+        .find_map(|gesture| match gesture {
+            Gesture::Pinch { phase, scale, .. } => Some((phase, scale)),
+            _ => None,
+        });
+    assert_eq!(pinch, Some((GesturePhase::Started, 2.)));
+    assert!(
+        !gestures(&events)
+            .iter()
+            .any(|gesture| matches!(gesture, Gesture::Rotate { .. }))
+    );
+
+    // Turn the second finger a quarter clockwise around the first one
+    let events = recognizer.touch_move(1, CursorPoint::new(100., 300.), now + FRAME * 2);
+    let angle = gestures(&events)
+        .into_iter()
+        .find_map(|gesture| match gesture {
+            Gesture::Rotate { phase, angle, .. } => Some((phase, angle)),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(angle.0, GesturePhase::Started);
+    assert!((angle.1 - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+
+    let events = recognizer.touch_end(1, CursorPoint::new(100., 300.), now + FRAME * 3);
+    let phases = gestures(&events)
+        .into_iter()
+        .filter_map(|gesture| match gesture {
+            Gesture::Pinch { phase, .. } | Gesture::Rotate { phase, .. } => Some(phase),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(phases, vec![GesturePhase::Ended, GesturePhase::Ended]);
+}
+
+#[test]
+fn long_press() {
+    let mut recognizer = GestureRecognizer::default();
+    let now = Instant::now();
+
+    recognizer.touch_start(0, CursorPoint::new(10., 10.), now);
+    let deadline = recognizer.deadline().unwrap();
+    assert!(recognizer.tick(deadline - FRAME).is_empty());
+    assert_eq!(
+        gestures(&recognizer.tick(deadline)),
+        vec![Gesture::LongPress]
+    );
+    assert_eq!(recognizer.deadline(), None);
+
+    // Moving away cancels it
+    recognizer.touch_end(0, CursorPoint::new(10., 10.), deadline);
+    recognizer.touch_start(0, CursorPoint::new(10., 10.), deadline);
+    recognizer.touch_move(0, CursorPoint::new(40., 10.), deadline + FRAME);
+    assert_eq!(recognizer.deadline(), None);
+}
+
+#[test]
+fn swipe() {
+    let mut recognizer = GestureRecognizer::default();
+    let now = Instant::now();
+
+    recognizer.touch_start(0, CursorPoint::new(200., 100.), now);
+    recognizer.touch_move(0, CursorPoint::new(150., 100.), now + FRAME);
+    let events = recognizer.touch_end(0, CursorPoint::new(100., 105.), now + FRAME * 2);
+
+    let swipe = gestures(&events)
+        .into_iter()
+        .find_map(|gesture| match gesture {
+            Gesture::Swipe {
+                direction,
+                velocity,
+            } => Some((direction, velocity)),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(swipe.0, SwipeDirection::Left);
+    assert!(swipe.1.x < -3000.);
+}
+
+#[test]
+fn trackpad_gestures_accumulate() {
+    let mut recognizer = GestureRecognizer::default();
+    let location = CursorPoint::new(50., 50.);
+
+    recognizer.trackpad_pinch(0.5, GesturePhase::Started, location);
+    let event = recognizer.trackpad_pinch(1., GesturePhase::Changed, location);
+    assert!(matches!(
+        event.gesture,
+        Gesture::Pinch { scale, .. } if scale == 3.
+    ));
+
+    recognizer.trackpad_rotate(0.25, GesturePhase::Started, location);
+    let event = recognizer.trackpad_rotate(0.25, GesturePhase::Ended, location);
+    assert!(matches!(
+        event.gesture,
+        Gesture::Rotate { angle, .. } if angle == 0.5
+    ));
+}
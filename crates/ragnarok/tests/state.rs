@@ -1,8 +1,12 @@
 #![allow(dead_code)]
 
-use std::collections::{
-    HashMap,
-    HashSet,
+use std::{
+    cell::RefCell,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    rc::Rc,
 };
 
 use ragnarok::{
@@ -15,6 +19,9 @@ use ragnarok::{
     EventsMeasurerRunner,
     NameOfEvent,
     NodesState,
+    PointerCaptureRequest,
+    PointerId,
+    ProcessedEvents,
     SourceEvent,
 };
 
@@ -209,6 +216,17 @@ impl SourceEvent for TestSourceEvent {
         }
     }
 
+    fn try_pointer_id(&self) -> Option<PointerId> {
+        match self {
+            Self::TouchReleased { .. } => None,
+            _ => Some(PointerId::Mouse),
+        }
+    }
+
+    fn is_pointer_released(&self) -> bool {
+        matches!(self, Self::MouseUp { .. })
+    }
+
     fn as_event_name(&self) -> Self::Name {
         match self {
             Self::MouseMove { .. } => EventName::MouseMove,
@@ -260,6 +278,8 @@ impl EmmitableEvent for TestEmmitableEvent {
 struct TestExecutor {
     emitted: Vec<TestEmmitableEvent>,
     handler: fn(&TestEmmitableEvent) -> bool,
+    pointer_capture_requests: Vec<PointerCaptureRequest<usize>>,
+    lost_pointer_captures: Rc<RefCell<Vec<usize>>>,
 }
 
 impl TestExecutor {
@@ -267,14 +287,13 @@ impl TestExecutor {
         Self {
             emitted: Vec::default(),
             handler,
+            pointer_capture_requests: Vec::default(),
+            lost_pointer_captures: Rc::default(),
         }
     }
 
     pub fn without_handler() -> Self {
-        Self {
-            emitted: Vec::default(),
-            handler: |_| true,
-        }
+        Self::new(|_| true)
     }
 }
 
@@ -292,6 +311,14 @@ impl EventsExecutor for TestExecutor {
         self.emitted.push(event);
         allowed
     }
+
+    fn take_pointer_capture_requests(&mut self) -> Vec<PointerCaptureRequest<Self::Key>> {
+        std::mem::take(&mut self.pointer_capture_requests)
+    }
+
+    fn emit_lost_pointer_capture(&mut self, key: Self::Key, _pointer: PointerId) {
+        self.lost_pointer_captures.borrow_mut().push(key);
+    }
 }
 
 #[derive(Default)]
@@ -988,3 +1015,349 @@ fn touch_released_multiple_nodes() {
     );
     TestExecutor::without_handler().run(&mut nodes_state, processed_events);
 }
+
+#[test]
+fn pointer_capture() {
+    let mut test_measurer = TestMeasurer::default();
+    let mut nodes_state = NodesState::default();
+
+    test_measurer.add(0, None, 0, Area::new((0., 0.).into(), (100., 100.).into()));
+    test_measurer.add(
+        1,
+        None,
+        0,
+        Area::new((200., 200.).into(), (100., 100.).into()),
+    );
+    test_measurer.listen_to(0, EventName::MouseDown);
+    test_measurer.listen_to(0, EventName::MouseMove);
+    test_measurer.listen_to(0, EventName::MouseUp);
+    test_measurer.listen_to(0, EventName::MouseOut);
+    test_measurer.listen_to(1, EventName::MouseMove);
+
+    // Node 0 captures the mouse when pressed
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseDown {
+            cursor: (50., 50.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    let mut executor = TestExecutor::without_handler();
+    executor
+        .pointer_capture_requests
+        .push(PointerCaptureRequest::Capture {
+            key: 0,
+            pointer: PointerId::Mouse,
+        });
+    executor.run(&mut nodes_state, processed_events);
+    assert_eq!(nodes_state.pointer_capture(PointerId::Mouse), Some(0));
+
+    // Moving over node 1 still reaches node 0, without leaving it
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    assert_eq!(
+        processed_events.emmitable_events,
+        vec![TestEmmitableEvent {
+            key: 0,
+            name: EventName::MouseMove,
+            source: EventName::MouseMove,
+        }]
+    );
+    TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+
+    // Releasing the mouse ends the capture
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseUp {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    assert_eq!(
+        processed_events.emmitable_events,
+        vec![TestEmmitableEvent {
+            key: 0,
+            name: EventName::MouseUp,
+            source: EventName::MouseUp,
+        }]
+    );
+    let executor = TestExecutor::without_handler();
+    let lost_pointer_captures = executor.lost_pointer_captures.clone();
+    executor.run(&mut nodes_state, processed_events);
+    assert_eq!(*lost_pointer_captures.borrow(), vec![0]);
+    assert_eq!(nodes_state.pointer_capture(PointerId::Mouse), None);
+
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    assert!(
+        processed_events
+            .emmitable_events
+            .contains(&TestEmmitableEvent {
+                key: 1,
+                name: EventName::MouseMove,
+                source: EventName::MouseMove,
+            })
+    );
+}
+
+#[test]
+fn captured_pointer_released_outside() {
+    let mut test_measurer = TestMeasurer::default();
+    let mut nodes_state = NodesState::default();
+
+    test_measurer.add(0, None, 0, Area::new((0., 0.).into(), (100., 100.).into()));
+    test_measurer.listen_to(0, EventName::MouseDown);
+    test_measurer.listen_to(0, EventName::MouseMove);
+    test_measurer.listen_to(0, EventName::MouseUp);
+    test_measurer.listen_to(0, EventName::MouseOut);
+    test_measurer.listen_to(0, EventName::MouseLeave);
+
+    // Node 0 is hovered and captures the mouse when pressed
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (50., 50.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseDown {
+            cursor: (50., 50.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    let mut executor = TestExecutor::without_handler();
+    executor
+        .pointer_capture_requests
+        .push(PointerCaptureRequest::Capture {
+            key: 0,
+            pointer: PointerId::Mouse,
+        });
+    executor.run(&mut nodes_state, processed_events);
+    assert!(nodes_state.is_hovered(0));
+    assert!(nodes_state.is_pressed(0));
+
+    // Dragging outside keeps it hovered while captured
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+    assert!(nodes_state.is_hovered(0));
+
+    // Releasing outside leaves it
+    let mut processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseUp {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    processed_events
+        .emmitable_events
+        .sort_by_key(|event| event.name as u8);
+    assert_eq!(
+        processed_events.emmitable_events,
+        vec![
+            TestEmmitableEvent {
+                key: 0,
+                name: EventName::MouseLeave,
+                source: EventName::MouseUp,
+            },
+            TestEmmitableEvent {
+                key: 0,
+                name: EventName::MouseOut,
+                source: EventName::MouseUp,
+            },
+            TestEmmitableEvent {
+                key: 0,
+                name: EventName::MouseUp,
+                source: EventName::MouseUp,
+            },
+        ]
+    );
+    TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+    assert_eq!(nodes_state.pointer_capture(PointerId::Mouse), None);
+    assert!(!nodes_state.is_hovered(0));
+    assert!(!nodes_state.is_pressed(0));
+
+    // Moving around doesn't leave it again
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (260., 260.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    assert!(processed_events.emmitable_events.is_empty());
+}
+
+#[test]
+fn pointer_capture_released_by_handler_while_dragging() {
+    let mut test_measurer = TestMeasurer::default();
+    let mut nodes_state = NodesState::default();
+
+    test_measurer.add(0, None, 0, Area::new((0., 0.).into(), (100., 100.).into()));
+    test_measurer.listen_to(0, EventName::MouseMove);
+    test_measurer.listen_to(0, EventName::MouseUp);
+    test_measurer.listen_to(0, EventName::MouseOut);
+
+    let processed_events = test_measurer.run(
+        &mut vec![
+            TestSourceEvent::MouseMove {
+                cursor: (50., 50.).into(),
+            },
+            TestSourceEvent::MouseDown {
+                cursor: (50., 50.).into(),
+            },
+        ],
+        &mut nodes_state,
+        None,
+    );
+    TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+    nodes_state.capture_pointer(PointerId::Mouse, 0);
+
+    // The handler stops capturing while dragging outside
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    let mut executor = TestExecutor::without_handler();
+    executor
+        .pointer_capture_requests
+        .push(PointerCaptureRequest::Release {
+            key: 0,
+            pointer: PointerId::Mouse,
+        });
+    executor.run(&mut nodes_state, processed_events);
+    assert_eq!(nodes_state.pointer_capture(PointerId::Mouse), None);
+    assert!(nodes_state.is_hovered(0));
+
+    // The next release outside retains its states
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseUp {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    assert_eq!(
+        processed_events.emmitable_events,
+        vec![TestEmmitableEvent {
+            key: 0,
+            name: EventName::MouseOut,
+            source: EventName::MouseUp,
+        }]
+    );
+    TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+    assert!(!nodes_state.is_hovered(0));
+    assert!(!nodes_state.is_pressed(0));
+}
+
+#[test]
+fn pointer_capture_is_lost_to_another_node() {
+    let mut nodes_state = NodesState::<usize>::default();
+    let mut executor = TestExecutor::without_handler();
+    let lost_pointer_captures = executor.lost_pointer_captures.clone();
+
+    executor.pointer_capture_requests.extend([
+        PointerCaptureRequest::Capture {
+            key: 0,
+            pointer: PointerId::Touch(1),
+        },
+        PointerCaptureRequest::Capture {
+            key: 1,
+            pointer: PointerId::Touch(1),
+        },
+        // Not capturing it anymore
+        PointerCaptureRequest::Release {
+            key: 0,
+            pointer: PointerId::Touch(1),
+        },
+    ]);
+    executor.run(
+        &mut nodes_state,
+        ProcessedEvents {
+            emmitable_events: vec![TestEmmitableEvent {
+                key: 0,
+                name: EventName::MouseDown,
+                source: EventName::MouseDown,
+            }],
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(*lost_pointer_captures.borrow(), vec![0]);
+    assert_eq!(nodes_state.pointer_capture(PointerId::Touch(1)), Some(1));
+}
+
+#[test]
+fn global_events_reach_every_listener_while_captured() {
+    let mut test_measurer = TestMeasurer::default();
+    let mut nodes_state = NodesState::default();
+
+    test_measurer.add(0, None, 0, Area::new((0., 0.).into(), (100., 100.).into()));
+    test_measurer.add(
+        1,
+        None,
+        0,
+        Area::new((200., 200.).into(), (100., 100.).into()),
+    );
+    test_measurer.listen_to(0, EventName::MouseMove);
+    test_measurer.listen_to(0, EventName::CaptureGlobalMouseMove);
+    test_measurer.listen_to(1, EventName::CaptureGlobalMouseMove);
+
+    nodes_state.capture_pointer(PointerId::Mouse, 0);
+
+    // The capturing node gets the event, and the global listeners, the capturing one included,
+    // get the global event
+    let mut processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (250., 250.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+    processed_events
+        .emmitable_events
+        .sort_by_key(|event| (event.key, event.name == EventName::MouseMove));
+    assert_eq!(
+        processed_events.emmitable_events,
+        vec![
+            TestEmmitableEvent {
+                key: 0,
+                name: EventName::CaptureGlobalMouseMove,
+                source: EventName::MouseMove,
+            },
+            TestEmmitableEvent {
+                key: 0,
+                name: EventName::MouseMove,
+                source: EventName::MouseMove,
+            },
+            TestEmmitableEvent {
+                key: 1,
+                name: EventName::CaptureGlobalMouseMove,
+                source: EventName::MouseMove,
+            },
+        ]
+    );
+}
@@ -1,10 +1,14 @@
 pub use euclid::Rect;
-use rustc_hash::FxHashMap;
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
     geometry::{
         Area,
+        Point2D,
         Size2D,
     },
     node::Node,
@@ -19,6 +23,8 @@ use crate::{
         AvailableAreaModel,
         Content,
         Direction,
+        GridPlacement,
+        GridSize,
         Inner,
         LayoutMetadata,
         Length,
@@ -63,13 +69,15 @@ where
 {
     /// Translate a single Node's cached areas by the given offset, notifying layout references.
     fn translate_node(&mut self, node_id: Key, offset_x: Length, offset_y: Length) {
-        let Some((area, visible_area, inner_sizes)) =
+        let Some((previous_visible_area, area, visible_area, inner_sizes)) =
             self.layout.get_mut(&node_id).map(|layout_node| {
+                let previous_visible_area = layout_node.visible_area();
                 layout_node.area.origin.x += offset_x.get();
                 layout_node.area.origin.y += offset_y.get();
                 layout_node.inner_area.origin.x += offset_x.get();
                 layout_node.inner_area.origin.y += offset_y.get();
                 (
+                    previous_visible_area,
                     layout_node.area,
                     layout_node.visible_area(),
                     layout_node.inner_sizes,
@@ -79,6 +87,11 @@ where
             return;
         };
 
+        if previous_visible_area != visible_area {
+            self.layout
+                .record_change(node_id, Some(previous_visible_area));
+        }
+
         if let Some(measurer) = self.measurer {
             measurer.notify_layout_references(node_id, area, visible_area, inner_sizes);
         }
@@ -95,7 +108,7 @@ where
 
             let translate = match node.position {
                 Position::Global(_) => false,
-                Position::Stacked(_) | Position::Absolute(_) => true,
+                Position::Stacked(_) | Position::Absolute(_) | Position::Sticky(_) => true,
             };
 
             if translate {
@@ -109,8 +122,12 @@ where
     fn set_hidden(&mut self, node_id: Key, hidden: bool) {
         let mut buffer = vec![node_id];
         while let Some(child) = buffer.pop() {
-            if let Some(layout_node) = self.layout.get_mut(&child) {
+            if let Some(layout_node) = self.layout.get_mut(&child)
+                && layout_node.hidden != hidden
+            {
                 layout_node.hidden = hidden;
+                let visible_area = layout_node.visible_area();
+                self.layout.record_change(child, Some(visible_area));
             }
             buffer.extend(self.tree_adapter.children_of(&child));
         }
@@ -141,12 +158,188 @@ where
         post_measure.content_size
     }
 
+    /// Mirror the stacked children of a right-to-left Node horizontally inside of its area.
+    fn mirror_children(&mut self, node_id: Key, node: &Node, area: &Area) {
+        // Children placed by the measurer itself are already laid out in the right direction
+        if let Some(measurer) = self.measurer
+            && measurer.should_post_measure(node_id)
+        {
+            return;
+        }
+
+        let content_area = area.without_gaps(&node.margin).without_gaps(&node.padding);
+        let mirror_axis = content_area.min_x() + content_area.max_x() + node.offset_x.get() * 2.;
+
+        for child_id in self.tree_adapter.children_of(&node_id) {
+            // Children that were only translated keep their mirrored position
+            if self.layout.dirty.get(&child_id) == Some(&DirtyReason::InnerLayout) {
+                continue;
+            }
+            let Some(child_data) = self.tree_adapter.get_node(&child_id) else {
+                continue;
+            };
+            if !child_data.position.is_stacked() {
+                continue;
+            }
+            let Some(child_area) = self.layout.get(&child_id).map(|child| child.area) else {
+                continue;
+            };
+
+            let offset_x = Length::new(mirror_axis - child_area.min_x() * 2. - child_area.width());
+            self.translate_node(child_id, offset_x, Length::default());
+            self.recursive_translate(child_id, offset_x, Length::default());
+        }
+    }
+
+    /// Sticky Nodes sorted from the outermost to the innermost.
+    fn sorted_sticky_nodes(&self) -> Vec<Key> {
+        let mut sticky_nodes = self
+            .layout
+            .sticky_offsets
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        sticky_nodes.sort_by_key(|node_id| self.tree_adapter.height(node_id));
+        sticky_nodes
+    }
+
+    /// Move the sticky Nodes and their descendants back to their flow position.
+    pub(crate) fn restore_sticky_nodes(&mut self) {
+        for node_id in self.sorted_sticky_nodes().into_iter().rev() {
+            let Some((offset_x, offset_y)) = self
+                .layout
+                .sticky_offsets
+                .insert(node_id, (Length::default(), Length::default()))
+            else {
+                continue;
+            };
+            if offset_x.get() != 0.0 || offset_y.get() != 0.0 {
+                self.translate_node(node_id, -offset_x, -offset_y);
+                self.recursive_translate(node_id, -offset_x, -offset_y);
+            }
+        }
+    }
+
+    /// Clamp the sticky Nodes to the visible area of their closest scrollable ancestor
+    /// and translate them along with their descendants.
+    pub(crate) fn apply_sticky_nodes(&mut self) {
+        for node_id in self.sorted_sticky_nodes() {
+            let Some(Node {
+                position: Position::Sticky(sides),
+                ..
+            }) = self.tree_adapter.get_node(&node_id)
+            else {
+                continue;
+            };
+            let Some(area) = self
+                .layout
+                .get(&node_id)
+                .map(|layout_node| layout_node.area)
+            else {
+                continue;
+            };
+            let Some(containing_area) = self
+                .tree_adapter
+                .parent_of(&node_id)
+                .and_then(|parent_id| self.layout.get(&parent_id))
+                .map(|parent| {
+                    Area::new(
+                        parent.inner_area.origin.cast_unit(),
+                        parent.inner_area.size.cast_unit().max(parent.inner_sizes),
+                    )
+                })
+            else {
+                continue;
+            };
+            let viewport = self.sticky_viewport(node_id);
+
+            let origin = sides.sticky_origin(&area, &viewport, &containing_area);
+            let offset_x = Length::new(origin.x - area.min_x());
+            let offset_y = Length::new(origin.y - area.min_y());
+            self.layout
+                .sticky_offsets
+                .insert(node_id, (offset_x, offset_y));
+
+            if offset_x.get() != 0.0 || offset_y.get() != 0.0 {
+                self.translate_node(node_id, offset_x, offset_y);
+                self.recursive_translate(node_id, offset_x, offset_y);
+            }
+        }
+    }
+
+    /// Visible area of the closest scrollable ancestor of a Node, or the root area if there is none.
+    fn sticky_viewport(&self, node_id: Key) -> Area {
+        let mut current = self.tree_adapter.parent_of(&node_id);
+        while let Some(ancestor_id) = current {
+            if self
+                .tree_adapter
+                .get_node(&ancestor_id)
+                .is_some_and(|node| node.scrollable)
+                && let Some(layout_node) = self.layout.get(&ancestor_id)
+            {
+                return layout_node.visible_area();
+            }
+            current = self.tree_adapter.parent_of(&ancestor_id);
+        }
+        self.layout_metadata.root_area
+    }
+
+    /// Derive the dimension of a Node with an aspect ratio that is sized by its content from the
+    /// one that can be resolved. Nothing is derived when both or none of the dimensions are resolvable.
+    fn aspect_ratio_size(
+        &self,
+        node: &Node,
+        initial_parent_area: &AreaOf<Parent>,
+        available_parent_area: &AreaOf<Available>,
+        phase: Phase,
+    ) -> Option<(AlignAxis, Size)> {
+        let aspect_ratio = node.aspect_ratio.filter(|ratio| *ratio > 0.)?;
+
+        match (node.width.inner_sized(), node.height.inner_sized()) {
+            (false, true) => {
+                let width = node.width.min_max(
+                    node.padding.horizontal(),
+                    initial_parent_area.size.width,
+                    available_parent_area.size.width,
+                    node.margin.left(),
+                    node.margin.horizontal(),
+                    &node.minimum_width,
+                    &node.maximum_width,
+                    self.layout_metadata.root_area.width(),
+                    phase,
+                ) - node.margin.horizontal();
+                Some((
+                    AlignAxis::Height,
+                    Size::Pixels(Length::new(width / aspect_ratio)),
+                ))
+            }
+            (true, false) => {
+                let height = node.height.min_max(
+                    node.padding.vertical(),
+                    initial_parent_area.size.height,
+                    available_parent_area.size.height,
+                    node.margin.top(),
+                    node.margin.vertical(),
+                    &node.minimum_height,
+                    &node.maximum_height,
+                    self.layout_metadata.root_area.height(),
+                    phase,
+                ) - node.margin.vertical();
+                Some((
+                    AlignAxis::Width,
+                    Size::Pixels(Length::new(height * aspect_ratio)),
+                ))
+            }
+            _ => None,
+        }
+    }
+
     /// Measure a Node and all its children.
     #[allow(clippy::too_many_arguments, clippy::missing_panics_doc)]
     pub fn measure_node(
         &mut self,
         node_id: Key,
-        node: &Node,
+        node: &mut Node,
         // Initial area occupied by it's parent
         initial_parent_area: AreaOf<Parent>,
         // Area that is available to use by the children of the parent
@@ -158,6 +351,20 @@ where
         // Current phase of measurement
         phase: Phase,
     ) -> (bool, LayoutNode) {
+        // The left and right gaps are the start and end sides in right-to-left layouts
+        let mut mirrored_node;
+        let node = if node.layout_direction.is_rtl() {
+            mirrored_node = {
+                let mut node = node.clone();
+                node.padding.mirror_horizontal();
+                node.margin.mirror_horizontal();
+                node
+            };
+            &mut mirrored_node
+        } else {
+            node
+        };
+
         let reason = self.layout.dirty.get(&node_id).copied();
 
         // If possible translate all this Node's descendants to avoid relayout
@@ -185,6 +392,15 @@ where
         let must_revalidate =
             parent_is_dirty || reason.is_some() || !self.layout.results.contains_key(&node_id);
         if must_revalidate {
+            // Turn the dimension derived from the aspect ratio into a fixed size while measuring,
+            // the original one is restored after as it can be derived again with other areas
+            let replaced_size = self
+                .aspect_ratio_size(node, &initial_parent_area, &available_parent_area, phase)
+                .map(|(axis, size)| match axis {
+                    AlignAxis::Width => (axis, std::mem::replace(&mut node.width, size)),
+                    AlignAxis::Height => (axis, std::mem::replace(&mut node.height, size)),
+                });
+
             // Create the initial Node area size
             let mut area_size = Size2D::new(node.padding.horizontal(), node.padding.vertical());
 
@@ -430,6 +646,10 @@ where
                 }
             }
 
+            if node.layout_direction.is_rtl() && must_cache_children && phase == Phase::Final {
+                self.mirror_children(node_id, node, &area);
+            }
+
             let mut layout_node = LayoutNode {
                 area,
                 margin: node.margin,
@@ -491,6 +711,21 @@ where
                 );
             }
 
+            // Keep track of the sticky Nodes so they can be clamped once the layout is done.
+            if must_cache_children && phase == Phase::Final {
+                if node.position.is_sticky() {
+                    self.layout.sticky_offsets.entry(node_id).or_default();
+                } else {
+                    self.layout.sticky_offsets.remove(&node_id);
+                }
+            }
+
+            match replaced_size {
+                Some((AlignAxis::Width, width)) => node.width = width,
+                Some((AlignAxis::Height, height)) => node.height = height,
+                None => {}
+            }
+
             (must_cache_children, layout_node)
         } else {
             let layout_node = self
@@ -545,6 +780,20 @@ where
     ) {
         let children = self.tree_adapter.children_of(parent_node_id);
 
+        if parent_node.content.is_grid() {
+            self.measure_grid_children(
+                parent_node,
+                children,
+                parent_area,
+                inner_area,
+                available_area,
+                inner_sizes,
+                must_cache_children,
+                parent_is_dirty,
+            );
+            return;
+        }
+
         let initial_area = *inner_area;
 
         let mut initial_phase_flex_grows = FxHashMap::default();
@@ -597,7 +846,7 @@ where
         if needs_initial_phase {
             //  Measure the children
             for child_id in &children {
-                let Some(child_data) = self.tree_adapter.get_node(child_id) else {
+                let Some(mut child_data) = self.tree_adapter.get_node(child_id) else {
                     continue;
                 };
 
@@ -611,7 +860,7 @@ where
 
                 let (_, mut child_areas) = self.measure_node(
                     *child_id,
-                    &child_data,
+                    &mut child_data,
                     initial_area.as_parent(),
                     initial_phase_available_area,
                     false,
@@ -709,7 +958,7 @@ where
                     let Some(flex_grow) = initial_phase_flex_grows.get(child_id) else {
                         continue;
                     };
-                    let Some(child_data) = self.tree_adapter.get_node(child_id) else {
+                    let Some(mut child_data) = self.tree_adapter.get_node(child_id) else {
                         continue;
                     };
                     if !child_data.position.is_stacked() {
@@ -740,7 +989,7 @@ where
 
                     let (_, mut child_areas) = self.measure_node(
                         *child_id,
-                        &child_data,
+                        &mut child_data,
                         initial_area.as_parent(),
                         corrected_available_area,
                         false,
@@ -820,7 +1069,7 @@ where
 
         // Final phase: measure the children with all the axis and sizes adjusted
         for child_id in children {
-            let Some(child_data) = self.tree_adapter.get_node(&child_id) else {
+            let Some(mut child_data) = self.tree_adapter.get_node(&child_id) else {
                 continue;
             };
 
@@ -900,7 +1149,7 @@ where
             // Final measurement
             let (child_revalidated, mut child_areas) = self.measure_node(
                 child_id,
-                &child_data,
+                &mut child_data,
                 initial_area.as_parent(),
                 adapted_available_area,
                 must_cache_children,
@@ -934,6 +1183,383 @@ where
         }
     }
 
+    /// Measure the children of a Node with a [`Grid`](Content::Grid) content.
+    /// Tracks are sized first, then every stacked child is measured inside the area of its cell.
+    #[allow(clippy::too_many_arguments)]
+    fn measure_grid_children(
+        &mut self,
+        parent_node: &Node,
+        children: Vec<Key>,
+        parent_area: &mut AreaOf<Parent>,
+        inner_area: &mut AreaOf<Inner>,
+        available_area: &mut AreaOf<Available>,
+        inner_sizes: &mut Size2D,
+        must_cache_children: bool,
+        parent_is_dirty: bool,
+    ) {
+        let Content::Grid {
+            columns,
+            rows,
+            column_spacing,
+            row_spacing,
+        } = &parent_node.content
+        else {
+            return;
+        };
+
+        let initial_area = *inner_area;
+
+        let mut stacked_children = children
+            .iter()
+            .filter_map(|child_id| {
+                let child_data = self.tree_adapter.get_node(child_id)?;
+                child_data
+                    .position
+                    .is_stacked()
+                    .then_some((*child_id, child_data))
+            })
+            .collect::<Vec<_>>();
+
+        let cells = Self::place_grid_cells(
+            columns.len().max(1),
+            stacked_children
+                .iter()
+                .map(|(_, child_data)| child_data.grid_placement),
+        );
+
+        // Rows that were not defined but are needed by the placed children size to their content
+        let rows_len = cells
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or_default()
+            .max(rows.len());
+        let mut rows = rows.clone();
+        rows.resize(rows_len, GridSize::Inner);
+
+        // Initial phase: Measure the children placed in a single auto track
+        let mut initial_phase_sizes = FxHashMap::default();
+        for ((child_id, child_data), cell) in stacked_children.iter_mut().zip(&cells) {
+            let in_auto_column = cell.column_span == 1
+                && matches!(columns.get(cell.column), Some(GridSize::Inner) | None);
+            let in_auto_row = cell.row_span == 1 && rows[cell.row] == GridSize::Inner;
+            if !in_auto_column && !in_auto_row {
+                continue;
+            }
+
+            let (_, mut child_areas) = self.measure_node(
+                *child_id,
+                child_data,
+                initial_area.as_parent(),
+                initial_area.as_available(),
+                false,
+                parent_is_dirty,
+                Phase::Initial,
+            );
+            child_areas.area.adjust_size(child_data);
+            initial_phase_sizes.insert(*child_id, child_areas.area.size);
+        }
+
+        let column_sizes = Self::grid_track_sizes(
+            columns,
+            initial_area.width(),
+            *column_spacing,
+            stacked_children
+                .iter()
+                .zip(&cells)
+                .filter(|(_, cell)| cell.column_span == 1)
+                .filter_map(|((child_id, _), cell)| {
+                    Some((cell.column, initial_phase_sizes.get(child_id)?.width))
+                }),
+        );
+
+        // Auto rows depend on the height of their children once they are constrained to their columns
+        for ((child_id, child_data), cell) in stacked_children.iter_mut().zip(&cells) {
+            if cell.row_span != 1 || rows[cell.row] != GridSize::Inner {
+                continue;
+            }
+            let mut cell_available_area = initial_area.as_available();
+            cell_available_area.size.width = Self::grid_span_size(
+                &column_sizes,
+                *column_spacing,
+                cell.column,
+                cell.column_span,
+            );
+
+            let (_, mut child_areas) = self.measure_node(
+                *child_id,
+                child_data,
+                cell_available_area.as_parent(),
+                cell_available_area,
+                false,
+                parent_is_dirty,
+                Phase::Initial,
+            );
+            child_areas.area.adjust_size(child_data);
+            initial_phase_sizes.insert(*child_id, child_areas.area.size);
+        }
+
+        let row_sizes = Self::grid_track_sizes(
+            &rows,
+            initial_area.height(),
+            *row_spacing,
+            stacked_children
+                .iter()
+                .zip(&cells)
+                .filter(|(_, cell)| cell.row_span == 1)
+                .filter_map(|((child_id, _), cell)| {
+                    Some((cell.row, initial_phase_sizes.get(child_id)?.height))
+                }),
+        );
+
+        let grid_size = Size2D::new(
+            Self::grid_span_size(&column_sizes, *column_spacing, 0, column_sizes.len()),
+            Self::grid_span_size(&row_sizes, *row_spacing, 0, row_sizes.len()),
+        );
+
+        // Final phase: Measure every child inside the area of its cell
+        let mut cells = stacked_children
+            .iter()
+            .map(|(child_id, _)| *child_id)
+            .zip(cells)
+            .collect::<FxHashMap<_, _>>();
+        for child_id in children {
+            let Some(mut child_data) = self.tree_adapter.get_node(&child_id) else {
+                continue;
+            };
+
+            let (child_parent_area, child_available_area) = if let Some(cell) =
+                cells.remove(&child_id)
+            {
+                let cell_area = Area::new(
+                    Point2D::new(
+                        initial_area.min_x()
+                            + Self::grid_span_offset(&column_sizes, *column_spacing, cell.column),
+                        initial_area.min_y()
+                            + Self::grid_span_offset(&row_sizes, *row_spacing, cell.row),
+                    ),
+                    Size2D::new(
+                        Self::grid_span_size(
+                            &column_sizes,
+                            *column_spacing,
+                            cell.column,
+                            cell.column_span,
+                        ),
+                        Self::grid_span_size(&row_sizes, *row_spacing, cell.row, cell.row_span),
+                    ),
+                );
+                (cell_area.as_parent(), cell_area.as_available())
+            } else {
+                (initial_area.as_parent(), *available_area)
+            };
+
+            let (child_revalidated, child_areas) = self.measure_node(
+                child_id,
+                &mut child_data,
+                child_parent_area,
+                child_available_area,
+                must_cache_children,
+                parent_is_dirty,
+                Phase::Final,
+            );
+
+            if child_revalidated && must_cache_children {
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+
+        *inner_sizes = grid_size;
+
+        if parent_node.width.inner_sized() {
+            parent_area.size.width = grid_size.width
+                + parent_node.padding.horizontal()
+                + parent_node.margin.horizontal();
+            inner_area.size.width = grid_size.width;
+        }
+        if parent_node.height.inner_sized() {
+            parent_area.size.height =
+                grid_size.height + parent_node.padding.vertical() + parent_node.margin.vertical();
+            inner_area.size.height = grid_size.height;
+        }
+    }
+
+    /// Resolve the cell of every grid child. Children with an explicit column and row are placed first,
+    /// the rest fill the next free cells row by row.
+    fn place_grid_cells(
+        columns_len: usize,
+        placements: impl Iterator<Item = GridPlacement>,
+    ) -> Vec<GridCell> {
+        let placements = placements.collect::<Vec<_>>();
+        let mut cells = vec![None; placements.len()];
+        let mut occupied = FxHashSet::default();
+
+        let clamp = |placement: &GridPlacement, column: usize| {
+            let column = column.min(columns_len - 1);
+            (column, placement.column_span.clamp(1, columns_len - column))
+        };
+
+        let occupy = |occupied: &mut FxHashSet<(usize, usize)>, cell: &GridCell| {
+            for column in cell.column..cell.column + cell.column_span {
+                for row in cell.row..cell.row + cell.row_span {
+                    occupied.insert((column, row));
+                }
+            }
+        };
+
+        let is_free = |occupied: &FxHashSet<(usize, usize)>, cell: &GridCell| {
+            (cell.column..cell.column + cell.column_span).all(|column| {
+                (cell.row..cell.row + cell.row_span).all(|row| !occupied.contains(&(column, row)))
+            })
+        };
+
+        for (placement, cell) in placements.iter().zip(cells.iter_mut()) {
+            if let (Some(column), Some(row)) = (placement.column, placement.row) {
+                let (column, column_span) = clamp(placement, column);
+                let explicit_cell = GridCell {
+                    column,
+                    row,
+                    column_span,
+                    row_span: placement.row_span.max(1),
+                };
+                occupy(&mut occupied, &explicit_cell);
+                *cell = Some(explicit_cell);
+            }
+        }
+
+        // Every auto placed child fits at most after all the occupied rows plus the rows of the
+        // children placed before it, which bounds the search for a free cell
+        let rows_limit = occupied
+            .iter()
+            .map(|(_, row)| row + 1)
+            .max()
+            .unwrap_or_default()
+            + placements
+                .iter()
+                .map(|placement| placement.row_span.max(1))
+                .sum::<usize>();
+
+        let mut cursor = 0;
+        for (placement, cell) in placements.iter().zip(cells.iter_mut()) {
+            if cell.is_some() {
+                continue;
+            }
+            let row_span = placement.row_span.max(1);
+            let auto_cell = match (placement.column, placement.row) {
+                (Some(column), _) => {
+                    let (column, column_span) = clamp(placement, column);
+                    (0..rows_limit)
+                        .map(|row| GridCell {
+                            column,
+                            row,
+                            column_span,
+                            row_span,
+                        })
+                        .find(|cell| is_free(&occupied, cell))
+                }
+                (None, Some(row)) => (0..columns_len)
+                    .map(|column| {
+                        let (column, column_span) = clamp(placement, column);
+                        GridCell {
+                            column,
+                            row,
+                            column_span,
+                            row_span,
+                        }
+                    })
+                    .find(|cell| is_free(&occupied, cell)),
+                (None, None) => (cursor..rows_limit * columns_len)
+                    .map(|index| (index % columns_len, index / columns_len))
+                    // Move to the next row instead of shrinking a span that does not fit
+                    .filter(|(column, _)| {
+                        *column == 0 || column + placement.column_span <= columns_len
+                    })
+                    .map(|(column, row)| {
+                        let (column, column_span) = clamp(placement, column);
+                        GridCell {
+                            column,
+                            row,
+                            column_span,
+                            row_span,
+                        }
+                    })
+                    .find(|cell| is_free(&occupied, cell)),
+            }
+            .unwrap_or_else(|| {
+                let (column, column_span) = clamp(placement, placement.column.unwrap_or_default());
+                GridCell {
+                    column,
+                    row: placement.row.unwrap_or_default(),
+                    column_span,
+                    row_span,
+                }
+            });
+
+            if placement.column.is_none() && placement.row.is_none() {
+                cursor = auto_cell.row * columns_len + auto_cell.column + auto_cell.column_span;
+            }
+            occupy(&mut occupied, &auto_cell);
+            *cell = Some(auto_cell);
+        }
+
+        cells.into_iter().map(Option::unwrap_or_default).collect()
+    }
+
+    /// Compute the size of every track given the available space and the sizes of the children
+    /// that were placed in a single track.
+    fn grid_track_sizes(
+        tracks: &[GridSize],
+        available_size: f32,
+        spacing: f32,
+        children_sizes: impl Iterator<Item = (usize, f32)>,
+    ) -> Vec<f32> {
+        let mut sizes = tracks
+            .iter()
+            .map(|track| match track {
+                GridSize::Pixels(px) => px.get(),
+                GridSize::Percentage(per) => available_size / 100.0 * per.get(),
+                GridSize::Fraction(_) | GridSize::Inner => 0.,
+            })
+            .collect::<Vec<_>>();
+
+        for (track, size) in children_sizes {
+            if tracks.get(track) == Some(&GridSize::Inner) {
+                sizes[track] = sizes[track].max(size);
+            }
+        }
+
+        let fractions = tracks
+            .iter()
+            .filter_map(|track| match track {
+                GridSize::Fraction(f) => Some(f.get()),
+                _ => None,
+            })
+            .sum::<f32>();
+
+        if fractions > 0. {
+            let used = sizes.iter().sum::<f32>() + spacing * tracks.len().saturating_sub(1) as f32;
+            let remaining = (available_size - used).max(0.);
+            for (track, size) in tracks.iter().zip(sizes.iter_mut()) {
+                if let GridSize::Fraction(f) = track {
+                    *size = remaining / fractions * f.get();
+                }
+            }
+        }
+
+        sizes
+    }
+
+    /// Offset from the start of the grid to the start of the given track.
+    fn grid_span_offset(sizes: &[f32], spacing: f32, start: usize) -> f32 {
+        sizes.iter().take(start).map(|size| size + spacing).sum()
+    }
+
+    /// Size of `span` consecutive tracks including the spacing between them.
+    fn grid_span_size(sizes: &[f32], spacing: f32, start: usize, span: usize) -> f32 {
+        let tracks = sizes.iter().skip(start).take(span);
+        let len = tracks.len();
+        tracks.sum::<f32>() + spacing * len.saturating_sub(1) as f32
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn wrap_child(
         wrap_spacing: f32,
@@ -1239,3 +1865,12 @@ where
         *available_size = *inner_size;
     }
 }
+
+/// Resolved cell of a child inside a [`Grid`](Content::Grid) content.
+#[derive(Clone, Copy, Default)]
+struct GridCell {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
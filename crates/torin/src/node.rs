@@ -7,6 +7,8 @@ use crate::{
     geometry::Length,
     prelude::{
         Content,
+        GridPlacement,
+        LayoutDirection,
         Position,
         VisibleSize,
     },
@@ -30,6 +32,9 @@ pub struct Node {
     pub maximum_width: Size,
     pub maximum_height: Size,
 
+    /// Width to height ratio used to derive the dimension that is not otherwise resolved
+    pub aspect_ratio: Option<f32>,
+
     // Visible dimensions
     pub visible_width: VisibleSize,
     pub visible_height: VisibleSize,
@@ -51,14 +56,23 @@ pub struct Node {
     /// Direction in which it's inner Nodes will be stacked
     pub direction: Direction,
 
+    /// Horizontal order of the inner Nodes, mirrored for right-to-left
+    pub layout_direction: LayoutDirection,
+
     /// Position config
     pub position: Position,
 
     pub content: Content,
 
+    /// Cell occupied by this Node when its parent uses a grid content.
+    pub grid_placement: GridPlacement,
+
     /// A Node might depend on inner sizes but have a fixed position, like scroll views.
     pub has_layout_references: bool,
 
+    /// Sticky descendants are kept inside of the visible area of this Node, like scroll views.
+    pub scrollable: bool,
+
     pub spacing: Length,
 
     /// A Node might need to relayout when its children change even if its own size
@@ -79,6 +93,7 @@ impl Scaled for Node {
         self.offset_x *= scale_factor;
         self.offset_y *= scale_factor;
         self.position.scale(scale_factor);
+        self.content.scale(scale_factor);
         self.spacing *= scale_factor;
     }
 }
@@ -97,6 +112,7 @@ impl Node {
             && self.minimum_height == other.minimum_height
             && self.maximum_width == other.maximum_width
             && self.maximum_height == other.maximum_height
+            && self.aspect_ratio == other.aspect_ratio
             && self.visible_width == other.visible_width
             && self.visible_height == other.visible_height
             && self.main_alignment == other.main_alignment
@@ -104,9 +120,12 @@ impl Node {
             && self.padding == other.padding
             && self.margin == other.margin
             && self.direction == other.direction
+            && self.layout_direction == other.layout_direction
             && self.position == other.position
             && self.content == other.content
+            && self.grid_placement == other.grid_placement
             && self.has_layout_references == other.has_layout_references
+            && self.scrollable == other.scrollable
             && self.spacing == other.spacing
             && self.depends_on_inner == other.depends_on_inner
     }
@@ -322,5 +341,7 @@ impl Node {
             || self.main_alignment.is_not_start()
             || self.has_layout_references
             || self.content == Content::Flex
+            || self.content.is_grid()
+            || self.layout_direction.is_rtl()
     }
 }
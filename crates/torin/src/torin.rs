@@ -111,6 +111,13 @@ pub struct Torin<Key: NodeKey> {
 
     /// Best Root node candidate from where to start measuringg
     pub root_node_candidate: RootNodeCandidate<Key>,
+
+    /// Offsets applied to the sticky Nodes on top of their flow position
+    pub sticky_offsets: FxHashMap<Key, (Length, Length)>,
+
+    /// Nodes whose visible area changed since the changes were last taken, along with the visible
+    /// area they had before, or `None` if they had no layout yet
+    pub changes: FxHashMap<Key, Option<Area>>,
 }
 
 impl<Key: NodeKey> Default for Torin<Key> {
@@ -126,6 +133,8 @@ impl<Key: NodeKey> Torin<Key> {
             results: HashMap::default(),
             dirty: FxHashMap::default(),
             root_node_candidate: RootNodeCandidate::None,
+            sticky_offsets: FxHashMap::default(),
+            changes: FxHashMap::default(),
         }
     }
 
@@ -143,6 +152,8 @@ impl<Key: NodeKey> Torin<Key> {
         self.root_node_candidate = RootNodeCandidate::None;
         self.results.clear();
         self.dirty.clear();
+        self.sticky_offsets.clear();
+        self.changes.clear();
     }
 
     /// Read the HashSet of dirty nodes
@@ -154,6 +165,8 @@ impl<Key: NodeKey> Torin<Key> {
     pub fn raw_remove(&mut self, node_id: Key) {
         self.results.remove(&node_id);
         self.dirty.remove(&node_id);
+        self.sticky_offsets.remove(&node_id);
+        self.changes.remove(&node_id);
         if let RootNodeCandidate::Valid(id) = self.root_node_candidate
             && id == node_id
         {
@@ -304,6 +317,15 @@ impl<Key: NodeKey> Torin<Key> {
             return;
         }
 
+        // Move the sticky Nodes back to their flow position so cached results can be reused
+        MeasureContext {
+            layout: self,
+            layout_metadata: LayoutMetadata { root_area },
+            tree_adapter,
+            measurer,
+        }
+        .restore_sticky_nodes();
+
         // Try the Root candidate otherwise use the provided Root
         let root_id = if let RootNodeCandidate::Valid(id) = self.root_node_candidate.take() {
             id
@@ -323,7 +345,7 @@ impl<Key: NodeKey> Torin<Key> {
                 hidden: false,
                 data: None,
             });
-        let root = tree_adapter.get_node(&root_id).unwrap();
+        let mut root = tree_adapter.get_node(&root_id).unwrap();
 
         #[cfg(debug_assertions)]
         {
@@ -350,7 +372,7 @@ impl<Key: NodeKey> Torin<Key> {
 
         let (root_revalidated, mut root_layout_node) = measure_context.measure_node(
             root_id,
-            &root,
+            &mut root,
             inner_area.as_parent(),
             available_area,
             true,
@@ -366,6 +388,15 @@ impl<Key: NodeKey> Torin<Key> {
             self.cache_node(root_id, root_layout_node);
         }
 
+        // Clamp the sticky Nodes now that every area is known
+        MeasureContext {
+            layout: self,
+            layout_metadata: LayoutMetadata { root_area },
+            tree_adapter,
+            measurer,
+        }
+        .apply_sticky_nodes();
+
         self.dirty.clear();
         self.root_node_candidate = RootNodeCandidate::None;
     }
@@ -382,6 +413,22 @@ impl<Key: NodeKey> Torin<Key> {
 
     /// Cache a Node's [LayoutNode]
     pub fn cache_node(&mut self, node_id: Key, layout_node: LayoutNode) {
-        self.results.insert(node_id, layout_node);
+        let visible_area = layout_node.visible_area();
+        let previous = self.results.insert(node_id, layout_node);
+        match previous {
+            Some(previous) if previous.visible_area() == visible_area => {}
+            previous => self.record_change(node_id, previous.map(|node| node.visible_area())),
+        }
+    }
+
+    /// Remember that the visible area of a Node changed, keeping the oldest known area.
+    pub(crate) fn record_change(&mut self, node_id: Key, previous: Option<Area>) {
+        self.changes.entry(node_id).or_insert(previous);
+    }
+
+    /// Take the Nodes whose visible area changed since the last call, along with their previous
+    /// visible area.
+    pub fn take_changes(&mut self) -> FxHashMap<Key, Option<Area>> {
+        std::mem::take(&mut self.changes)
     }
 }
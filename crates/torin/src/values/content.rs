@@ -1,3 +1,8 @@
+use crate::{
+    grid::GridSize,
+    scaled::Scaled,
+};
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Clone, Debug, Default)]
 pub enum Content {
@@ -11,6 +16,15 @@ pub enum Content {
     /// Wrap children to the next line or column when they exceed the available space,
     /// with an optional gap between wrapped lines.
     Wrap { wrap_spacing: Option<f32> },
+    /// Place children in the cells of a two-dimensional grid made of column and row tracks,
+    /// with a gap between columns and between rows.
+    /// See [`GridPlacement`](crate::grid::GridPlacement) for how children pick their cell.
+    Grid {
+        columns: Vec<GridSize>,
+        rows: Vec<GridSize>,
+        column_spacing: f32,
+        row_spacing: f32,
+    },
 }
 
 impl Content {
@@ -41,6 +55,31 @@ impl Content {
         }
     }
 
+    /// Use a [`Grid`](Content::Grid) content with the given tracks and no spacing.
+    pub fn grid(columns: Vec<GridSize>, rows: Vec<GridSize>) -> Content {
+        Content::Grid {
+            columns,
+            rows,
+            column_spacing: 0.,
+            row_spacing: 0.,
+        }
+    }
+
+    /// Use a [`Grid`](Content::Grid) content with the given tracks and spacings.
+    pub fn grid_spacing(
+        columns: Vec<GridSize>,
+        rows: Vec<GridSize>,
+        column_spacing: f32,
+        row_spacing: f32,
+    ) -> Content {
+        Content::Grid {
+            columns,
+            rows,
+            column_spacing,
+            row_spacing,
+        }
+    }
+
     pub fn is_fit(&self) -> bool {
         self == &Self::Fit
     }
@@ -53,6 +92,10 @@ impl Content {
         matches!(self, Self::Wrap { .. })
     }
 
+    pub fn is_grid(&self) -> bool {
+        matches!(self, Self::Grid { .. })
+    }
+
     pub fn allows_alignments(&self) -> bool {
         matches!(self, Self::Normal | Self::Flex | Self::Fit)
     }
@@ -65,6 +108,36 @@ impl Content {
             Self::Fit => "fit".to_owned(),
             Self::Flex => "flex".to_owned(),
             Self::Wrap { .. } => "wrap".to_owned(),
+            Self::Grid { columns, rows, .. } => format!(
+                "grid({} / {})",
+                columns
+                    .iter()
+                    .map(GridSize::pretty)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                rows.iter()
+                    .map(GridSize::pretty)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        }
+    }
+}
+
+impl Scaled for Content {
+    fn scale(&mut self, scale_factor: f32) {
+        if let Self::Grid {
+            columns,
+            rows,
+            column_spacing,
+            row_spacing,
+        } = self
+        {
+            for track in columns.iter_mut().chain(rows.iter_mut()) {
+                track.scale(scale_factor);
+            }
+            *column_spacing *= scale_factor;
+            *row_spacing *= scale_factor;
         }
     }
 }
@@ -83,6 +83,11 @@ impl Gaps {
         self.left = Length::new(value);
     }
 
+    /// Swap the left and right sides.
+    pub fn mirror_horizontal(&mut self) {
+        std::mem::swap(&mut self.left, &mut self.right);
+    }
+
     pub fn fill_all(&mut self, value: f32) {
         self.fill_horizontal(value);
         self.fill_vertical(value);
@@ -0,0 +1,136 @@
+use crate::{
+    geometry::Length,
+    scaled::Scaled,
+};
+
+/// Size of a column or row track in a [`Grid`](crate::content::Content::Grid) content.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub enum GridSize {
+    /// Fixed track size in pixels.
+    ///
+    /// Can also be created with [`GridSize::px`].
+    Pixels(Length),
+
+    /// Track size as a percentage relative to the grid's inner size.
+    ///
+    /// Can also be created with [`GridSize::percent`].
+    Percentage(Length),
+
+    /// Fraction of the space left after the other tracks have been sized,
+    /// shared proportionally between all the fraction tracks.
+    ///
+    /// Can also be created with [`GridSize::fr`].
+    Fraction(Length),
+
+    /// Sized by the biggest child placed only in this track.
+    ///
+    /// Can also be created with [`GridSize::auto`].
+    Inner,
+}
+
+impl GridSize {
+    /// Use a [`Pixels`](GridSize::Pixels) track size.
+    pub fn px(px: f32) -> GridSize {
+        GridSize::Pixels(Length::new(px))
+    }
+
+    /// Use a [`Percentage`](GridSize::Percentage) track size.
+    pub fn percent(percent: f32) -> GridSize {
+        GridSize::Percentage(Length::new(percent))
+    }
+
+    /// Use a [`Fraction`](GridSize::Fraction) track size.
+    pub fn fr(fraction: f32) -> GridSize {
+        GridSize::Fraction(Length::new(fraction))
+    }
+
+    /// Use an [`Inner`](GridSize::Inner) track size.
+    pub fn auto() -> GridSize {
+        GridSize::Inner
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Pixels(s) => format!("{}", s.get()),
+            Self::Percentage(p) => format!("{}%", p.get()),
+            Self::Fraction(f) => format!("{}fr", f.get()),
+            Self::Inner => "auto".to_string(),
+        }
+    }
+}
+
+impl Scaled for GridSize {
+    fn scale(&mut self, scale_factor: f32) {
+        if let Self::Pixels(s) = self {
+            *s *= scale_factor;
+        }
+    }
+}
+
+/// Cell a Node occupies inside a parent with a [`Grid`](crate::content::Content::Grid) content.
+///
+/// Nodes without an explicit column or row are placed in the next free cell, row by row.
+///
+/// ```
+/// # use torin::prelude::*;
+/// let auto = GridPlacement::default();
+/// let header = GridPlacement::new(0, 0).column_span(3);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct GridPlacement {
+    pub column: Option<usize>,
+    pub row: Option<usize>,
+    pub column_span: usize,
+    pub row_span: usize,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            column: None,
+            row: None,
+            column_span: 1,
+            row_span: 1,
+        }
+    }
+}
+
+impl GridPlacement {
+    /// Place the Node in the given column and row.
+    pub fn new(column: usize, row: usize) -> Self {
+        Self {
+            column: Some(column),
+            row: Some(row),
+            ..Self::default()
+        }
+    }
+
+    /// Set how many columns the Node spans.
+    #[must_use]
+    pub fn column_span(mut self, column_span: usize) -> Self {
+        self.column_span = column_span.max(1);
+        self
+    }
+
+    /// Set how many rows the Node spans.
+    #[must_use]
+    pub fn row_span(mut self, row_span: usize) -> Self {
+        self.row_span = row_span.max(1);
+        self
+    }
+
+    pub fn pretty(&self) -> String {
+        let column = self
+            .column
+            .map_or_else(|| "auto".to_string(), |c| c.to_string());
+        let row = self
+            .row
+            .map_or_else(|| "auto".to_string(), |r| r.to_string());
+        format!(
+            "{column} / {row} (span {}x{})",
+            self.column_span, self.row_span
+        )
+    }
+}
@@ -0,0 +1,37 @@
+/// Horizontal order in which a Node lays out its children, usually inherited from the text style.
+///
+/// With [`RightToLeft`](LayoutDirection::RightToLeft) children are mirrored inside of the Node:
+/// horizontal children start from the right, [`Start`](crate::alignment::Alignment::Start) and
+/// [`End`](crate::alignment::Alignment::End) alignments are swapped horizontally and the left and
+/// right sides of the padding and margin are treated as the start and end sides.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default, Copy)]
+pub enum LayoutDirection {
+    /// Lay out from left to right. This is the default.
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Use a [`LeftToRight`](LayoutDirection::LeftToRight) layout direction.
+    pub fn ltr() -> LayoutDirection {
+        LayoutDirection::LeftToRight
+    }
+
+    /// Use a [`RightToLeft`](LayoutDirection::RightToLeft) layout direction.
+    pub fn rtl() -> LayoutDirection {
+        LayoutDirection::RightToLeft
+    }
+
+    pub fn is_rtl(&self) -> bool {
+        *self == LayoutDirection::RightToLeft
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::LeftToRight => "ltr".to_string(),
+            Self::RightToLeft => "rtl".to_string(),
+        }
+    }
+}
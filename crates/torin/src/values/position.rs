@@ -19,6 +19,57 @@ pub struct PositionSides {
     pub left: Option<f32>,
 }
 
+impl PositionSides {
+    /// Origin of a sticky area placed at `area` by the layout flow, clamped by these sides to the
+    /// `viewport` and kept inside of its `containing_area`.
+    pub(crate) fn sticky_origin(
+        &self,
+        area: &Area,
+        viewport: &Area,
+        containing_area: &Area,
+    ) -> Point2D {
+        Point2D::new(
+            sticky_axis(
+                area.min_x(),
+                area.width(),
+                (self.left, self.right),
+                (viewport.min_x(), viewport.max_x()),
+                (containing_area.min_x(), containing_area.max_x()),
+            ),
+            sticky_axis(
+                area.min_y(),
+                area.height(),
+                (self.top, self.bottom),
+                (viewport.min_y(), viewport.max_y()),
+                (containing_area.min_y(), containing_area.max_y()),
+            ),
+        )
+    }
+}
+
+/// Clamp the flow position of a sticky area in one axis so it keeps its offsets from the viewport
+/// edges, without moving past the containing edges.
+fn sticky_axis(
+    flow: f32,
+    size: f32,
+    (start, end): (Option<f32>, Option<f32>),
+    (viewport_start, viewport_end): (f32, f32),
+    (containing_start, containing_end): (f32, f32),
+) -> f32 {
+    let mut value = flow;
+    if let Some(start) = start {
+        value = value.max(viewport_start + start);
+    }
+    if let Some(end) = end {
+        value = value.min(viewport_end - end - size);
+    }
+    if value > flow {
+        value.min((containing_end - size).max(flow))
+    } else {
+        value.max(containing_start.min(flow))
+    }
+}
+
 /// How an element is placed relative to its parent or the window.
 ///
 /// Build one of the variants and set the sides you need with the chainable
@@ -30,6 +81,7 @@ pub struct PositionSides {
 /// let stacked = Position::new_stacked(); // default, follows normal layout flow
 /// let absolute = Position::new_absolute().top(10.0).left(20.0); // offset from the parent
 /// let global = Position::new_global().bottom(0.0).right(0.0); // offset from the window
+/// let sticky = Position::new_sticky().top(0.0); // sticks to the top of the scroll container
 /// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Clone, Debug)]
@@ -41,6 +93,9 @@ pub enum Position {
     Absolute(Box<PositionSides>),
     /// Taken out of the flow and positioned by offsets relative to the window.
     Global(Box<PositionSides>),
+    /// Placed by the normal layout flow, but clamped by its offsets to the visible area of the
+    /// closest scrollable ancestor, without ever leaving its parent.
+    Sticky(Box<PositionSides>),
 }
 
 impl Default for Position {
@@ -80,6 +135,17 @@ impl Position {
         }))
     }
 
+    /// Create a [`Sticky`](Position::Sticky) position that follows the normal layout flow
+    /// until it would scroll out of view.
+    pub fn new_sticky() -> Self {
+        Self::Sticky(Box::new(PositionSides {
+            top: None,
+            right: None,
+            bottom: None,
+            left: None,
+        }))
+    }
+
     /// Set the offset from the top edge.
     #[must_use]
     pub fn top(mut self, value: f32) -> Self {
@@ -110,12 +176,16 @@ impl Position {
 
     fn position_mut(&mut self) -> &mut PositionSides {
         match self {
-            Self::Absolute(position) | Self::Global(position) | Self::Stacked(position) => position,
+            Self::Absolute(position)
+            | Self::Global(position)
+            | Self::Stacked(position)
+            | Self::Sticky(position) => position,
         }
     }
 
+    /// Whether this position follows the normal layout flow, [`Sticky`](Position::Sticky) included.
     pub fn is_stacked(&self) -> bool {
-        matches!(self, Self::Stacked { .. })
+        matches!(self, Self::Stacked { .. } | Self::Sticky { .. })
     }
 
     pub fn is_absolute(&self) -> bool {
@@ -126,6 +196,10 @@ impl Position {
         matches!(self, Self::Global { .. })
     }
 
+    pub fn is_sticky(&self) -> bool {
+        matches!(self, Self::Sticky { .. })
+    }
+
     pub(crate) fn get_origin(
         &self,
         available_parent_area: &AreaOf<Available>,
@@ -134,7 +208,7 @@ impl Position {
         root_area: &Area,
     ) -> Point2D {
         match self {
-            Self::Stacked(_) => available_parent_area.origin.cast_unit(),
+            Self::Stacked(_) | Self::Sticky(_) => available_parent_area.origin.cast_unit(),
             Self::Absolute(absolute_position) => {
                 let PositionSides {
                     top,
@@ -196,7 +270,7 @@ impl Position {
 impl Scaled for Position {
     fn scale(&mut self, scale_factor: f32) {
         match self {
-            Self::Absolute(position) | Self::Global(position) => {
+            Self::Absolute(position) | Self::Global(position) | Self::Sticky(position) => {
                 if let Some(top) = &mut position.top {
                     *top *= scale_factor;
                 }
@@ -219,6 +293,13 @@ impl Position {
     pub fn pretty(&self) -> String {
         match self {
             Self::Stacked(_) => "stacked".to_string(),
+            Self::Sticky(positions) => format!(
+                "sticky {}, {}, {}, {}",
+                positions.top.unwrap_or_default(),
+                positions.right.unwrap_or_default(),
+                positions.bottom.unwrap_or_default(),
+                positions.left.unwrap_or_default()
+            ),
             Self::Absolute(positions) | Self::Global(positions) => format!(
                 "{}, {}, {}, {}",
                 positions.top.unwrap_or_default(),
@@ -0,0 +1,85 @@
+use rustc_hash::FxHashMap;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn layout_changes() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    for (id, height) in [(1, 100.0), (2, 100.0), (3, 100.0)] {
+        mocked_tree.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(height)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.take_changes(),
+        FxHashMap::from_iter([(0, None), (1, None), (2, None), (3, None)])
+    );
+
+    mocked_tree.set_node(
+        2,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    layout.invalidate(2);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Only the resized Node and the sibling that moved after it changed
+    assert_eq!(
+        layout.take_changes(),
+        FxHashMap::from_iter([
+            (
+                2,
+                Some(Rect::new(
+                    Point2D::new(0.0, 100.0),
+                    Size2D::new(100.0, 100.0)
+                ))
+            ),
+            (
+                3,
+                Some(Rect::new(
+                    Point2D::new(0.0, 200.0),
+                    Size2D::new(100.0, 100.0)
+                ))
+            ),
+        ])
+    );
+    assert!(layout.take_changes().is_empty());
+}
@@ -0,0 +1,251 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn grid_tracks() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_content(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(300.0)),
+            Content::grid(
+                vec![GridSize::px(100.0), GridSize::fr(1.0), GridSize::fr(2.0)],
+                vec![GridSize::percent(50.0), GridSize::fr(1.0)],
+            ),
+        ),
+    );
+    for node_id in 1..=4 {
+        mocked_tree.add(
+            node_id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(100.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(200.0, 0.0), Size2D::new(200.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(&4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(100.0, 150.0)),
+    );
+}
+
+#[test]
+pub fn grid_spacing_and_padding() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_padding(
+        Size::Pixels(Length::new(230.0)),
+        Size::Pixels(Length::new(230.0)),
+        Gaps::new(10.0, 10.0, 10.0, 10.0),
+    );
+    root.content = Content::grid_spacing(
+        vec![GridSize::fr(1.0), GridSize::fr(1.0)],
+        vec![GridSize::fr(1.0), GridSize::fr(1.0)],
+        10.0,
+        20.0,
+    );
+    mocked_tree.add(0, None, vec![1, 2, 3], root);
+    for node_id in 1..=3 {
+        mocked_tree.add(
+            node_id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(10.0, 10.0), Size2D::new(100.0, 95.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(120.0, 10.0), Size2D::new(100.0, 95.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(10.0, 125.0), Size2D::new(100.0, 95.0)),
+    );
+}
+
+#[test]
+pub fn grid_placement_and_spans() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_content(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            Content::grid(
+                vec![GridSize::fr(1.0), GridSize::fr(1.0), GridSize::fr(1.0)],
+                vec![GridSize::fr(1.0), GridSize::fr(1.0), GridSize::fr(1.0)],
+            ),
+        ),
+    );
+
+    // Auto placed, goes into the first free cell
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+
+    // Auto placed, skips the cells taken by the explicitly placed Node and moves
+    // to the first row with two consecutive free columns
+    let mut wide = Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical);
+    wide.grid_placement = GridPlacement::default().column_span(2);
+    mocked_tree.add(2, Some(0), vec![], wide);
+
+    // Explicitly placed, skipped by the auto placed Nodes
+    let mut tall = Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical);
+    tall.grid_placement = GridPlacement::new(1, 0).row_span(2);
+    mocked_tree.add(3, Some(0), vec![], tall);
+
+    // Auto placed after the wide one
+    mocked_tree.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(100.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 200.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(&4).unwrap().area,
+        Rect::new(Point2D::new(200.0, 200.0), Size2D::new(100.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn grid_auto_tracks() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_content(
+            Size::Inner,
+            Size::Inner,
+            Content::grid_spacing(
+                vec![GridSize::auto(), GridSize::px(50.0)],
+                vec![GridSize::auto()],
+                5.0,
+                5.0,
+            ),
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(80.0)),
+            Size::Pixels(Length::new(20.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(10.0)),
+            Size::Pixels(Length::new(40.0)),
+            Direction::Vertical,
+        ),
+    );
+    // Placed in an implicit auto row
+    mocked_tree.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(60.0)),
+            Size::Pixels(Length::new(30.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(135.0, 75.0)),
+    );
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(80.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(85.0, 0.0), Size2D::new(10.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 45.0), Size2D::new(60.0, 30.0)),
+    );
+}
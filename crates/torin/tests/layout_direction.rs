@@ -0,0 +1,208 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn right_to_left_horizontal() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_padding(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(100.0)),
+        Gaps::new(0.0, 0.0, 0.0, 20.0),
+    );
+    root.direction = Direction::Horizontal;
+    root.layout_direction = LayoutDirection::RightToLeft;
+    mocked_tree.add(0, None, vec![1, 2], root);
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![3],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Fill,
+            Direction::Horizontal,
+        ),
+    );
+    mocked_tree.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Fill,
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(20.0)),
+            Size::Pixels(Length::new(20.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // The left padding is the start side, so it goes on the right
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(230.0, 0.0), Size2D::new(50.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(130.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    // Descendants move along with their mirrored parent
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(230.0, 0.0), Size2D::new(20.0, 20.0)),
+    );
+}
+
+#[test]
+pub fn right_to_left_alignments_and_margin() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_alignments_and_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(300.0)),
+        Alignment::Start,
+        Alignment::End,
+        Direction::Vertical,
+    );
+    root.layout_direction = LayoutDirection::RightToLeft;
+    mocked_tree.add(0, None, vec![1, 2], root);
+
+    let mut start = Node::from_size_and_margin(
+        Size::Pixels(Length::new(100.0)),
+        Size::Pixels(Length::new(50.0)),
+        Gaps::new(0.0, 0.0, 0.0, 10.0),
+    );
+    start.layout_direction = LayoutDirection::RightToLeft;
+    mocked_tree.add(1, Some(0), vec![], start);
+    mocked_tree.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Cross end alignment becomes the left side
+    assert_eq!(
+        layout.get(&1).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(50.0, 50.0)),
+    );
+
+    let mut root = Node::from_size_and_alignments_and_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(300.0)),
+        Alignment::Start,
+        Alignment::Start,
+        Direction::Vertical,
+    );
+    root.layout_direction = LayoutDirection::RightToLeft;
+    mocked_tree.set_node(0, root);
+    layout.invalidate(0);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Cross start alignment becomes the right side, and the start margin goes on the right
+    assert_eq!(
+        layout.get(&1).unwrap().visible_area(),
+        Rect::new(Point2D::new(190.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(250.0, 50.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn right_to_left_partial_relayout() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(100.0)),
+        Direction::Horizontal,
+    );
+    root.layout_direction = LayoutDirection::RightToLeft;
+    mocked_tree.add(0, None, vec![1], root);
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Fill,
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(250.0, 0.0), Size2D::new(50.0, 100.0)),
+    );
+
+    mocked_tree.set_node(
+        1,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(80.0)),
+            Size::Fill,
+            Direction::Vertical,
+        ),
+    );
+    layout.invalidate(1);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(220.0, 0.0), Size2D::new(80.0, 100.0)),
+    );
+}
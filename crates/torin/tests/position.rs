@@ -464,3 +464,139 @@ pub fn global_with_inner_sized_and_bottom() {
         Rect::new(Point2D::new(20.0, 460.0), Size2D::new(80.0, 30.0)),
     );
 }
+
+#[test]
+pub fn sticky() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_direction(
+        Size::Pixels(Length::new(200.0)),
+        Size::Pixels(Length::new(100.0)),
+        Direction::Vertical,
+    );
+    root.scrollable = true;
+    root.offset_y = Length::new(-50.0);
+    mocked_tree.add(0, None, vec![1, 4], root.clone());
+    for (group_id, header_id, item_id) in [(1, 2, 3), (4, 5, 6)] {
+        mocked_tree.add(
+            group_id,
+            Some(0),
+            vec![header_id, item_id],
+            Node::from_size_and_direction(Size::Fill, Size::Inner, Direction::Vertical),
+        );
+        mocked_tree.add(
+            header_id,
+            Some(group_id),
+            vec![],
+            Node::from_size_and_position(
+                Size::Fill,
+                Size::Pixels(Length::new(20.0)),
+                Position::new_sticky().top(0.0),
+            ),
+        );
+        mocked_tree.add(
+            item_id,
+            Some(group_id),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Fill,
+                Size::Pixels(Length::new(130.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Scrolled out of view, so it sticks to the top
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, -30.0), Size2D::new(200.0, 130.0)),
+    );
+    // Still in view, so it stays in its flow position
+    assert_eq!(
+        layout.get(&5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 20.0)),
+    );
+
+    root.offset_y = Length::new(-140.0);
+    mocked_tree.set_node(0, root);
+    layout.invalidate_with_reason(0, DirtyReason::InnerLayout);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Pushed up by the end of its parent
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, -10.0), Size2D::new(200.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 10.0), Size2D::new(200.0, 20.0)),
+    );
+}
+
+#[test]
+pub fn sticky_without_scrollable_ancestor() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_padding(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+            Gaps::new(10.0, 10.0, 10.0, 10.0),
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![2],
+        Node::from_size_and_direction(
+            Size::Fill,
+            Size::Pixels(Length::new(20.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_position(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(20.0)),
+            Position::new_sticky().bottom(0.0),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Visible inside of the window, so it stays in its flow position
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(10.0, 10.0), Size2D::new(50.0, 20.0)),
+    );
+}
@@ -943,3 +943,102 @@ pub fn size_fn() {
         Rect::new(Point2D::new(0.0, 0.0), Size2D::new(250.0, 1000.0)),
     );
 }
+
+#[test]
+pub fn aspect_ratio() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(800.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    // Height derived from the width
+    let mut derived_height =
+        Node::from_size_and_margin(Size::Fill, Size::Inner, Gaps::new(10.0, 0.0, 10.0, 0.0));
+    derived_height.aspect_ratio = Some(16.0 / 9.0);
+    mocked_tree.add(1, Some(0), vec![], derived_height);
+
+    // Width derived from the height
+    let mut derived_width = Node::from_size_and_direction(
+        Size::Inner,
+        Size::Pixels(Length::new(100.0)),
+        Direction::Vertical,
+    );
+    derived_width.aspect_ratio = Some(0.5);
+    mocked_tree.add(2, Some(0), vec![], derived_width);
+
+    // Derived height limited by the maximum height
+    let mut limited = Node::from_sizes(
+        Size::Fill,
+        Size::Inner,
+        Size::Inner,
+        Size::Inner,
+        Size::Inner,
+        Size::Pixels(Length::new(200.0)),
+    );
+    limited.aspect_ratio = Some(1.0);
+    mocked_tree.add(3, Some(0), vec![], limited);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 10.0), Size2D::new(800.0, 450.0)),
+    );
+
+    assert_eq!(
+        layout.get(&2).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 470.0), Size2D::new(50.0, 100.0)),
+    );
+
+    assert_eq!(
+        layout.get(&3).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 570.0), Size2D::new(800.0, 200.0)),
+    );
+}
+
+#[test]
+pub fn aspect_ratio_ignored_when_both_sizes_resolve() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+
+    let mut child = Node::from_size_and_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(100.0)),
+        Direction::Vertical,
+    );
+    child.aspect_ratio = Some(1.0);
+    mocked_tree.add(1, Some(0), vec![], child);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(300.0, 100.0)),
+    );
+}
@@ -0,0 +1,60 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(
+        LaunchConfig::new()
+            .with_window(WindowConfig::new(source_app).with_title("Source"))
+            .with_window(WindowConfig::new(target_app).with_title("Target"))
+            // On Windows and macOS, the file of the payload can also be dropped into other apps
+            .with_os_drag(true),
+    )
+}
+
+fn source_app() -> impl IntoElement {
+    rect().expanded().center().child(
+        DragZone::new(())
+            .payload(
+                DragPayload::new()
+                    .with_file("Cargo.toml")
+                    .with_text("Hello from the source window"),
+            )
+            .drag_element(label().text("Dragging"))
+            .child(
+                rect()
+                    .padding(12.)
+                    .corner_radius(8.)
+                    .background((25, 189, 125))
+                    .child("Drag me into the other window"),
+            ),
+    )
+}
+
+fn target_app() -> impl IntoElement {
+    let mut dropped = use_state(|| None::<String>);
+    let mut drag_over = use_state(|| false);
+
+    rect().expanded().center().child(
+        DropZone::new(move |payload: DragPayload| {
+            dropped.set(payload.text().map(str::to_string));
+        })
+        .on_drag_over(move |is_over| drag_over.set(is_over))
+        .child(
+            rect()
+                .width(Size::px(250.))
+                .height(Size::px(150.))
+                .center()
+                .corner_radius(8.)
+                .background(if drag_over() {
+                    (200, 235, 215)
+                } else {
+                    (235, 235, 235)
+                })
+                .child(dropped.read().clone().unwrap_or("Drop here".to_string())),
+        ),
+    )
+}
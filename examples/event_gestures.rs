@@ -0,0 +1,64 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(600., 500.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut offset = use_state(|| (0., 0.));
+    let mut scale = use_state(|| 1.);
+    let mut rotation = use_state(|| 0.);
+    let mut last_gesture = use_state(|| "Pan, pinch, rotate, swipe or long press".to_string());
+
+    let (offset_x, offset_y) = offset();
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(12.)
+        .on_pan(move |e: Event<PanEventData>| {
+            offset.with_mut(|mut offset| {
+                offset.0 += e.delta.x as f32;
+                offset.1 += e.delta.y as f32;
+            });
+        })
+        .on_pinch(move |e: Event<PinchEventData>| {
+            scale.set((scale() * e.delta as f32).clamp(0.25, 4.));
+        })
+        .on_rotate(move |e: Event<RotateEventData>| {
+            rotation.set(rotation() + e.delta.to_degrees() as f32);
+        })
+        .on_long_press(move |_| {
+            offset.set((0., 0.));
+            scale.set(1.);
+            rotation.set(0.);
+            last_gesture.set("Long press, reset".to_string());
+        })
+        .on_swipe(move |e: Event<SwipeEventData>| {
+            last_gesture.set(format!(
+                "Swiped {:?} at {:.0} px/s",
+                e.direction,
+                e.velocity.length()
+            ));
+        })
+        .child(label().text(last_gesture.read().clone()))
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(150.))
+                .center()
+                .offset_x(offset_x)
+                .offset_y(offset_y)
+                .scale(scale())
+                .rotate(rotation())
+                .corner_radius(12.)
+                .background((0, 119, 182))
+                .color((255, 255, 255))
+                .child(format!("{:.2}x, {:.0}°", scale(), rotation())),
+        )
+}
@@ -0,0 +1,52 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 500.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut count = use_state(|| 0);
+
+    // Recorded once and re-used until something inside of it changes
+    let grid = rect()
+        .width(Size::fill())
+        .height(Size::fill())
+        .horizontal()
+        .content(Content::wrap())
+        .spacing(6.)
+        .padding(6.)
+        .cache_layer(true)
+        .children((0..200).map(|i| {
+            rect()
+                .width(Size::px(40.))
+                .height(Size::px(40.))
+                .center()
+                .corner_radius(8.)
+                .background((15, 163, (i * 7 % 255) as u8))
+                .color(Color::WHITE)
+                .shadow((0., 2., 6., 0., (0, 0, 0, 60)))
+                .child(i.to_string())
+        }));
+
+    rect()
+        .expanded()
+        .child(
+            rect()
+                .width(Size::fill())
+                .height(Size::px(60.))
+                .center()
+                .child(
+                    Button::new()
+                        .on_press(move |_| {
+                            *count.write() += 1;
+                        })
+                        .child(format!("Pressed {} times", count.read())),
+                ),
+        )
+        .child(grid)
+}
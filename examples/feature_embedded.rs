@@ -3,8 +3,6 @@
     windows_subsystem = "windows"
 )]
 
-use std::borrow::Cow;
-
 use freya_core::{
     integration::*,
     prelude::*,
@@ -61,7 +59,7 @@ struct EmbeddedFreya {
 
     font_collection: FontCollection,
     font_manager: FontMgr,
-    default_fonts: Vec<Cow<'static, str>>,
+    default_fonts: FallbackFonts,
 
     events_sender: UnboundedSender<EventsChunk>,
     events_receiver: UnboundedReceiver<EventsChunk>,
@@ -90,7 +88,7 @@ impl EmbeddedFreya {
             progress,
             font_collection,
             font_manager: dynamic_font_manager,
-            default_fonts: default_fonts(),
+            default_fonts: default_fonts().into(),
             events_sender,
             events_receiver,
             size,
@@ -133,6 +131,7 @@ impl EmbeddedFreya {
             canvas: surface.canvas(),
             scale_factor: self.scale_factor,
             background: Color::WHITE,
+            damage: &Damage::full(),
         }
         .render();
 
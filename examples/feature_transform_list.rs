@@ -0,0 +1,41 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let mut presses = use_state(|| 0);
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(40.)
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(80.))
+                .center()
+                .background((222, 231, 145))
+                .transforms([
+                    Transform::translate(60., 0.),
+                    Transform::rotate(20.),
+                    Transform::skew(-15., 0.),
+                ])
+                .on_press(move |_| *presses.write() += 1)
+                .child(format!("Pressed {} times", presses.read())),
+        )
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(80.))
+                .center()
+                .background((145, 200, 231))
+                .transform(Transform::matrix(1., 0.2, 0., 1., -60., 0.))
+                .child("Matrix"),
+        )
+}
@@ -0,0 +1,47 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .padding(10.)
+        .content(Content::grid_spacing(
+            vec![GridSize::px(150.), GridSize::fr(1.), GridSize::fr(2.)],
+            vec![GridSize::auto(), GridSize::fr(1.), GridSize::px(40.)],
+            10.,
+            10.,
+        ))
+        .child(
+            rect()
+                .grid_span(3, 1)
+                .width(Size::fill())
+                .padding(10.)
+                .background((50, 50, 255))
+                .child("Header"),
+        )
+        .child(
+            rect()
+                .grid_span(1, 2)
+                .expanded()
+                .background((150, 150, 150))
+                .child("Sidebar"),
+        )
+        .child(rect().expanded().background((255, 50, 50)).child("Content"))
+        .child(rect().expanded().background((50, 255, 50)).child("Details"))
+        .child(
+            rect()
+                .grid_column(1)
+                .grid_row(2)
+                .grid_span(2, 1)
+                .expanded()
+                .background((200, 50, 200))
+                .child("Footer"),
+        )
+}
@@ -0,0 +1,49 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let mut rtl = use_state(|| true);
+
+    let layout_direction = if *rtl.read() {
+        LayoutDirection::RightToLeft
+    } else {
+        LayoutDirection::LeftToRight
+    };
+
+    rect()
+        .expanded()
+        .padding((10., 10., 10., 40.))
+        .spacing(10.)
+        .layout_direction(layout_direction)
+        .child(
+            Button::new()
+                .on_press(move |_| rtl.toggle())
+                .child(layout_direction.pretty()),
+        )
+        .child(
+            rect()
+                .horizontal()
+                .spacing(10.)
+                .children(["١", "٢", "٣"].map(|number| {
+                    rect()
+                        .width(Size::px(60.))
+                        .height(Size::px(60.))
+                        .center()
+                        .background((15, 163, 242))
+                        .child(number)
+                })),
+        )
+        .child(
+            paragraph()
+                .width(Size::fill())
+                .text_align(TextAlign::Start)
+                .span("مرحبا بالعالم، hello world"),
+        )
+}
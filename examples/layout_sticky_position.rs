@@ -0,0 +1,32 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    ScrollView::new().child(rect().width(Size::fill()).children((0..10).map(|group| {
+        rect()
+            .width(Size::fill())
+            .child(
+                rect()
+                    .width(Size::fill())
+                    .padding(10.)
+                    .background((15, 163, 242))
+                    .color(Color::WHITE)
+                    .position(Position::new_sticky().top(0.))
+                    .layer(Layer::Relative(1))
+                    .child(format!("Group {group}")),
+            )
+            .children((0..8).map(|item| {
+                rect()
+                    .width(Size::fill())
+                    .padding(10.)
+                    .child(format!("Item {item}"))
+            }))
+    })))
+}
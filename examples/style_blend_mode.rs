@@ -0,0 +1,48 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 450.)))
+}
+
+fn app() -> impl IntoElement {
+    let embedded = ("rust-logo", include_bytes!("./rust_logo.png"));
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(24.)
+        .child(
+            // Duotone image, taking its luminosity over a colored background
+            rect().background((123, 31, 162)).child(
+                ImageViewer::new(embedded)
+                    .width(Size::px(150.))
+                    .height(Size::px(150.))
+                    .blend_mode(BlendMode::Luminosity),
+            ),
+        )
+        .child(
+            // Text inverting whatever is behind it
+            rect()
+                .width(Size::px(400.))
+                .height(Size::px(120.))
+                .center()
+                .background(
+                    LinearGradient::new()
+                        .angle(90.)
+                        .stop((Color::WHITE, 0.0))
+                        .stop((Color::BLACK, 100.0)),
+                )
+                .child(
+                    label()
+                        .text("Blend modes")
+                        .font_size(64.)
+                        .color(Color::WHITE)
+                        .blend_mode(BlendMode::Difference),
+                ),
+        )
+}
@@ -0,0 +1,58 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 500.)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .spacing(16.)
+        .child(
+            // Diagonal hero section
+            rect()
+                .width(Size::fill())
+                .height(Size::px(150.))
+                .center()
+                .background((15, 163, 242))
+                .color(Color::WHITE)
+                .font_size(32.)
+                .clip_path(ClipPath::polygon([(0., 0.), (1., 0.), (1., 0.7), (0., 1.)]))
+                .child("Hello, World!"),
+        )
+        .child(
+            rect()
+                .width(Size::fill())
+                .horizontal()
+                .spacing(16.)
+                .padding(16.)
+                .child(
+                    // Circular avatar with a badge cut out of it
+                    rect()
+                        .width(Size::px(120.))
+                        .height(Size::px(120.))
+                        .background((222, 231, 145))
+                        .clip_path(ClipPath::circle().difference(ClipPath::svg(
+                            "M 102 102 m -20 0 a 20 20 0 1 0 40 0 a 20 20 0 1 0 -40 0",
+                        ))),
+                )
+                .child(
+                    // List fading out at the bottom
+                    rect()
+                        .width(Size::fill())
+                        .height(Size::px(200.))
+                        .overflow(Overflow::Clip)
+                        .mask(
+                            LinearGradient::new()
+                                .stop((Color::BLACK, 70.0))
+                                .stop((Color::TRANSPARENT, 100.0)),
+                        )
+                        .children((0..20).map(|i| label().text(format!("Item {i}")))),
+                ),
+        )
+}
@@ -0,0 +1,53 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(600., 400.)))
+}
+
+fn card(name: &'static str, filters: impl IntoIterator<Item = Filter>) -> Rect {
+    rect()
+        .width(Size::px(120.))
+        .height(Size::px(120.))
+        .center()
+        .corner_radius(12.)
+        .background((15, 163, 242))
+        .color(Color::WHITE)
+        .filters(filters)
+        .child(name)
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .spacing(16.)
+        .child(
+            rect()
+                .horizontal()
+                .spacing(16.)
+                .child(card("None", []))
+                .child(card("Grayscale", [Filter::grayscale(1.)]))
+                .child(card("Hue", [Filter::hue_rotate(120.)]))
+                .child(card(
+                    "Drop shadow",
+                    [Filter::drop_shadow(0., 6., 12., (0, 0, 0, 0.5))],
+                )),
+        )
+        .child(
+            rect()
+                .horizontal()
+                .spacing(16.)
+                .child(card("Bright", [Filter::brightness(1.5)]))
+                .child(card("Contrast", [Filter::contrast(0.4)]))
+                .child(card("Invert", [Filter::invert(1.)]))
+                .child(card(
+                    "Disabled",
+                    [Filter::saturate(0.2), Filter::brightness(1.2)],
+                )),
+        )
+}
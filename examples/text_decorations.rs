@@ -0,0 +1,63 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 400.)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .spacing(16.)
+        .font_size(24.)
+        .child(
+            // Spell checking squiggles
+            paragraph()
+                .span("This sentence has a ")
+                .span(
+                    Span::new("mispeled")
+                        .text_decoration(TextDecoration::Underline)
+                        .text_decoration_style(TextDecorationStyle::Wavy)
+                        .text_decoration_color(Color::RED),
+                )
+                .span(" word."),
+        )
+        .child(
+            // Diagnostics of a code editor
+            paragraph()
+                .font_family("Jetbrains Mono")
+                .span("let ")
+                .span(
+                    Span::new("unused")
+                        .text_decoration(TextDecoration::Underline)
+                        .text_decoration_style(TextDecorationStyle::Dotted)
+                        .text_decoration_color((230, 180, 0)),
+                )
+                .span(" = 1;"),
+        )
+        .child(
+            label()
+                .text("Double and thick")
+                .text_decoration(TextDecoration::Underline)
+                .text_decoration_style(TextDecorationStyle::Double)
+                .text_decoration_thickness(2.),
+        )
+        .child(
+            label()
+                .text("Dashed overline")
+                .text_decoration(TextDecoration::Overline)
+                .text_decoration_style(TextDecorationStyle::Dashed),
+        )
+        .child(
+            label()
+                .text("Outlined")
+                .font_size(48.)
+                .font_weight(FontWeight::BOLD)
+                .text_stroke((1.5, Color::from_rgb(50, 50, 200))),
+        )
+}
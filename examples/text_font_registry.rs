@@ -0,0 +1,54 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(
+        LaunchConfig::new()
+            .with_script_fallback_fonts(
+                FontScript::Cjk,
+                [
+                    "Noto Sans CJK JP",
+                    "Noto Sans JP",
+                    "Yu Gothic",
+                    "Hiragino Sans",
+                ],
+            )
+            .with_script_fallback_fonts(
+                FontScript::Emoji,
+                ["Noto Color Emoji", "Apple Color Emoji", "Segoe UI Emoji"],
+            )
+            .with_window(WindowConfig::new(app).with_size(600., 500.)),
+    )
+}
+
+fn app() -> impl IntoElement {
+    let families = use_hook(|| FontRegistry::get().families());
+
+    rect()
+        .expanded()
+        .padding(12.)
+        .spacing(8.)
+        .child(label().font_size(20.).text("こんにちは, 世界! 👋"))
+        .child(label().text(format!("{} font families installed", families.len())))
+        .child(
+            ScrollView::new()
+                .width(Size::fill())
+                .height(Size::flex(1.))
+                .spacing(4.)
+                .children(families.into_iter().map(|family| {
+                    let styles = family
+                        .faces
+                        .iter()
+                        .map(|face| face.name.clone().unwrap_or_else(|| "Unnamed".to_string()))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    paragraph()
+                        .span(Span::new(family.name.clone()).font_family(family.name))
+                        .span(format!("  {styles}"))
+                })),
+        )
+}
@@ -0,0 +1,54 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(600., 450.)))
+}
+
+fn app() -> impl IntoElement {
+    let alignments = [
+        InlineAlignment::Middle,
+        InlineAlignment::Baseline(14.),
+        InlineAlignment::AboveBaseline,
+        InlineAlignment::BelowBaseline,
+        InlineAlignment::Top,
+        InlineAlignment::Bottom,
+    ];
+
+    rect()
+        .expanded()
+        .padding(16.)
+        .spacing(12.)
+        .children(alignments.into_iter().map(|alignment| {
+            paragraph()
+                .width(Size::fill())
+                .font_size(20.)
+                .span(Span::new(format!("{}: ", alignment.pretty())).font_weight(FontWeight::BOLD))
+                .span("Great job ")
+                .inline_child(emoji(), alignment)
+                .span(" the build is ")
+                .inline_child(badge("passing"), alignment)
+                .span(" again")
+        }))
+}
+
+/// A custom emoji, sized like a glyph of the text.
+fn emoji() -> impl IntoElement {
+    rect()
+        .width(Size::px(18.))
+        .height(Size::px(18.))
+        .corner_radius(9.)
+        .background((250, 190, 40))
+}
+
+fn badge(text: &'static str) -> impl IntoElement {
+    rect()
+        .padding((2., 6.))
+        .corner_radius(4.)
+        .background((40, 160, 80))
+        .child(label().font_size(12.).color(Color::WHITE).text(text))
+}
@@ -0,0 +1,49 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 300.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut hovered = use_state::<Option<usize>>(|| None);
+    let mut pressed = use_state(|| "Nothing".to_string());
+
+    let link = |text: &'static str, id: &'static str, index: usize| {
+        Span::new(text)
+            .id(id)
+            .color((80, 140, 255))
+            .text_decoration(if hovered() == Some(index) {
+                TextDecoration::Underline
+            } else {
+                TextDecoration::None
+            })
+            .on_press(move |e: Event<SpanEventData>| {
+                pressed.set(e.id.as_deref().unwrap_or_default().to_string())
+            })
+            .on_pointer_enter(move |e: Event<SpanEventData>| hovered.set(Some(e.index)))
+            .on_pointer_leave(move |_| hovered.set(None))
+    };
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(12.)
+        .child(
+            paragraph()
+                .width(Size::px(400.))
+                .font_size(18.)
+                .span("Hey ")
+                .span(link("@freya", "user:freya", 1))
+                .span(", the docs are at ")
+                .span(link("freyaui.dev", "https://freyaui.dev", 3))
+                .span(" and the code lives on ")
+                .span(link("GitHub", "https://github.com/marc2332/freya", 5))
+                .span(". Spans wrap along with the rest of the text."),
+        )
+        .child(label().text(format!("Pressed: {}", pressed())))
+}
@@ -0,0 +1,47 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(600., 400.)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .horizontal()
+        .padding(24.)
+        .spacing(48.)
+        .child(
+            rect()
+                .spacing(24.)
+                .child(
+                    text_path()
+                        .path("M 20 110 A 90 90 0 0 1 200 110")
+                        .text("FREYA · TEXT ON A CIRCLE")
+                        .text_align(TextAlign::Center)
+                        .font_size(18.)
+                        .font_weight(FontWeight::BOLD)
+                        .color((40, 90, 180)),
+                )
+                .child(
+                    text_path()
+                        .path("M 0 60 C 60 0 120 120 220 40")
+                        .text("Labels can follow any curve")
+                        .start_offset(10.)
+                        .font_size(16.),
+                ),
+        )
+        .child(
+            paragraph()
+                .height(Size::px(220.))
+                .writing_mode(WritingMode::VerticalRl)
+                .font_size(22.)
+                .span("春はあけぼの。やうやう白くなりゆく山ぎは、")
+                .span(Span::new("少しあかりて").color((200, 60, 60)))
+                .span("、紫だちたる雲のほそくたなびきたる。"),
+        )
+}
@@ -0,0 +1,58 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 400.)))
+}
+
+const PATH: &str = "/home/user/projects/freya/crates/freya-core/src/elements/paragraph.rs";
+const TITLE: &str = "A rather long title that doesn't fit in the sidebar";
+
+fn app() -> impl IntoElement {
+    let overflows = [
+        TextOverflow::Clip,
+        TextOverflow::Ellipsis,
+        TextOverflow::StartEllipsis,
+        TextOverflow::MiddleEllipsis,
+        TextOverflow::Fade,
+    ];
+
+    rect()
+        .expanded()
+        .padding(12.)
+        .spacing(12.)
+        .children(overflows.into_iter().map(|text_overflow| {
+            rect()
+                .width(Size::px(220.))
+                .padding(6.)
+                .spacing(4.)
+                .background((235, 235, 235))
+                .corner_radius(6.)
+                .child(
+                    label()
+                        .font_size(12.)
+                        .color((120, 120, 120))
+                        .text(text_overflow.pretty()),
+                )
+                .child(
+                    label()
+                        .width(Size::fill())
+                        .max_lines(1)
+                        .text_overflow(text_overflow.clone())
+                        .text(PATH),
+                )
+                .child(
+                    paragraph()
+                        .width(Size::fill())
+                        .max_lines(1)
+                        .text_overflow(text_overflow)
+                        .font_weight(FontWeight::BOLD)
+                        .span(Span::new("Title: ").color((40, 90, 180)))
+                        .span(TITLE),
+                )
+        }))
+}
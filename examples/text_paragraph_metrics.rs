@@ -0,0 +1,74 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(600., 400.)))
+}
+
+const TEXT: &str = "Freya is a cross-platform GUI library for Rust.\nResize the window to see how the wrapped lines get their own number in the gutter, and hover the text to see the character under the pointer.";
+
+fn app() -> impl IntoElement {
+    let holder = use_state(ParagraphHolder::default);
+    let mut lines = use_state(Vec::<ParagraphLine>::new);
+    let mut hovered = use_state(|| None::<usize>);
+
+    let gutter = lines
+        .read()
+        .iter()
+        .fold(rect().width(Size::px(32.)), |gutter, line| {
+            gutter.child(
+                label()
+                    .position(Position::new_absolute().top(line.top()))
+                    .color((150, 150, 150))
+                    .text(format!("{}", line.index + 1)),
+            )
+        });
+
+    let highlight = hovered().and_then(|index| {
+        holder
+            .read()
+            .rects_for_range(index..index + 1)
+            .first()
+            .map(|area| {
+                rect()
+                    .position(
+                        Position::new_absolute()
+                            .top(area.min_y())
+                            .left(area.min_x()),
+                    )
+                    .width(Size::px(area.width()))
+                    .height(Size::px(area.height()))
+                    .background((255, 220, 120))
+            })
+    });
+
+    rect()
+        .expanded()
+        .padding(12.)
+        .spacing(8.)
+        .child(label().text(format!(
+            "{} lines, hovering character {:?}",
+            lines.read().len(),
+            hovered()
+        )))
+        .child(
+            rect().horizontal().width(Size::fill()).child(gutter).child(
+                rect().width(Size::flex(1.)).maybe_child(highlight).child(
+                    paragraph()
+                        .width(Size::fill())
+                        .holder(holder.read().clone())
+                        .on_sized(move |_| lines.set(holder.read().lines()))
+                        .on_pointer_move(move |e: Event<PointerEventData>| {
+                            hovered.set(holder.read().index_at(e.element_location()));
+                        })
+                        .on_pointer_leave(move |_| hovered.set(None))
+                        .font_size(18.)
+                        .span(TEXT),
+                ),
+            ),
+        )
+}
@@ -0,0 +1,43 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 450.)))
+}
+
+const MESSAGES: [(&str, &str); 4] = [
+    ("Alice", "Did you try selecting text across messages?"),
+    (
+        "Bob",
+        "Yes! Drag over the whole transcript and press Ctrl+C.",
+    ),
+    (
+        "Alice",
+        "The names, the messages and the styled spans all get copied.",
+    ),
+    ("Bob", "In document order, one line per text."),
+];
+
+fn app() -> impl IntoElement {
+    ScrollView::new().child(
+        SelectionScope::new()
+            .width(Size::fill())
+            .padding(16.)
+            .spacing(12.)
+            .children(MESSAGES.iter().map(|(author, message)| {
+                rect()
+                    .spacing(4.)
+                    .child(label().text(*author).font_weight(FontWeight::BOLD))
+                    .child(
+                        paragraph()
+                            .width(Size::fill())
+                            .span(*message)
+                            .span(Span::new(" ✓").color((80, 160, 80))),
+                    )
+            })),
+    )
+}
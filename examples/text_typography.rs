@@ -0,0 +1,45 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 400.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut count = use_state(|| 0);
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(12.)
+        .font_size(24.)
+        .child(
+            // Digits keep the same width, so the counter does not jitter
+            label()
+                .font_feature(FontFeature::tabular_numbers())
+                .text(format!("{}", count())),
+        )
+        .child(
+            Button::new()
+                .on_press(move |_| count.set(count() + 111))
+                .child("Increase"),
+        )
+        .child(label().letter_spacing(6.).text("SPACED OUT"))
+        .child(label().word_spacing(24.).text("Wide word gaps"))
+        .child(
+            paragraph()
+                .span("Ligatures: ")
+                .span(Span::new("office"))
+                .span(" vs ")
+                .span(Span::new("office").font_feature(FontFeature::ligatures(false))),
+        )
+        .child(
+            label()
+                .font_variation(FontVariation::weight(800.))
+                .text("Variable font weight"),
+        )
+}
@@ -0,0 +1,46 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(700., 400.)))
+}
+
+const TEXT: &str = "Die Donaudampfschifffahrtsgesellschaft: https://example.com/a/very/long/url";
+
+fn column(title: &str, word_break: WordBreak, hyphens: Hyphens) -> impl IntoElement {
+    rect()
+        .width(Size::px(140.))
+        .height(Size::fill())
+        .padding(8.)
+        .spacing(8.)
+        .background((235, 235, 235))
+        .child(
+            label()
+                .text(title.to_string())
+                .font_weight(FontWeight::BOLD),
+        )
+        .child(
+            paragraph()
+                .width(Size::fill())
+                .locale("de")
+                .word_break(word_break)
+                .hyphens(hyphens)
+                .span(TEXT),
+        )
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .horizontal()
+        .padding(12.)
+        .spacing(12.)
+        .child(column("Normal", WordBreak::Normal, Hyphens::Manual))
+        .child(column("Break all", WordBreak::BreakAll, Hyphens::Manual))
+        .child(column("Anywhere", WordBreak::Anywhere, Hyphens::Manual))
+        .child(column("Hyphens", WordBreak::Normal, Hyphens::Auto))
+}